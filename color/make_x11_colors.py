@@ -10,6 +10,8 @@
 # [Throw away the keys: Easy, Minimal Perfect Hashing](https://stevehanov.ca/blog/?id=119)
 # for the basic technique.
 
+import struct
+
 colors = [
     ("aliceblue", (240, 248, 255, 255)),
     ("antiquewhite", (250, 235, 215, 255)),
@@ -252,6 +254,55 @@ pub(crate) const COLORS: [[u8; 4]; {n}] = [""")
 for (name, rgba) in keys:
     print(f'    {list(rgba)},')
 print("];")
+
+# The matrices of the Oklab color space, as in `colorspace.rs`.
+OKLAB_SRGB_TO_LMS = [
+    [0.41222146, 0.53633255, 0.051445995],
+    [0.2119035, 0.6806995, 0.10739696],
+    [0.08830246, 0.28171885, 0.6299787],
+]
+OKLAB_LMS_TO_LAB = [
+    [0.21045426, 0.7936178, -0.004072047],
+    [1.9779985, -2.4285922, 0.4505937],
+    [0.025904037, 0.78277177, -0.80867577],
+]
+
+def srgb_to_lin(x):
+    if x <= 0.04045:
+        return x / 12.92
+    return ((x + 0.055) / 1.055) ** 2.4
+
+def matvecmul(m, v):
+    return [sum(m[i][j] * v[j] for j in range(3)) for i in range(3)]
+
+def to_oklab(rgba):
+    rgb = [srgb_to_lin(c / 255) for c in rgba[:3]]
+    lms = [c ** (1 / 3) for c in matvecmul(OKLAB_SRGB_TO_LMS, rgb)]
+    return matvecmul(OKLAB_LMS_TO_LAB, lms)
+
+def to_f32(x):
+    return struct.unpack("f", struct.pack("f", x))[0]
+
+# The shortest literal round-tripping through `f32`.
+def f32_literal(x):
+    x = to_f32(x)
+    if x == 0:
+        return "0."
+    for precision in range(1, 10):
+        literal = f"{x:.{precision}g}"
+        if to_f32(float(literal)) == x:
+            break
+    if "." not in literal and "e" not in literal:
+        literal += "."
+    return literal
+
+print(f"""
+/// The [`Oklab`](crate::Oklab) components of the named X11 colors, in the same order as
+/// [`NAMES`].
+pub(crate) const OKLAB: [[f32; 3]; {n}] = [""")
+for (name, rgba) in keys:
+    print(f"    [{', '.join(f32_literal(c) for c in to_oklab(rgba))}],")
+print("];")
 print("""
 /// Hash the 32 bit key into a value less than `n`, adding salt.
 ///
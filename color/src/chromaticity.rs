@@ -1,7 +1,10 @@
 // Copyright 2024 the Color Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::{matdiagmatmul, matmatmul, matvecmul};
+use crate::{
+    colorspace::{LINEAR_SRGB_TO_XYZ_D65, XYZ_D65_TO_LINEAR_SRGB},
+    matdiagmatmul, matinv, matmatmul, matvecmul,
+};
 
 /// CIE `xy` chromaticity, specifying a color in the XYZ color space, but not its luminosity.
 ///
@@ -13,6 +16,10 @@ use crate::{matdiagmatmul, matmatmul, matvecmul};
 /// Y = Y
 /// Z = Y/y * (1 - x - y)
 /// ```
+///
+/// The `xyY` coordinates of colors can be calculated with [`OpaqueColor::to_xyy`].
+///
+/// [`OpaqueColor::to_xyy`]: crate::OpaqueColor::to_xyy
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Chromaticity {
     /// The x-coordinate of the CIE `xy` chromaticity.
@@ -45,7 +52,8 @@ impl Chromaticity {
 
     /// The [ACES white point][aceswp].
     ///
-    /// This is the reference white of [ACEScg](crate::AcesCg) and [ACES2065-1](crate::Aces2065_1).
+    /// This is the reference white of [ACEScg](crate::AcesCg), [ACEScc](crate::AcesCc),
+    /// [ACEScct](crate::AcesCct) and [ACES2065-1](crate::Aces2065_1).
     /// The white point is near the D60 white point under the standard 2° observer.
     ///
     /// [aceswp]: https://docs.acescentral.com/tb/white-point
@@ -54,30 +62,309 @@ impl Chromaticity {
         y: 0.33767,
     };
 
+    /// The CIE standard illuminant A under the standard 2° observer.
+    ///
+    /// This represents typical tungsten-filament lighting, a Planckian radiator at about 2856 K.
+    pub const A: Self = Self {
+        x: 0.44757,
+        y: 0.40745,
+    };
+
+    /// The CIE illuminant C under the standard 2° observer.
+    ///
+    /// This represents average daylight. It has been superseded by the D-series illuminants, but is
+    /// still the reference white of some older standards, such as NTSC.
+    pub const C: Self = Self {
+        x: 0.31006,
+        y: 0.31616,
+    };
+
+    /// The CIE equal-energy illuminant E.
+    ///
+    /// This has a constant spectral power distribution over the visible spectrum, and is the
+    /// reference white of the CIE XYZ and RGB color spaces.
+    pub const E: Self = Self {
+        x: 1. / 3.,
+        y: 1. / 3.,
+    };
+
+    /// The CIE D55 illuminant under the standard 2° observer.
+    ///
+    /// This represents mid-morning or mid-afternoon daylight, with a correlated color temperature
+    /// of about 5500 K.
+    pub const D55: Self = Self {
+        x: 0.33242,
+        y: 0.34743,
+    };
+
+    /// The CIE D75 illuminant under the standard 2° observer.
+    ///
+    /// This represents north sky daylight, with a correlated color temperature of about 7500 K.
+    pub const D75: Self = Self {
+        x: 0.29902,
+        y: 0.31485,
+    };
+
+    /// The CIE F1 illuminant under the standard 2° observer.
+    ///
+    /// This represents a daylight fluorescent lamp, with a correlated color temperature of about
+    /// 6430 K.
+    pub const F1: Self = Self {
+        x: 0.31310,
+        y: 0.33727,
+    };
+
+    /// The CIE F2 illuminant under the standard 2° observer.
+    ///
+    /// This represents a cool white fluorescent lamp, with a correlated color temperature of about
+    /// 4230 K.
+    pub const F2: Self = Self {
+        x: 0.37208,
+        y: 0.37529,
+    };
+
+    /// The CIE F3 illuminant under the standard 2° observer.
+    ///
+    /// This represents a white fluorescent lamp, with a correlated color temperature
+    /// of about 3450 K.
+    pub const F3: Self = Self {
+        x: 0.40910,
+        y: 0.39430,
+    };
+
+    /// The CIE F4 illuminant under the standard 2° observer.
+    ///
+    /// This represents a warm white fluorescent lamp, with a correlated color temperature of about
+    /// 2940 K.
+    pub const F4: Self = Self {
+        x: 0.44018,
+        y: 0.40329,
+    };
+
+    /// The CIE F5 illuminant under the standard 2° observer.
+    ///
+    /// This represents a daylight fluorescent lamp, with a correlated color temperature of about
+    /// 6350 K.
+    pub const F5: Self = Self {
+        x: 0.31379,
+        y: 0.34531,
+    };
+
+    /// The CIE F6 illuminant under the standard 2° observer.
+    ///
+    /// This represents a lite white fluorescent lamp, with a correlated color temperature of about
+    /// 4150 K.
+    pub const F6: Self = Self {
+        x: 0.37790,
+        y: 0.38835,
+    };
+
+    /// The CIE F7 illuminant under the standard 2° observer.
+    ///
+    /// This represents a broadband daylight fluorescent lamp, with a correlated color temperature
+    /// of about 6500 K.
+    pub const F7: Self = Self {
+        x: 0.31292,
+        y: 0.32933,
+    };
+
+    /// The CIE F8 illuminant under the standard 2° observer.
+    ///
+    /// This represents a broadband fluorescent lamp, with a correlated color temperature of about
+    /// 5000 K.
+    pub const F8: Self = Self {
+        x: 0.34588,
+        y: 0.35875,
+    };
+
+    /// The CIE F9 illuminant under the standard 2° observer.
+    ///
+    /// This represents a broadband cool white fluorescent lamp, with a correlated color temperature
+    /// of about 4150 K.
+    pub const F9: Self = Self {
+        x: 0.37417,
+        y: 0.37281,
+    };
+
+    /// The CIE F10 illuminant under the standard 2° observer.
+    ///
+    /// This represents a narrowband fluorescent lamp, with a correlated color temperature of about
+    /// 5000 K.
+    pub const F10: Self = Self {
+        x: 0.34609,
+        y: 0.35986,
+    };
+
+    /// The CIE F11 illuminant under the standard 2° observer.
+    ///
+    /// This represents a narrowband fluorescent lamp, with a correlated color temperature of about
+    /// 4000 K.
+    pub const F11: Self = Self {
+        x: 0.38052,
+        y: 0.37713,
+    };
+
+    /// The CIE F12 illuminant under the standard 2° observer.
+    ///
+    /// This represents a narrowband fluorescent lamp, with a correlated color temperature of about
+    /// 3000 K.
+    pub const F12: Self = Self {
+        x: 0.43695,
+        y: 0.40441,
+    };
+
+    /// The DCI white point of the [SMPTE RP 431-2][rp431] digital cinema reference projector.
+    ///
+    /// This is not a CIE illuminant, and is slightly greenish compared to the D-series
+    /// illuminants.
+    ///
+    /// [rp431]: https://doi.org/10.5594/SMPTE.RP431-2.2011
+    pub const DCI: Self = Self { x: 0.314, y: 0.351 };
+
+    /// Calculate the chromaticities of a white with the given correlated color temperature, in
+    /// kelvin.
+    ///
+    /// Below 4000 K, this follows the Planckian locus, the chromaticities of black-body radiators,
+    /// using the cubic spline approximation of [Kim et al.][kim]. From 4000 K, it follows the CIE
+    /// daylight locus of the D-series illuminants, as defined in CIE 015:2018. Temperatures are
+    /// clamped to the range of these approximations, from 1667 K to 25000 K.
+    ///
+    /// As the loci differ slightly, the chromaticities are not continuous at 4000 K. They are
+    /// suitable as white balance targets for [`ColorSpace::chromatically_adapt`].
+    ///
+    /// The D-series illuminants are defined with a slightly different value of Planck's second
+    /// radiation constant, so for example [`Chromaticity::D65`] corresponds to about 6504 K rather
+    /// than 6500 K.
+    ///
+    /// ```rust
+    /// use color::Chromaticity;
+    ///
+    /// let d65 = Chromaticity::from_cct(6504.);
+    /// assert!((d65.x - Chromaticity::D65.x).abs() < 2e-4);
+    /// assert!((d65.y - Chromaticity::D65.y).abs() < 2e-4);
+    /// ```
+    ///
+    /// [kim]: https://patents.google.com/patent/US7024034B2
+    /// [`ColorSpace::chromatically_adapt`]: crate::ColorSpace::chromatically_adapt
+    #[must_use]
+    pub fn from_cct(cct: f32) -> Self {
+        let t = cct.clamp(1667., 25000.);
+        let t_recip = 1. / t;
+        let t_recip2 = t_recip * t_recip;
+        let t_recip3 = t_recip2 * t_recip;
+        if t < 4000. {
+            let x = -0.266_123_9e9 * t_recip3 - 0.234_358_9e6 * t_recip2
+                + 0.877_695_6e3 * t_recip
+                + 0.179_910;
+            let y = if t < 2222. {
+                -1.106_381_4 * x * x * x - 1.348_110_2 * x * x + 2.185_558_3 * x - 0.202_196_83
+            } else {
+                -0.954_947_6 * x * x * x - 1.374_185_9 * x * x + 2.091_37 * x - 0.167_488_67
+            };
+            Self { x, y }
+        } else {
+            let x = if t <= 7000. {
+                -4.607e9 * t_recip3 + 2.9678e6 * t_recip2 + 0.09911e3 * t_recip + 0.244_063
+            } else {
+                -2.0064e9 * t_recip3 + 1.9018e6 * t_recip2 + 0.24748e3 * t_recip + 0.237_04
+            };
+            let y = -3. * x * x + 2.87 * x - 0.275;
+            Self { x, y }
+        }
+    }
+
+    /// Calculate the `xy` chromaticities of an XYZ color.
+    ///
+    /// The chromaticities of black, where `X + Y + Z` is zero, are undefined and are returned as
+    /// NaN.
+    #[must_use]
+    pub const fn from_xyz([x, y, z]: [f32; 3]) -> Self {
+        let sum_recip = 1. / (x + y + z);
+        Self {
+            x: x * sum_recip,
+            y: y * sum_recip,
+        }
+    }
+
     /// Convert the `xy` chromaticities to XYZ, assuming `xyY` with `Y=1`.
-    pub(crate) const fn to_xyz(self) -> [f32; 3] {
+    #[must_use]
+    pub const fn to_xyz(self) -> [f32; 3] {
         let y_recip = 1. / self.y;
         [self.x * y_recip, 1., (1. - self.x - self.y) * y_recip]
     }
 
-    /// Calculate the 3x3 linear Bradford chromatic adaptation matrix from linear sRGB space.
+    /// Convert CIE 1976 UCS `u'v'` chromaticities to `xy` chromaticities.
+    ///
+    /// The `u'v'` chromaticity diagram is more perceptually uniform than the `xy` diagram, and is
+    /// used by CIELUV and for calculating correlated color temperatures.
+    #[must_use]
+    pub const fn from_uv([u, v]: [f32; 2]) -> Self {
+        let denom_recip = 1. / (6. * u - 16. * v + 12.);
+        Self {
+            x: 9. * u * denom_recip,
+            y: 4. * v * denom_recip,
+        }
+    }
+
+    /// Convert the `xy` chromaticities to CIE 1976 UCS `u'v'` chromaticities.
+    ///
+    /// This is the inverse of [`Chromaticity::from_uv`].
+    #[must_use]
+    pub const fn to_uv(self) -> [f32; 2] {
+        let denom_recip = 1. / (-2. * self.x + 12. * self.y + 3.);
+        [4. * self.x * denom_recip, 9. * self.y * denom_recip]
+    }
+
+    /// Convert XYZ to `xyY`, using the chromaticities of `white` for black.
+    pub(crate) const fn xyz_to_xyy(xyz: [f32; 3], white: Self) -> [f32; 3] {
+        if xyz[0] + xyz[1] + xyz[2] == 0. {
+            return [white.x, white.y, 0.];
+        }
+        let Self { x, y } = Self::from_xyz(xyz);
+        [x, y, xyz[1]]
+    }
+
+    /// Convert `xyY` to XYZ, mapping chromaticities with `y = 0` to black.
+    pub(crate) const fn xyy_to_xyz([x, y, big_y]: [f32; 3]) -> [f32; 3] {
+        if y == 0. {
+            return [0., 0., 0.];
+        }
+        let [x, _, z] = Self { x, y }.to_xyz();
+        [x * big_y, big_y, z * big_y]
+    }
+
+    /// Calculate the 3x3 linear chromatic adaptation matrix from linear sRGB space.
     ///
     /// This calculates the matrix going from a reference white of `self` to a reference white of
-    /// `to`.
-    pub(crate) const fn linear_srgb_chromatic_adaptation_matrix(self, to: Self) -> [[f32; 3]; 3] {
-        let bradford_source = matvecmul(&Self::XYZ_TO_BRADFORD, self.to_xyz());
-        let bradford_dest = matvecmul(&Self::XYZ_TO_BRADFORD, to.to_xyz());
+    /// `to`, using the given chromatic adaptation transform.
+    pub(crate) const fn linear_srgb_chromatic_adaptation_matrix(
+        self,
+        to: Self,
+        method: ChromaticAdaptation,
+    ) -> [[f32; 3]; 3] {
+        let xyz_to_cone = method.xyz_to_cone_matrix();
+        let cone_source = matvecmul(&xyz_to_cone, self.to_xyz());
+        let cone_dest = matvecmul(&xyz_to_cone, to.to_xyz());
+
+        // The Bradford matrices are precalculated with exact rational arithmetic.
+        let (srgb_to_cone, cone_to_srgb) = match method {
+            ChromaticAdaptation::Bradford => (Self::SRGB_TO_BRADFORD, Self::BRADFORD_TO_SRGB),
+            _ => (
+                matmatmul(&xyz_to_cone, &LINEAR_SRGB_TO_XYZ_D65),
+                matmatmul(&XYZ_D65_TO_LINEAR_SRGB, &matinv(&xyz_to_cone)),
+            ),
+        };
 
         matmatmul(
             &matdiagmatmul(
-                &Self::BRADFORD_TO_SRGB,
+                &cone_to_srgb,
                 [
-                    bradford_dest[0] / bradford_source[0],
-                    bradford_dest[1] / bradford_source[1],
-                    bradford_dest[2] / bradford_source[2],
+                    cone_dest[0] / cone_source[0],
+                    cone_dest[1] / cone_source[1],
+                    cone_dest[2] / cone_source[2],
                 ],
             ),
-            &Self::SRGB_TO_BRADFORD,
+            &srgb_to_cone,
         )
     }
 
@@ -118,10 +405,114 @@ impl Chromaticity {
             4_639_090_845_380_000. / 4_536_975_728_019_583.,
         ],
     ];
+}
 
-    const XYZ_TO_BRADFORD: [[f32; 3]; 3] = [
-        [0.8951, 0.2664, -0.1614],
-        [-0.7502, 1.7135, 0.0367],
-        [0.0389, -0.0685, 1.0296],
-    ];
+/// A linear chromatic adaptation transform.
+///
+/// Chromatic adaptation transforms predict how colors under one reference white appear under
+/// another, by scaling the components of a cone response space by the ratio of the responses to
+/// the two reference whites. They differ in the cone response space used.
+///
+/// This is used by [`ColorSpace::chromatically_adapt_with`] and
+/// [`DynamicColor::chromatically_adapt_with`]. All other chromatic adaptation in this crate uses
+/// the linear Bradford transform, following [CSS Color Module Level 4 § 11][css-sec].
+///
+/// [`ColorSpace::chromatically_adapt_with`]: crate::ColorSpace::chromatically_adapt_with
+/// [`DynamicColor::chromatically_adapt_with`]: crate::DynamicColor::chromatically_adapt_with
+/// [css-sec]: https://www.w3.org/TR/css-color-4/#color-conversion
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChromaticAdaptation {
+    /// The linear Bradford transform, as used by CSS and ICC profiles.
+    #[default]
+    Bradford,
+    /// The transform of the CIECAM02 color appearance model.
+    Cat02,
+    /// The transform of the CAM16 color appearance model.
+    Cat16,
+    /// The von Kries transform, with the Hunt-Pointer-Estévez cone responses normalized to D65.
+    VonKries,
+    /// Scaling of the XYZ components, without a cone response space.
+    ///
+    /// This is the simplest and least accurate transform.
+    XyzScaling,
+}
+
+impl ChromaticAdaptation {
+    /// The matrix from XYZ to the cone response space of the transform.
+    const fn xyz_to_cone_matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            Self::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.0030, 0.0136, 0.9834],
+            ],
+            Self::Cat16 => [
+                [0.401_288, 0.650_173, -0.051_461],
+                [-0.250_268, 1.204_414, 0.045_854],
+                [-0.002_079, 0.048_952, 0.953_127],
+            ],
+            Self::VonKries => [
+                [0.400_24, 0.7076, -0.080_81],
+                [-0.2263, 1.165_32, 0.0457],
+                [0., 0., 0.918_22],
+            ],
+            Self::XyzScaling => [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Chromaticity;
+
+    #[test]
+    fn xyz_roundtrip() {
+        for white in [Chromaticity::D65, Chromaticity::D50, Chromaticity::ACES] {
+            let xyz = white.to_xyz();
+            assert!((xyz[1] - 1.).abs() < 1e-7);
+            let roundtripped = Chromaticity::from_xyz(xyz.map(|c| c * 0.3));
+            assert!((roundtripped.x - white.x).abs() < 1e-6);
+            assert!((roundtripped.y - white.y).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn from_cct() {
+        for (cct, white) in [
+            (2856., Chromaticity::A),
+            (5003., Chromaticity::D50),
+            (5503., Chromaticity::D55),
+            (6504., Chromaticity::D65),
+            (7504., Chromaticity::D75),
+        ] {
+            let c = Chromaticity::from_cct(cct);
+            assert!(
+                (c.x - white.x).abs() < 1e-3 && (c.y - white.y).abs() < 1e-3,
+                "{cct}: {c:?} != {white:?}"
+            );
+        }
+
+        // Temperatures are clamped.
+        assert_eq!(Chromaticity::from_cct(1000.), Chromaticity::from_cct(1667.));
+        assert_eq!(Chromaticity::from_cct(1e6), Chromaticity::from_cct(25000.));
+    }
+
+    #[test]
+    fn uv() {
+        // The `u'v'` chromaticities of D65 and D50 from CIE 15:2018, table 11.3.
+        let [u, v] = Chromaticity::D65.to_uv();
+        assert!((u - 0.1978).abs() < 1e-4 && (v - 0.4683).abs() < 1e-4);
+        let [u, v] = Chromaticity::D50.to_uv();
+        assert!((u - 0.2092).abs() < 1e-4 && (v - 0.4881).abs() < 1e-4);
+
+        let roundtripped = Chromaticity::from_uv(Chromaticity::D65.to_uv());
+        assert!((roundtripped.x - Chromaticity::D65.x).abs() < 1e-6);
+        assert!((roundtripped.y - Chromaticity::D65.y).abs() < 1e-6);
+    }
 }
@@ -8,7 +8,9 @@ use core::marker::PhantomData;
 
 use crate::{
     cache_key::{BitEq, BitHash},
-    ColorSpace, ColorSpaceLayout, ColorSpaceTag, Oklab, Oklch, PremulRgba8, Rgba8, Srgb,
+    gamut, Chromaticity, ColorSpace, ColorSpaceLayout, ColorSpaceTag, ColorTemperature,
+    ColorTemperatureMethod, IctcpPq, JzCzhz, Oklab, Oklch, PremulRgba8, Rgba8, Srgb, XyzD50,
+    XyzD65,
 };
 
 #[cfg(all(not(feature = "std"), not(test)))]
@@ -191,6 +193,28 @@ pub(crate) fn fixup_hues_for_interpolate(
     }
 }
 
+/// The deltaEz color difference between two colors given in [`JzCzhz`].
+///
+/// This is the metric defined alongside Jzazbz by Safdar et al., where the hue difference is
+/// weighted by the chroma of both colors.
+fn delta_e_z([j1, c1, h1]: [f32; 3], [j2, c2, h2]: [f32; 3]) -> f32 {
+    let dj = j1 - j2;
+    let dc = c1 - c2;
+    let dh = 2. * (c1 * c2).sqrt() * ((h1 - h2).to_radians() * 0.5).sin();
+    (dj * dj + dc * dc + dh * dh).sqrt()
+}
+
+/// The deltaE ITP color difference of ITU-R BT.2124 between two colors given in [`IctcpPq`].
+///
+/// The `Ct` axis is halved, and the result scaled such that a difference of 1 is about one just
+/// noticeable difference.
+fn delta_e_itp([i1, ct1, cp1]: [f32; 3], [i2, ct2, cp2]: [f32; 3]) -> f32 {
+    let di = i1 - i2;
+    let dt = 0.5 * (ct1 - ct2);
+    let dp = cp1 - cp2;
+    720. * (di * di + dt * dt + dp * dp).sqrt()
+}
+
 impl<CS: ColorSpace> OpaqueColor<CS> {
     /// A black color.
     ///
@@ -233,6 +257,38 @@ impl<CS: ColorSpace> OpaqueColor<CS> {
         (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt()
     }
 
+    /// Difference between two colors by the deltaEz metric of [Jzazbz].
+    ///
+    /// The colors are converted to [`JzCzhz`], with linear sRGB white at
+    /// [`Jzazbz::SDR_WHITE_LUMINANCE`]. Unlike [`OpaqueColor::difference`], this metric remains
+    /// perceptually meaningful for high dynamic range colors.
+    ///
+    /// [Jzazbz]: crate::Jzazbz
+    /// [`Jzazbz::SDR_WHITE_LUMINANCE`]: crate::Jzazbz::SDR_WHITE_LUMINANCE
+    #[must_use]
+    pub fn delta_e_z(self, other: Self) -> f32 {
+        delta_e_z(
+            CS::convert::<JzCzhz>(self.components),
+            CS::convert::<JzCzhz>(other.components),
+        )
+    }
+
+    /// Difference between two colors by the deltaE ITP metric of [ITU-R BT.2124][bt2124].
+    ///
+    /// The colors are converted to [`IctcpPq`], with linear sRGB white at
+    /// [`IctcpPq::SDR_WHITE_LUMINANCE`]. A difference of 1 is about one just noticeable difference
+    /// under the viewing conditions of HDR television.
+    ///
+    /// [bt2124]: https://www.itu.int/rec/R-REC-BT.2124
+    /// [`IctcpPq::SDR_WHITE_LUMINANCE`]: crate::IctcpPq::SDR_WHITE_LUMINANCE
+    #[must_use]
+    pub fn delta_e_itp(self, other: Self) -> f32 {
+        delta_e_itp(
+            CS::convert::<IctcpPq>(self.components),
+            CS::convert::<IctcpPq>(other.components),
+        )
+    }
+
     /// Linearly interpolate colors, without hue fixup.
     ///
     /// This method produces meaningful results in rectangular color spaces,
@@ -282,6 +338,53 @@ impl<CS: ColorSpace> OpaqueColor<CS> {
         0.2126 * r + 0.7152 * g + 0.0722 * b
     }
 
+    /// Convert the color to CIE `xyY`, relative to a D65 reference white.
+    ///
+    /// The color is converted to [`XyzD65`], and its `xy` chromaticities returned with its
+    /// luminance `Y` as `[x, y, Y]`. Black has the chromaticities of D65.
+    ///
+    /// See also [`Chromaticity::from_xyz`] and [`Chromaticity::to_uv`] for other chromaticity
+    /// coordinates.
+    #[must_use]
+    pub fn to_xyy(self) -> [f32; 3] {
+        Chromaticity::xyz_to_xyy(CS::convert::<XyzD65>(self.components), XyzD65::WHITE_POINT)
+    }
+
+    /// Create a color from CIE `xyY` components, relative to a D65 reference white.
+    ///
+    /// This is the inverse of [`OpaqueColor::to_xyy`]. Components with `y = 0` result in black.
+    #[must_use]
+    pub fn from_xyy(xyy: [f32; 3]) -> Self {
+        Self::new(XyzD65::convert::<CS>(Chromaticity::xyy_to_xyz(xyy)))
+    }
+
+    /// Convert the color to CIE `xyY`, relative to a D50 reference white.
+    ///
+    /// This is like [`OpaqueColor::to_xyy`], but converts through [`XyzD50`], as is common in
+    /// print and ICC workflows. Black has the chromaticities of D50.
+    #[must_use]
+    pub fn to_xyy_d50(self) -> [f32; 3] {
+        Chromaticity::xyz_to_xyy(CS::convert::<XyzD50>(self.components), XyzD50::WHITE_POINT)
+    }
+
+    /// Create a color from CIE `xyY` components, relative to a D50 reference white.
+    ///
+    /// This is the inverse of [`OpaqueColor::to_xyy_d50`].
+    #[must_use]
+    pub fn from_xyy_d50(xyy: [f32; 3]) -> Self {
+        Self::new(XyzD50::convert::<CS>(Chromaticity::xyy_to_xyz(xyy)))
+    }
+
+    /// Estimate the correlated color temperature and Duv of the color.
+    ///
+    /// The chromaticity of the color is determined through [`XyzD65`]. See [`ColorTemperature`]
+    /// for more details.
+    #[must_use]
+    pub fn color_temperature(self, method: ColorTemperatureMethod) -> ColorTemperature {
+        let xy = Chromaticity::from_xyz(CS::convert::<XyzD65>(self.components));
+        ColorTemperature::from_chromaticity(xy, method)
+    }
+
     /// Map components.
     #[must_use]
     pub fn map(self, f: impl Fn(f32, f32, f32) -> [f32; 3]) -> Self {
@@ -320,13 +423,17 @@ impl<CS: ColorSpace> OpaqueColor<CS> {
     #[must_use]
     pub fn map_lightness(self, f: impl Fn(f32) -> f32) -> Self {
         match CS::TAG {
-            Some(ColorSpaceTag::Lab) | Some(ColorSpaceTag::Lch) => {
-                self.map(|l, c1, c2| [100.0 * f(l * 0.01), c1, c2])
-            }
+            Some(ColorSpaceTag::Lab)
+            | Some(ColorSpaceTag::Lch)
+            | Some(ColorSpaceTag::Luv)
+            | Some(ColorSpaceTag::LchUv) => self.map(|l, c1, c2| [100.0 * f(l * 0.01), c1, c2]),
             Some(ColorSpaceTag::Oklab) | Some(ColorSpaceTag::Oklch) => {
                 self.map(|l, c1, c2| [f(l), c1, c2])
             }
-            Some(ColorSpaceTag::Hsl) => self.map(|h, s, l| [h, s, 100.0 * f(l * 0.01)]),
+            Some(ColorSpaceTag::Hsl | ColorSpaceTag::Hsluv | ColorSpaceTag::Hpluv) => {
+                self.map(|h, s, l| [h, s, 100.0 * f(l * 0.01)])
+            }
+            Some(ColorSpaceTag::Okhsl) => self.map(|h, s, l| [h, s, f(l)]),
             _ => self.map_in::<Oklab>(|l, a, b| [f(l), a, b]),
         }
     }
@@ -450,6 +557,42 @@ impl<CS: ColorSpace> AlphaColor<CS> {
         AlphaColor::new(add_alpha(components, alpha))
     }
 
+    /// Map a color into the gamut of a different color space.
+    ///
+    /// Colors that are already within the gamut of the target color space are converted
+    /// unchanged. Other colors have their chroma reduced in [Oklch] until clipping the color
+    /// results in a difference that is not noticeable, as described in
+    /// [CSS Color Module Level 4 § 13.2][css-sec]. Very light and very dark colors are mapped to
+    /// white and black respectively.
+    ///
+    /// [`Hsl`], [`Hwb`], [`Hsv`], [`Hsluv`], [`Hpluv`], [`Okhsl`] and [`Okhsv`] are mapped to the
+    /// gamut of [sRGB][Srgb].
+    ///
+    /// [css-sec]: https://www.w3.org/TR/css-color-4/#binsearch
+    /// [`Hsl`]: crate::Hsl
+    /// [`Hwb`]: crate::Hwb
+    /// [`Hsv`]: crate::Hsv
+    /// [`Hsluv`]: crate::Hsluv
+    /// [`Hpluv`]: crate::Hpluv
+    /// [`Okhsl`]: crate::Okhsl
+    /// [`Okhsv`]: crate::Okhsv
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color::{AlphaColor, Oklch, Srgb};
+    ///
+    /// let vivid_green = AlphaColor::<Oklch>::new([0.8, 0.4, 145., 1.]);
+    /// let mapped = vivid_green.map_to_gamut::<Srgb>();
+    /// assert!(mapped.components.iter().all(|x| (0. ..=1.).contains(x)));
+    /// ```
+    #[must_use]
+    pub fn map_to_gamut<TargetCs: ColorSpace>(self) -> AlphaColor<TargetCs> {
+        let (opaque, alpha) = split_alpha(self.components);
+        let components = gamut::map_to_gamut::<CS, TargetCs>(opaque);
+        AlphaColor::new(add_alpha(components, alpha))
+    }
+
     /// Convert a color to the corresponding premultiplied form.
     #[must_use]
     pub const fn premultiply(self) -> PremulColor<CS> {
@@ -464,6 +607,31 @@ impl<CS: ColorSpace> AlphaColor<CS> {
         (d[0] * d[0] + d[1] * d[1] + d[2] * d[2] + d[3] * d[3]).sqrt()
     }
 
+    /// Difference between two colors by the deltaEz metric of [Jzazbz], ignoring alpha.
+    ///
+    /// See [`OpaqueColor::delta_e_z`].
+    ///
+    /// [Jzazbz]: crate::Jzazbz
+    #[must_use]
+    pub fn delta_e_z(self, other: Self) -> f32 {
+        let (a, _) = split_alpha(self.components);
+        let (b, _) = split_alpha(other.components);
+        delta_e_z(CS::convert::<JzCzhz>(a), CS::convert::<JzCzhz>(b))
+    }
+
+    /// Difference between two colors by the deltaE ITP metric of [ITU-R BT.2124][bt2124],
+    /// ignoring alpha.
+    ///
+    /// See [`OpaqueColor::delta_e_itp`].
+    ///
+    /// [bt2124]: https://www.itu.int/rec/R-REC-BT.2124
+    #[must_use]
+    pub fn delta_e_itp(self, other: Self) -> f32 {
+        let (a, _) = split_alpha(self.components);
+        let (b, _) = split_alpha(other.components);
+        delta_e_itp(CS::convert::<IctcpPq>(a), CS::convert::<IctcpPq>(b))
+    }
+
     /// Linearly interpolate colors, without hue fixup.
     ///
     /// This method produces meaningful results in rectangular color spaces,
@@ -499,6 +667,16 @@ impl<CS: ColorSpace> AlphaColor<CS> {
         Self::new(add_alpha(CS::scale_chroma(opaque, scale), alpha))
     }
 
+    /// Estimate the correlated color temperature and Duv of the color.
+    ///
+    /// The chromaticity of the color is determined through [`XyzD65`], ignoring alpha. See
+    /// [`ColorTemperature`] for more details.
+    #[must_use]
+    pub fn color_temperature(self, method: ColorTemperatureMethod) -> ColorTemperature {
+        let (opaque, _) = split_alpha(self.components);
+        OpaqueColor::<CS>::new(opaque).color_temperature(method)
+    }
+
     /// Map components.
     #[must_use]
     pub fn map(self, f: impl Fn(f32, f32, f32, f32) -> [f32; 4]) -> Self {
@@ -537,13 +715,19 @@ impl<CS: ColorSpace> AlphaColor<CS> {
     #[must_use]
     pub fn map_lightness(self, f: impl Fn(f32) -> f32) -> Self {
         match CS::TAG {
-            Some(ColorSpaceTag::Lab) | Some(ColorSpaceTag::Lch) => {
+            Some(ColorSpaceTag::Lab)
+            | Some(ColorSpaceTag::Lch)
+            | Some(ColorSpaceTag::Luv)
+            | Some(ColorSpaceTag::LchUv) => {
                 self.map(|l, c1, c2, a| [100.0 * f(l * 0.01), c1, c2, a])
             }
             Some(ColorSpaceTag::Oklab) | Some(ColorSpaceTag::Oklch) => {
                 self.map(|l, c1, c2, a| [f(l), c1, c2, a])
             }
-            Some(ColorSpaceTag::Hsl) => self.map(|h, s, l, a| [h, s, 100.0 * f(l * 0.01), a]),
+            Some(ColorSpaceTag::Hsl | ColorSpaceTag::Hsluv | ColorSpaceTag::Hpluv) => {
+                self.map(|h, s, l, a| [h, s, 100.0 * f(l * 0.01), a])
+            }
+            Some(ColorSpaceTag::Okhsl) => self.map(|h, s, l, a| [h, s, f(l), a]),
             _ => self.map_in::<Oklab>(|l, a, b, alpha| [f(l), a, b, alpha]),
         }
     }
@@ -963,9 +1147,10 @@ mod tests {
     extern crate alloc;
 
     use super::{
-        fast_round_to_u8, fixup_hue, AlphaColor, HueDirection, PremulColor, PremulRgba8, Rgba8,
-        Srgb,
+        fast_round_to_u8, fixup_hue, AlphaColor, HueDirection, OpaqueColor, PremulColor,
+        PremulRgba8, Rgba8, Srgb,
     };
+    use crate::{Jzazbz, LinearSrgb};
 
     #[test]
     fn to_rgba8_saturation() {
@@ -1097,4 +1282,81 @@ mod tests {
 
         assert_eq!(&failures, &[0.49999997]);
     }
+
+    #[test]
+    fn delta_e_z() {
+        let a = OpaqueColor::<Srgb>::new([0.8, 0.3, 0.2]);
+        let b = OpaqueColor::<Srgb>::new([0.78, 0.32, 0.21]);
+        assert_eq!(a.delta_e_z(a), 0.);
+        assert!((a.delta_e_z(b) - b.delta_e_z(a)).abs() < 1e-7);
+
+        // For small differences, deltaEz is close to the Euclidean distance in Jzazbz.
+        let euclidean = a.convert::<Jzazbz>().difference(b.convert::<Jzazbz>());
+        assert!((a.delta_e_z(b) - euclidean).abs() < euclidean * 1e-2);
+
+        // Alpha is ignored.
+        assert_eq!(
+            a.with_alpha(0.5).delta_e_z(b.with_alpha(1.)),
+            a.delta_e_z(b)
+        );
+    }
+
+    #[test]
+    fn delta_e_itp() {
+        let white = OpaqueColor::<LinearSrgb>::new([1., 1., 1.]);
+        let brighter = OpaqueColor::<LinearSrgb>::new([1.1, 1.1, 1.1]);
+        assert_eq!(white.delta_e_itp(white), 0.);
+
+        // Reference value computed with the formulas of ITU-R BT.2100 and BT.2124 in double
+        // precision.
+        assert!((white.delta_e_itp(brighter) - 7.188_511).abs() < 1e-2);
+        assert_eq!(
+            white.with_alpha(0.5).delta_e_itp(brighter.with_alpha(1.)),
+            white.delta_e_itp(brighter)
+        );
+    }
+
+    #[test]
+    fn xyy() {
+        // The primaries of sRGB, with their luminance.
+        for (srgb, xyy) in [
+            ([1., 0., 0.], [0.64, 0.33, 0.2126]),
+            ([0., 1., 0.], [0.3, 0.6, 0.7152]),
+            ([0., 0., 1.], [0.15, 0.06, 0.0722]),
+            ([1., 1., 1.], [0.3127, 0.329, 1.]),
+            ([0., 0., 0.], [0.3127, 0.329, 0.]),
+        ] {
+            let color = OpaqueColor::<Srgb>::new(srgb);
+            let [x, y, big_y] = color.to_xyy();
+            assert!((x - xyy[0]).abs() < 1e-4 && (y - xyy[1]).abs() < 1e-4);
+            assert!((big_y - xyy[2]).abs() < 1e-4);
+            assert!(OpaqueColor::<Srgb>::from_xyy(xyy).difference(color) < 1e-3);
+        }
+
+        // White is adapted to the D50 reference white.
+        let white = OpaqueColor::<Srgb>::new([1., 1., 1.]);
+        let [x, y, big_y] = white.to_xyy_d50();
+        assert!((x - 0.3457).abs() < 1e-4 && (y - 0.3585).abs() < 1e-4);
+        assert!((big_y - 1.).abs() < 1e-5);
+        assert!(OpaqueColor::<Srgb>::from_xyy_d50([x, y, big_y]).difference(white) < 1e-4);
+    }
+
+    #[test]
+    fn color_temperature() {
+        use crate::{ColorTemperatureMethod, DynamicColor};
+
+        // sRGB white has the D65 white point.
+        let white = AlphaColor::<Srgb>::new([1., 1., 1., 0.5]);
+        let cct = white.color_temperature(ColorTemperatureMethod::Ohno2013);
+        assert!((cct.cct - 6504.).abs() < 5.);
+        assert_eq!(
+            DynamicColor::from_alpha_color(white)
+                .color_temperature(ColorTemperatureMethod::Ohno2013),
+            cct
+        );
+
+        // Warm colors have lower temperatures.
+        let warm = OpaqueColor::<Srgb>::new([1., 0.8, 0.6]);
+        assert!(warm.color_temperature(ColorTemperatureMethod::McCamy).cct < 4000.);
+    }
 }
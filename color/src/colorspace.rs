@@ -6,7 +6,12 @@ extern crate std;
 
 use core::any::TypeId;
 
-use crate::{matvecmul, tag::ColorSpaceTag, Chromaticity};
+use crate::{
+    gamut::{GamutCusp, RgbGamut},
+    matmatmul, matvecmul,
+    tag::ColorSpaceTag,
+    ChromaticAdaptation, Chromaticity, ParametricCurve, TransferFunction,
+};
 
 #[cfg(all(not(feature = "std"), not(test)))]
 use crate::floatfuncs::FloatFuncs;
@@ -39,6 +44,9 @@ use crate::floatfuncs::FloatFuncs;
 /// - [`ColorSpace::convert`] can be implemented to specialize specific conversions;
 /// - implement [`ColorSpace::scale_chroma`] if your color space has a natural representation of
 ///   chroma.
+/// - RGB color spaces defined by their primaries, white point and transfer function can instead
+///   be declared through [`RgbColorSpace`](crate::RgbColorSpace), which derives the conversions
+///   of the color space [`Rgb`](crate::Rgb).
 ///
 /// ```rust
 /// use color::{ColorSpace, ColorSpaceLayout};
@@ -154,7 +162,10 @@ pub trait ColorSpace: Clone + Copy + 'static {
             lin_srgb
         } else {
             let lin_srgb_adaptation_matrix = const {
-                Chromaticity::D65.linear_srgb_chromatic_adaptation_matrix(Self::WHITE_POINT)
+                Chromaticity::D65.linear_srgb_chromatic_adaptation_matrix(
+                    Self::WHITE_POINT,
+                    ChromaticAdaptation::Bradford,
+                )
             };
             matvecmul(&lin_srgb_adaptation_matrix, lin_srgb)
         }
@@ -182,7 +193,10 @@ pub trait ColorSpace: Clone + Copy + 'static {
             src
         } else {
             let lin_srgb_adaptation_matrix = const {
-                Self::WHITE_POINT.linear_srgb_chromatic_adaptation_matrix(Chromaticity::D65)
+                Self::WHITE_POINT.linear_srgb_chromatic_adaptation_matrix(
+                    Chromaticity::D65,
+                    ChromaticAdaptation::Bradford,
+                )
             };
             matvecmul(&lin_srgb_adaptation_matrix, src)
         };
@@ -216,18 +230,33 @@ pub trait ColorSpace: Clone + Copy + 'static {
     ///
     /// The color is assumed to be under a reference white point of `from` and is chromatically
     /// adapted to the given white point `to`. The linear Bradford transform is used to perform the
-    /// chromatic adaptation.
+    /// chromatic adaptation. See [`ColorSpace::chromatically_adapt_with`] to use a different
+    /// transform.
     fn chromatically_adapt(src: [f32; 3], from: Chromaticity, to: Chromaticity) -> [f32; 3] {
+        Self::chromatically_adapt_with(src, from, to, ChromaticAdaptation::Bradford)
+    }
+
+    /// Chromatically adapt the color between the given white point chromaticities, using the
+    /// given chromatic adaptation transform.
+    ///
+    /// The color is assumed to be under a reference white point of `from` and is chromatically
+    /// adapted to the given white point `to`.
+    fn chromatically_adapt_with(
+        src: [f32; 3],
+        from: Chromaticity,
+        to: Chromaticity,
+        method: ChromaticAdaptation,
+    ) -> [f32; 3] {
         if from == to {
             return src;
         }
 
         let lin_srgb_adaptation_matrix = if from == Chromaticity::D65 && to == Chromaticity::D50 {
-            Chromaticity::D65.linear_srgb_chromatic_adaptation_matrix(Chromaticity::D50)
+            Chromaticity::D65.linear_srgb_chromatic_adaptation_matrix(Chromaticity::D50, method)
         } else if from == Chromaticity::D50 && to == Chromaticity::D65 {
-            Chromaticity::D50.linear_srgb_chromatic_adaptation_matrix(Chromaticity::D65)
+            Chromaticity::D50.linear_srgb_chromatic_adaptation_matrix(Chromaticity::D65, method)
         } else {
-            from.linear_srgb_chromatic_adaptation_matrix(to)
+            from.linear_srgb_chromatic_adaptation_matrix(to, method)
         };
 
         let lin_srgb_adapted = matvecmul(
@@ -267,6 +296,34 @@ pub trait ColorSpace: Clone + Copy + 'static {
     /// assert_eq!(XyzD65::clip([0.4, -0.2, 1.2]), [0.4, -0.2, 1.2]);
     /// ```
     fn clip(src: [f32; 3]) -> [f32; 3];
+
+    /// Whether the color is within the gamut of the color space.
+    ///
+    /// Components are allowed to be out of bounds by up to `epsilon`, which accommodates small
+    /// errors accumulated during color conversion.
+    ///
+    /// The default implementation checks whether [clipping](ColorSpace::clip) changes any of the
+    /// components by more than `epsilon`. Color spaces without gamut limits, such as [`Lab`] and
+    /// [`XyzD65`], consider all colors to be in gamut, following
+    /// [CSS Color Module Level 4 § 13.2][css-sec]. Cylindrical color spaces derived from RGB
+    /// spaces, such as [`Hsl`], check against the gamut of the RGB space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use color::{ColorSpace, Hsl, Lab, Srgb};
+    ///
+    /// assert!(Srgb::is_in_gamut([0.4, 0.2, 1.0], 1e-4));
+    /// assert!(!Srgb::is_in_gamut([0.4, -0.2, 1.2], 1e-4));
+    /// assert!(!Hsl::is_in_gamut([120., 150., 50.], 1e-4));
+    /// assert!(Lab::is_in_gamut([50., 200., -200.], 1e-4));
+    /// ```
+    ///
+    /// [css-sec]: https://www.w3.org/TR/css-color-4/#binsearch
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        let clipped = Self::clip(src);
+        (0..3).all(|i| (clipped[i] - src[i]).abs() <= epsilon)
+    }
 }
 
 /// The layout of a color space, particularly the hue component.
@@ -371,7 +428,7 @@ impl From<LinearSrgb> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct Srgb;
 
-fn srgb_to_lin(x: f32) -> f32 {
+pub(crate) fn srgb_to_lin(x: f32) -> f32 {
     if x.abs() <= 0.04045 {
         x * (1.0 / 12.92)
     } else {
@@ -379,7 +436,7 @@ fn srgb_to_lin(x: f32) -> f32 {
     }
 }
 
-fn lin_to_srgb(x: f32) -> f32 {
+pub(crate) fn lin_to_srgb(x: f32) -> f32 {
     if x.abs() <= 0.0031308 {
         x * 12.92
     } else {
@@ -407,6 +464,8 @@ impl ColorSpace for Srgb {
             rgb_to_hsl(src, true)
         } else if TypeId::of::<TargetCS>() == TypeId::of::<Hwb>() {
             rgb_to_hwb(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsv>() {
+            rgb_to_hsv(src)
         } else {
             let lin_rgb = Self::to_linear_srgb(src);
             TargetCS::from_linear_srgb(lin_rgb)
@@ -442,26 +501,28 @@ impl From<Srgb> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct DisplayP3;
 
+pub(crate) const LINEAR_DISPLAYP3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_2, -0.224_940_18, 0.0],
+    [-0.042_056_955, 1.042_056_9, 0.0],
+    [-0.019_637_555, -0.078_636_04, 1.098_273_6],
+];
+
+pub(crate) const LINEAR_SRGB_TO_DISPLAYP3: [[f32; 3]; 3] = [
+    [0.822_461_96, 0.177_538_04, 0.0],
+    [0.033_194_2, 0.966_805_8, 0.0],
+    [0.017_082_632, 0.072_397_44, 0.910_519_96],
+];
+
 impl ColorSpace for DisplayP3 {
     const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::DisplayP3);
 
     const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
 
     fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        const LINEAR_DISPLAYP3_TO_SRGB: [[f32; 3]; 3] = [
-            [1.224_940_2, -0.224_940_18, 0.0],
-            [-0.042_056_955, 1.042_056_9, 0.0],
-            [-0.019_637_555, -0.078_636_04, 1.098_273_6],
-        ];
         matvecmul(&LINEAR_DISPLAYP3_TO_SRGB, src.map(srgb_to_lin))
     }
 
     fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        const LINEAR_SRGB_TO_DISPLAYP3: [[f32; 3]; 3] = [
-            [0.822_461_96, 0.177_538_04, 0.0],
-            [0.033_194_2, 0.966_805_8, 0.0],
-            [0.017_082_632, 0.072_397_44, 0.910_519_96],
-        ];
         matvecmul(&LINEAR_SRGB_TO_DISPLAYP3, src).map(lin_to_srgb)
     }
 
@@ -476,6 +537,44 @@ impl From<DisplayP3> for ColorSpaceTag {
     }
 }
 
+/// 🌌 The linear-light Display P3 color space.
+///
+/// This is the [Display P3](DisplayP3) color space without its transfer function, with the same
+/// primaries and white point.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` white. The natural bounds of the channels are `[0, 1]`.
+///
+/// This corresponds to the `display-p3-linear` color space in CSS Color.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearDisplayP3;
+
+impl ColorSpace for LinearDisplayP3 {
+    const IS_LINEAR: bool = true;
+
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::LinearDisplayP3);
+
+    const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_DISPLAYP3_TO_SRGB, src)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_SRGB_TO_DISPLAYP3, src)
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<LinearDisplayP3> for ColorSpaceTag {
+    fn from(_: LinearDisplayP3) -> Self {
+        Self::LinearDisplayP3
+    }
+}
+
 /// 🌌 The Adobe RGB (1998) color space.
 ///
 /// Adobe RGB is similar to [sRGB](`Srgb`) but has higher green chromaticity, thereby extending its
@@ -493,56 +592,60 @@ impl From<DisplayP3> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct A98Rgb;
 
+// XYZ_to_lin_sRGB * lin_A98_to_XYZ
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+const LINEAR_A98RGB_TO_SRGB: [[f32; 3]; 3] = [
+    [
+        (66_942_405. / 47_872_228.) as f32,
+        (-19_070_177. / 47_872_228.) as f32,
+        0.,
+    ],
+    [0., 1., 0.],
+    [
+        0.,
+        (-11_512_411. / 268_173_353.) as f32,
+        (279_685_764. / 268_173_353.) as f32,
+    ],
+];
+
+// XYZ_to_lin_A98RGB * lin_sRGB_to_XYZ
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+const LINEAR_SRGB_TO_A98RGB: [[f32; 3]; 3] = [
+    [
+        (47_872_228. / 66_942_405.) as f32,
+        (19_070_177. / 66_942_405.) as f32,
+        0.0,
+    ],
+    [0., 1., 0.],
+    [
+        0.,
+        (11_512_411. / 279_685_764.) as f32,
+        (268_173_353. / 279_685_764.) as f32,
+    ],
+];
+
+const A98_TRANSFER: TransferFunction = TransferFunction::Gamma(563. / 256.);
+
 impl ColorSpace for A98Rgb {
     const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::A98Rgb);
 
     const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
 
     fn to_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_sRGB * lin_A98_to_XYZ
-        #[expect(
-            clippy::cast_possible_truncation,
-            reason = "exact rational, truncate at compile-time"
-        )]
-        const LINEAR_A98RGB_TO_SRGB: [[f32; 3]; 3] = [
-            [
-                (66_942_405. / 47_872_228.) as f32,
-                (-19_070_177. / 47_872_228.) as f32,
-                0.,
-            ],
-            [0., 1., 0.],
-            [
-                0.,
-                (-11_512_411. / 268_173_353.) as f32,
-                (279_685_764. / 268_173_353.) as f32,
-            ],
-        ];
         matvecmul(
             &LINEAR_A98RGB_TO_SRGB,
-            [r, g, b].map(|x| x.abs().powf(563. / 256.).copysign(x)),
+            [r, g, b].map(|x| A98_TRANSFER.to_linear(x)),
         )
     }
 
     fn from_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_A98RGB * lin_sRGB_to_XYZ
-        #[expect(
-            clippy::cast_possible_truncation,
-            reason = "exact rational, truncate at compile-time"
-        )]
-        const LINEAR_SRGB_TO_A98RGB: [[f32; 3]; 3] = [
-            [
-                (47_872_228. / 66_942_405.) as f32,
-                (19_070_177. / 66_942_405.) as f32,
-                0.0,
-            ],
-            [0., 1., 0.],
-            [
-                0.,
-                (11_512_411. / 279_685_764.) as f32,
-                (268_173_353. / 279_685_764.) as f32,
-            ],
-        ];
-        matvecmul(&LINEAR_SRGB_TO_A98RGB, [r, g, b]).map(|x| x.abs().powf(256. / 563.).copysign(x))
+        matvecmul(&LINEAR_SRGB_TO_A98RGB, [r, g, b]).map(|x| A98_TRANSFER.from_linear(x))
     }
 
     fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
@@ -556,6 +659,44 @@ impl From<A98Rgb> for ColorSpaceTag {
     }
 }
 
+/// 🌌 The linear-light Adobe RGB (1998) color space.
+///
+/// This is the [Adobe RGB](A98Rgb) color space without its transfer function, with the same
+/// primaries and white point.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` white. The natural bounds of the channels are `[0, 1]`.
+///
+/// This corresponds to the `a98-rgb-linear` color space in CSS Color.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearA98Rgb;
+
+impl ColorSpace for LinearA98Rgb {
+    const IS_LINEAR: bool = true;
+
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::LinearA98Rgb);
+
+    const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_A98RGB_TO_SRGB, src)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_SRGB_TO_A98RGB, src)
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<LinearA98Rgb> for ColorSpaceTag {
+    fn from(_: LinearA98Rgb) -> Self {
+        Self::LinearA98Rgb
+    }
+}
+
 /// 🌌 The ProPhoto RGB color space.
 ///
 /// ProPhoto RGB is similar to [sRGB](`Srgb`) but has higher red, green and blue chromaticities,
@@ -576,23 +717,60 @@ impl From<A98Rgb> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct ProphotoRgb;
 
-impl ProphotoRgb {
-    fn transfer_to_linear(x: f32) -> f32 {
-        if x.abs() <= 16. / 512. {
-            x / 16.
-        } else {
-            x.abs().powf(1.8).copysign(x)
-        }
-    }
+// XYZ_to_lin_sRGB * D50_to_D65 * lin_prophoto_to_XYZ
+const LINEAR_PROPHOTORGB_TO_SRGB: [[f32; 3]; 3] = [
+    [2.034_367_6, -0.727_634_5, -0.306_733_07],
+    [-0.228_826_79, 1.231_753_3, -0.002_926_598],
+    [-0.008_558_424, -0.153_268_2, 1.161_826_6],
+];
 
-    fn transfer_from_linear(x: f32) -> f32 {
-        if x.abs() <= 1. / 512. {
-            x * 16.
-        } else {
-            x.abs().powf(1. / 1.8).copysign(x)
-        }
-    }
-}
+// XYZ_to_lin_prophoto * D65_to_D50 * lin_sRGB_to_XYZ
+const LINEAR_SRGB_TO_PROPHOTORGB: [[f32; 3]; 3] = [
+    [0.529_280_4, 0.330_153, 0.140_566_6],
+    [0.098_366_22, 0.873_463_9, 0.028_169_824],
+    [0.016_875_342, 0.117_659_41, 0.865_465_2],
+];
+
+// XYZ_to_lin_sRGB * lin_prophoto_to_XYZ
+const LINEAR_PROPHOTORGB_TO_SRGB_ABSOLUTE: [[f32; 3]; 3] = [
+    [
+        11_822_636_894_621. / 5_517_784_378_314.,
+        -2_646_118_971_832. / 4_032_227_045_691.,
+        -2_824_985_149. / 9_114_754_233.,
+    ],
+    [
+        -270_896_603_412_176. / 1_163_584_209_404_097.,
+        107_798_623_831_136. / 89_506_477_646_469.,
+        822_014_396. / 202_327_283_847.,
+    ],
+    [
+        -2412976100974. / 167_796_255_001_401.,
+        -1_777_081_293_536. / 12_907_404_230_877.,
+        879_168_464. / 1_006_099_419.,
+    ],
+];
+
+// XYZ_to_lin_prophoto * lin_sRGB_to_XYZ
+const LINEAR_SRGB_TO_PROPHOTORGB_ABSOLUTE: [[f32; 3]; 3] = [
+    [
+        7_356_071_250_722. / 14_722_127_359_275.,
+        25_825_157_007_599. / 88_332_764_155_650.,
+        1_109_596_896_521. / 6_309_483_153_975.,
+    ],
+    [
+        170_513_936_009. / 1_766_822_975_400.,
+        18_792_073_269_331. / 21_201_875_704_800.,
+        91_195_554_323. / 3_028_839_386_400.,
+    ],
+    [
+        946_201. / 40_387_053.,
+        105_017_795. / 726_966_954.,
+        8_250_997. / 7_162_236.,
+    ],
+];
+
+const PROPHOTO_TRANSFER: TransferFunction =
+    TransferFunction::Parametric(ParametricCurve::PROPHOTO_RGB);
 
 impl ColorSpace for ProphotoRgb {
     const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::ProphotoRgb);
@@ -601,77 +779,26 @@ impl ColorSpace for ProphotoRgb {
     const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
 
     fn to_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_sRGB * D50_to_D65 * lin_prophoto_to_XYZ
-        const LINEAR_PROPHOTORGB_TO_SRGB: [[f32; 3]; 3] = [
-            [2.034_367_6, -0.727_634_5, -0.306_733_07],
-            [-0.228_826_79, 1.231_753_3, -0.002_926_598],
-            [-0.008_558_424, -0.153_268_2, 1.161_826_6],
-        ];
-
         matvecmul(
             &LINEAR_PROPHOTORGB_TO_SRGB,
-            [r, g, b].map(Self::transfer_to_linear),
+            [r, g, b].map(|x| PROPHOTO_TRANSFER.to_linear(x)),
         )
     }
 
     fn from_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_prophoto * D65_to_D50 * lin_sRGB_to_XYZ
-        const LINEAR_SRGB_TO_PROPHOTORGB: [[f32; 3]; 3] = [
-            [0.529_280_4, 0.330_153, 0.140_566_6],
-            [0.098_366_22, 0.873_463_9, 0.028_169_824],
-            [0.016_875_342, 0.117_659_41, 0.865_465_2],
-        ];
-
-        matvecmul(&LINEAR_SRGB_TO_PROPHOTORGB, [r, g, b]).map(Self::transfer_from_linear)
+        matvecmul(&LINEAR_SRGB_TO_PROPHOTORGB, [r, g, b]).map(|x| PROPHOTO_TRANSFER.from_linear(x))
     }
 
     fn to_linear_srgb_absolute([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_sRGB * lin_prophoto_to_XYZ
-        const LINEAR_PROPHOTORGB_TO_SRGB: [[f32; 3]; 3] = [
-            [
-                11_822_636_894_621. / 5_517_784_378_314.,
-                -2_646_118_971_832. / 4_032_227_045_691.,
-                -2_824_985_149. / 9_114_754_233.,
-            ],
-            [
-                -270_896_603_412_176. / 1_163_584_209_404_097.,
-                107_798_623_831_136. / 89_506_477_646_469.,
-                822_014_396. / 202_327_283_847.,
-            ],
-            [
-                -2412976100974. / 167_796_255_001_401.,
-                -1_777_081_293_536. / 12_907_404_230_877.,
-                879_168_464. / 1_006_099_419.,
-            ],
-        ];
-
         matvecmul(
-            &LINEAR_PROPHOTORGB_TO_SRGB,
-            [r, g, b].map(Self::transfer_to_linear),
+            &LINEAR_PROPHOTORGB_TO_SRGB_ABSOLUTE,
+            [r, g, b].map(|x| PROPHOTO_TRANSFER.to_linear(x)),
         )
     }
 
     fn from_linear_srgb_absolute([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_prophoto * lin_sRGB_to_XYZ
-        const LINEAR_SRGB_TO_PROPHOTORGB: [[f32; 3]; 3] = [
-            [
-                7_356_071_250_722. / 14_722_127_359_275.,
-                25_825_157_007_599. / 88_332_764_155_650.,
-                1_109_596_896_521. / 6_309_483_153_975.,
-            ],
-            [
-                170_513_936_009. / 1_766_822_975_400.,
-                18_792_073_269_331. / 21_201_875_704_800.,
-                91_195_554_323. / 3_028_839_386_400.,
-            ],
-            [
-                946_201. / 40_387_053.,
-                105_017_795. / 726_966_954.,
-                8_250_997. / 7_162_236.,
-            ],
-        ];
-
-        matvecmul(&LINEAR_SRGB_TO_PROPHOTORGB, [r, g, b]).map(Self::transfer_from_linear)
+        matvecmul(&LINEAR_SRGB_TO_PROPHOTORGB_ABSOLUTE, [r, g, b])
+            .map(|x| PROPHOTO_TRANSFER.from_linear(x))
     }
 
     fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
@@ -685,6 +812,97 @@ impl From<ProphotoRgb> for ColorSpaceTag {
     }
 }
 
+/// 🌌 The linear-light ProPhoto RGB color space.
+///
+/// This is the [ProPhoto RGB](ProphotoRgb) color space without its transfer function, with the
+/// same primaries and D50 reference white.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` white. The natural bounds of the channels are `[0, 1]`.
+///
+/// This corresponds to the `prophoto-rgb-linear` color space in CSS Color.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearProphotoRgb;
+
+impl ColorSpace for LinearProphotoRgb {
+    const IS_LINEAR: bool = true;
+
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::LinearProphotoRgb);
+
+    const WHITE_POINT: Chromaticity = Chromaticity::D50;
+    const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_PROPHOTORGB_TO_SRGB, src)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_SRGB_TO_PROPHOTORGB, src)
+    }
+
+    fn to_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_PROPHOTORGB_TO_SRGB_ABSOLUTE, src)
+    }
+
+    fn from_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_SRGB_TO_PROPHOTORGB_ABSOLUTE, src)
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<LinearProphotoRgb> for ColorSpaceTag {
+    fn from(_: LinearProphotoRgb) -> Self {
+        Self::LinearProphotoRgb
+    }
+}
+
+/// 🌌 The Rec. 709 color space.
+///
+/// Rec. 709 is the color space of HDTV. It has the same primaries and white point as
+/// [sRGB](`Srgb`), but a different transfer function.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` white. The natural bounds of the channels are `[0, 1]`.
+///
+/// The color space is defined by the International Telecommunication Union [here][itu]. Its
+/// transfer function inverts the opto-electronic transfer function of the specification, see
+/// [`ParametricCurve::REC709`]. This follows how [Rec. 2020](`Rec2020`) is defined in CSS.
+///
+/// Video is commonly stored as [Y'CbCr](crate::YcbcrFormat) derived from Rec. 709 components.
+///
+/// [itu]: https://www.itu.int/rec/R-REC-BT.709/en
+#[derive(Clone, Copy, Debug)]
+pub struct Rec709;
+
+const REC709_TRANSFER: TransferFunction = TransferFunction::Parametric(ParametricCurve::REC709);
+
+impl ColorSpace for Rec709 {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Rec709);
+
+    const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        src.map(|x| REC709_TRANSFER.to_linear(x))
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        src.map(|x| REC709_TRANSFER.from_linear(x))
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<Rec709> for ColorSpaceTag {
+    fn from(_: Rec709) -> Self {
+        Self::Rec709
+    }
+}
+
 /// 🌌 The Rec. 2020 color space.
 ///
 /// Rec. 2020 is similar to [sRGB](`Srgb`) but has higher red, green and blue chromaticities,
@@ -703,12 +921,51 @@ impl From<ProphotoRgb> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct Rec2020;
 
-impl Rec2020 {
-    // These are the parameters of the transfer function defined in the Rec. 2020 specification.
-    // They are truncated here to f32 precision.
-    const A: f32 = 1.099_296_8;
-    const B: f32 = 0.018_053_97;
-}
+// XYZ_to_lin_sRGB * lin_Rec2020_to_XYZ
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+pub(crate) const LINEAR_REC2020_TO_SRGB: [[f32; 3]; 3] = [
+    [
+        (2_785_571_537. / 1_677_558_947.) as f32,
+        (-985_802_650. / 1_677_558_947.) as f32,
+        (-122_209_940. / 1_677_558_947.) as f32,
+    ],
+    [
+        (-4_638_020_506. / 37_238_079_773.) as f32,
+        (42_187_016_744. / 37_238_079_773.) as f32,
+        (-310_916_465. / 37_238_079_773.) as f32,
+    ],
+    [
+        (-97_469_024. / 5_369_968_309.) as f32,
+        (-3_780_738_464. / 37_589_778_163.) as f32,
+        (42_052_799_795. / 37_589_778_163.) as f32,
+    ],
+];
+
+// XYZ_to_lin_Rec2020 * lin_sRGB_to_XYZ
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "exact rational, truncate at compile-time"
+)]
+pub(crate) const LINEAR_SRGB_TO_REC2020: [[f32; 3]; 3] = [
+    [
+        (2_939_026_994. / 4_684_425_795.) as f32,
+        (9_255_011_753. / 28_106_554_770.) as f32,
+        (173_911_579. / 4_015_222_110.) as f32,
+    ],
+    [
+        (76_515_593. / 1_107_360_270.) as f32,
+        (6_109_575_001. / 6_644_161_620.) as f32,
+        (75_493_061. / 6_644_161_620.) as f32,
+    ],
+    [
+        (12_225_392. / 745_840_075.) as f32,
+        (1_772_384_008. / 20_137_682_025.) as f32,
+        (18_035_212_433. / 20_137_682_025.) as f32,
+    ],
+];
 
 impl ColorSpace for Rec2020 {
     const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Rec2020);
@@ -716,74 +973,15 @@ impl ColorSpace for Rec2020 {
     const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
 
     fn to_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_sRGB * lin_Rec2020_to_XYZ
-        #[expect(
-            clippy::cast_possible_truncation,
-            reason = "exact rational, truncate at compile-time"
-        )]
-        const LINEAR_REC2020_TO_SRGB: [[f32; 3]; 3] = [
-            [
-                (2_785_571_537. / 1_677_558_947.) as f32,
-                (-985_802_650. / 1_677_558_947.) as f32,
-                (-122_209_940. / 1_677_558_947.) as f32,
-            ],
-            [
-                (-4_638_020_506. / 37_238_079_773.) as f32,
-                (42_187_016_744. / 37_238_079_773.) as f32,
-                (-310_916_465. / 37_238_079_773.) as f32,
-            ],
-            [
-                (-97_469_024. / 5_369_968_309.) as f32,
-                (-3_780_738_464. / 37_589_778_163.) as f32,
-                (42_052_799_795. / 37_589_778_163.) as f32,
-            ],
-        ];
-
-        fn transfer(x: f32) -> f32 {
-            if x.abs() < Rec2020::B * 4.5 {
-                x * (1. / 4.5)
-            } else {
-                ((x.abs() + (Rec2020::A - 1.)) / Rec2020::A)
-                    .powf(1. / 0.45)
-                    .copysign(x)
-            }
-        }
-
-        matvecmul(&LINEAR_REC2020_TO_SRGB, [r, g, b].map(transfer))
+        matvecmul(
+            &LINEAR_REC2020_TO_SRGB,
+            [r, g, b].map(|x| TransferFunction::Rec2020.to_linear(x)),
+        )
     }
 
     fn from_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_Rec2020 * lin_sRGB_to_XYZ
-        #[expect(
-            clippy::cast_possible_truncation,
-            reason = "exact rational, truncate at compile-time"
-        )]
-        const LINEAR_SRGB_TO_REC2020: [[f32; 3]; 3] = [
-            [
-                (2_939_026_994. / 4_684_425_795.) as f32,
-                (9_255_011_753. / 28_106_554_770.) as f32,
-                (173_911_579. / 4_015_222_110.) as f32,
-            ],
-            [
-                (76_515_593. / 1_107_360_270.) as f32,
-                (6_109_575_001. / 6_644_161_620.) as f32,
-                (75_493_061. / 6_644_161_620.) as f32,
-            ],
-            [
-                (12_225_392. / 745_840_075.) as f32,
-                (1_772_384_008. / 20_137_682_025.) as f32,
-                (18_035_212_433. / 20_137_682_025.) as f32,
-            ],
-        ];
-
-        fn transfer(x: f32) -> f32 {
-            if x.abs() < Rec2020::B {
-                x * 4.5
-            } else {
-                (Rec2020::A * x.abs().powf(0.45) - (Rec2020::A - 1.)).copysign(x)
-            }
-        }
-        matvecmul(&LINEAR_SRGB_TO_REC2020, [r, g, b]).map(transfer)
+        matvecmul(&LINEAR_SRGB_TO_REC2020, [r, g, b])
+            .map(|x| TransferFunction::Rec2020.from_linear(x))
     }
 
     fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
@@ -797,63 +995,195 @@ impl From<Rec2020> for ColorSpaceTag {
     }
 }
 
-/// 🌌 The ACES2065-1 color space.
+/// 🌌 The linear-light Rec. 2020 color space.
 ///
-/// This is a linear color space with a very wide gamut. It is is often used for archival and
-/// interchange.
+/// This is the [Rec. 2020](Rec2020) color space without its transfer function, with the same
+/// primaries and white point.
 ///
 /// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
-/// pure black and `[1, 1, 1]` white. The natural bounds of the components are
-/// `[-65504.0, 65504.0]`.
-///
-/// This color space is [characterized by the Academy Color Encoding System][aces20651] and is
-/// specified in [SMPTE ST 2065-1:2021][smpte].
-///
-/// ACES2065-1 has a reference white [near D60][aceswp]; see the [XYZ-D65 color space](`XyzD65`)
-/// documentation for some background information on the meaning of "reference white."
-///
-/// See also [`AcesCg`].
+/// pure black and `[1, 1, 1]` white. The natural bounds of the channels are `[0, 1]`.
 ///
-/// [aces20651]: https://draftdocs.acescentral.com/specifications/encodings/aces2065-1/
-/// [smpte]: https://pub.smpte.org/doc/st2065-1/20200909-pub/st2065-1-2021.pdf
-/// [aceswp]: https://docs.acescentral.com/tb/white-point
+/// This corresponds to the `rec2020-linear` color space in CSS Color.
 #[derive(Clone, Copy, Debug)]
-pub struct Aces2065_1;
+pub struct LinearRec2020;
 
-impl ColorSpace for Aces2065_1 {
+impl ColorSpace for LinearRec2020 {
     const IS_LINEAR: bool = true;
 
-    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Aces2065_1);
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::LinearRec2020);
 
-    const WHITE_POINT: Chromaticity = Chromaticity::ACES;
-    const WHITE_COMPONENTS: [f32; 3] = [1.0, 1.0, 1.0];
+    const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
 
     fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_sRGB * ACESwp_to_D65 * ACES2065_1_to_XYZ
-        const ACES2065_1_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
-            [2.521_686, -1.134_131, -0.387_555_2],
-            [-0.276_479_9, 1.372_719, -0.096_239_17],
-            [-0.015_378_065, -0.152_975_34, 1.168_353_4],
-        ];
-        matvecmul(&ACES2065_1_TO_LINEAR_SRGB, src)
+        matvecmul(&LINEAR_REC2020_TO_SRGB, src)
     }
 
     fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_ACES2065_1 * D65_to_ACESwp * lin_sRGB_to_XYZ
-        const LINEAR_SRGB_TO_ACES2065_1: [[f32; 3]; 3] = [
-            [0.439_632_98, 0.382_988_7, 0.177_378_33],
-            [0.089_776_44, 0.813_439_4, 0.096_784_13],
-            [0.017_541_17, 0.111_546_55, 0.870_912_25],
-        ];
-        matvecmul(&LINEAR_SRGB_TO_ACES2065_1, src)
+        matvecmul(&LINEAR_SRGB_TO_REC2020, src)
     }
 
-    fn to_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
-        // XYZ_to_lin_sRGB * ACES2065_1_to_XYZ
-        const ACES2065_1_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
-            [
-                54_120_196_967_290_615. / 21_154_043_450_084_358.,
-                -320_017_885_460_000. / 285_865_452_028_167.,
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<LinearRec2020> for ColorSpaceTag {
+    fn from(_: LinearRec2020) -> Self {
+        Self::LinearRec2020
+    }
+}
+
+/// 🌌 The Rec. 2100 PQ color space.
+///
+/// This has the primaries of [Rec. 2020](Rec2020), with the perceptual quantizer of SMPTE ST 2084
+/// as defined in [ITU-R BT.2100][bt2100] as transfer function, for high dynamic range content.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` a luminance of 10,000 cd/m². The natural bounds of the channels are
+/// `[0, 1]`. Linear sRGB white is mapped to the SDR reference white of 203 cd/m² of
+/// [ITU-R BT.2408][bt2408], which has components of about 0.58.
+///
+/// This corresponds to the `rec2100-pq` color space of the [CSS Color HDR Module][css-sec].
+///
+/// [bt2100]: https://www.itu.int/rec/R-REC-BT.2100
+/// [bt2408]: https://www.itu.int/pub/R-REP-BT.2408
+/// [css-sec]: https://drafts.csswg.org/css-color-hdr/#valdef-color-rec2100-pq
+#[derive(Clone, Copy, Debug)]
+pub struct Rec2100Pq;
+
+impl ColorSpace for Rec2100Pq {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Rec2100Pq);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.580_688_8, 0.580_688_8, 0.580_688_8];
+
+    fn to_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
+        const SCALE: f32 = 10_000. / SDR_WHITE_LUMINANCE;
+        matvecmul(
+            &LINEAR_REC2020_TO_SRGB,
+            [r, g, b].map(|x| TransferFunction::Pq.to_linear(x) * SCALE),
+        )
+    }
+
+    fn from_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
+        const SCALE: f32 = SDR_WHITE_LUMINANCE / 10_000.;
+        matvecmul(&LINEAR_SRGB_TO_REC2020, [r, g, b])
+            .map(|x| TransferFunction::Pq.from_linear(x * SCALE))
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<Rec2100Pq> for ColorSpaceTag {
+    fn from(_: Rec2100Pq) -> Self {
+        Self::Rec2100Pq
+    }
+}
+
+/// 🌌 The Rec. 2100 HLG color space.
+///
+/// This has the primaries of [Rec. 2020](Rec2020), with the hybrid log-gamma transfer function
+/// defined in [ITU-R BT.2100][bt2100], for high dynamic range content.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` the peak of the HLG signal. The natural bounds of the channels are
+/// `[0, 1]`. Linear sRGB white is mapped to HLG reference white of [ITU-R BT.2408][bt2408], which
+/// has components of 0.75.
+///
+/// This corresponds to the `rec2100-hlg` color space of the [CSS Color HDR Module][css-sec].
+///
+/// [bt2100]: https://www.itu.int/rec/R-REC-BT.2100
+/// [bt2408]: https://www.itu.int/pub/R-REP-BT.2408
+/// [css-sec]: https://drafts.csswg.org/css-color-hdr/#valdef-color-rec2100-hlg
+#[derive(Clone, Copy, Debug)]
+pub struct Rec2100Hlg;
+
+impl ColorSpace for Rec2100Hlg {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Rec2100Hlg);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.75, 0.75, 0.75];
+
+    fn to_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
+        matvecmul(
+            &LINEAR_REC2020_TO_SRGB,
+            [r, g, b].map(|x| TransferFunction::Hlg.to_linear(x) / HLG_REFERENCE_WHITE),
+        )
+    }
+
+    fn from_linear_srgb([r, g, b]: [f32; 3]) -> [f32; 3] {
+        matvecmul(&LINEAR_SRGB_TO_REC2020, [r, g, b])
+            .map(|x| TransferFunction::Hlg.from_linear(x * HLG_REFERENCE_WHITE))
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+impl From<Rec2100Hlg> for ColorSpaceTag {
+    fn from(_: Rec2100Hlg) -> Self {
+        Self::Rec2100Hlg
+    }
+}
+
+/// 🌌 The ACES2065-1 color space.
+///
+/// This is a linear color space with a very wide gamut. It is is often used for archival and
+/// interchange.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` white. The natural bounds of the components are
+/// `[-65504.0, 65504.0]`.
+///
+/// This color space is [characterized by the Academy Color Encoding System][aces20651] and is
+/// specified in [SMPTE ST 2065-1:2021][smpte].
+///
+/// ACES2065-1 has a reference white [near D60][aceswp]; see the [XYZ-D65 color space](`XyzD65`)
+/// documentation for some background information on the meaning of "reference white."
+///
+/// See also [`AcesCg`].
+///
+/// [aces20651]: https://draftdocs.acescentral.com/specifications/encodings/aces2065-1/
+/// [smpte]: https://pub.smpte.org/doc/st2065-1/20200909-pub/st2065-1-2021.pdf
+/// [aceswp]: https://docs.acescentral.com/tb/white-point
+#[derive(Clone, Copy, Debug)]
+pub struct Aces2065_1;
+
+impl ColorSpace for Aces2065_1 {
+    const IS_LINEAR: bool = true;
+
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Aces2065_1);
+
+    const WHITE_POINT: Chromaticity = Chromaticity::ACES;
+    const WHITE_COMPONENTS: [f32; 3] = [1.0, 1.0, 1.0];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        // XYZ_to_lin_sRGB * ACESwp_to_D65 * ACES2065_1_to_XYZ
+        const ACES2065_1_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+            [2.521_686, -1.134_131, -0.387_555_2],
+            [-0.276_479_9, 1.372_719, -0.096_239_17],
+            [-0.015_378_065, -0.152_975_34, 1.168_353_4],
+        ];
+        matvecmul(&ACES2065_1_TO_LINEAR_SRGB, src)
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        // XYZ_to_ACES2065_1 * D65_to_ACESwp * lin_sRGB_to_XYZ
+        const LINEAR_SRGB_TO_ACES2065_1: [[f32; 3]; 3] = [
+            [0.439_632_98, 0.382_988_7, 0.177_378_33],
+            [0.089_776_44, 0.813_439_4, 0.096_784_13],
+            [0.017_541_17, 0.111_546_55, 0.870_912_25],
+        ];
+        matvecmul(&LINEAR_SRGB_TO_ACES2065_1, src)
+    }
+
+    fn to_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        // XYZ_to_lin_sRGB * ACES2065_1_to_XYZ
+        const ACES2065_1_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+            [
+                54_120_196_967_290_615. / 21_154_043_450_084_358.,
+                -320_017_885_460_000. / 285_865_452_028_167.,
                 -564_067_687_050. / 1_439_638_182_257.,
             ],
             [
@@ -1015,6 +1345,116 @@ impl From<AcesCg> for ColorSpaceTag {
     }
 }
 
+/// 🌌 The ACEScc color space.
+///
+/// ACEScc is a logarithmic encoding of [ACEScg](AcesCg), with the same primaries and reference
+/// white. It is designed as a working space for color grading, where its logarithmic encoding
+/// makes adjustments behave more like those on film.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively). Linear-light
+/// `[0, 0, 0]` and below is encoded as about `[-0.3584, -0.3584, -0.3584]`, and reference white as
+/// about `[0.5548, 0.5548, 0.5548]`. The natural bounds of the components are
+/// `[-0.3584, 1.468]`, which are the encodings of zero and the largest half-precision float.
+///
+/// This color space is defined by the Academy Color Encoding System [specification][acescc]. The
+/// encoding is available as [`TransferFunction::AcesCc`].
+///
+/// See also [`AcesCct`].
+///
+/// [acescc]: https://docs.acescentral.com/specifications/acescc/
+#[derive(Clone, Copy, Debug)]
+pub struct AcesCc;
+
+impl ColorSpace for AcesCc {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::AcesCc);
+
+    const WHITE_POINT: Chromaticity = Chromaticity::ACES;
+    const WHITE_COMPONENTS: [f32; 3] = [0.554_794_5, 0.554_794_5, 0.554_794_5];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::to_linear_srgb(src.map(|x| TransferFunction::AcesCc.to_linear(x)))
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::from_linear_srgb(src).map(|x| TransferFunction::AcesCc.from_linear(x))
+    }
+
+    fn to_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::to_linear_srgb_absolute(src.map(|x| TransferFunction::AcesCc.to_linear(x)))
+    }
+
+    fn from_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::from_linear_srgb_absolute(src).map(|x| TransferFunction::AcesCc.from_linear(x))
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        const MIN: f32 = -0.358_447_5;
+        const MAX: f32 = 1.467_996_3;
+        [r.clamp(MIN, MAX), g.clamp(MIN, MAX), b.clamp(MIN, MAX)]
+    }
+}
+
+impl From<AcesCc> for ColorSpaceTag {
+    fn from(_: AcesCc) -> Self {
+        Self::AcesCc
+    }
+}
+
+/// 🌌 The ACEScct color space.
+///
+/// ACEScct is a logarithmic encoding of [ACEScg](AcesCg), with the same primaries and reference
+/// white. It is identical to [ACEScc](AcesCc) above about linear-light `0.0078`, but has a linear
+/// toe below that, giving shadows a response more like traditional log film scans. It is the
+/// most common color space for interpolating color grades.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with
+/// linear-light `[0, 0, 0]` encoded as about `[0.0729, 0.0729, 0.0729]` and reference white as
+/// about `[0.5548, 0.5548, 0.5548]`. The natural upper bound of the components is `1.468`, the
+/// encoding of the largest half-precision float, and the linear toe extends to negative values.
+///
+/// This color space is defined by the Academy Color Encoding System [specification][acescct].
+/// The encoding is available as [`TransferFunction::AcesCct`].
+///
+/// [acescct]: https://docs.acescentral.com/specifications/acescct/
+#[derive(Clone, Copy, Debug)]
+pub struct AcesCct;
+
+impl ColorSpace for AcesCct {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::AcesCct);
+
+    const WHITE_POINT: Chromaticity = Chromaticity::ACES;
+    const WHITE_COMPONENTS: [f32; 3] = [0.554_794_5, 0.554_794_5, 0.554_794_5];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::to_linear_srgb(src.map(|x| TransferFunction::AcesCct.to_linear(x)))
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::from_linear_srgb(src).map(|x| TransferFunction::AcesCct.from_linear(x))
+    }
+
+    fn to_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::to_linear_srgb_absolute(src.map(|x| TransferFunction::AcesCct.to_linear(x)))
+    }
+
+    fn from_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        AcesCg::from_linear_srgb_absolute(src).map(|x| TransferFunction::AcesCct.from_linear(x))
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        // The encodings of -65504 and 65504, matching the bounds of ACEScg.
+        const MIN: f32 = -690_427.7;
+        const MAX: f32 = 1.467_996_3;
+        [r.clamp(MIN, MAX), g.clamp(MIN, MAX), b.clamp(MIN, MAX)]
+    }
+}
+
+impl From<AcesCct> for ColorSpaceTag {
+    fn from(_: AcesCct) -> Self {
+        Self::AcesCct
+    }
+}
+
 /// 🌌 The CIE XYZ color space with a 2° observer and a reference white of D50.
 ///
 /// Its components are `[X, Y, Z]`. The components are unbounded, but are usually positive.
@@ -1064,6 +1504,10 @@ impl ColorSpace for XyzD50 {
     fn clip([x, y, z]: [f32; 3]) -> [f32; 3] {
         [x, y, z]
     }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
 }
 
 impl From<XyzD50> for ColorSpaceTag {
@@ -1125,6 +1569,26 @@ impl From<XyzD50> for ColorSpaceTag {
 #[derive(Clone, Copy, Debug)]
 pub struct XyzD65;
 
+pub(crate) const XYZ_D65_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+    [12_831. / 3_959., -329. / 214., -1_974. / 3_959.],
+    [
+        -851_781. / 878_810.,
+        1_648_619. / 878_810.,
+        36_519. / 878_810.,
+    ],
+    [705. / 12_673., -2_585. / 12_673., 705. / 667.],
+];
+
+pub(crate) const LINEAR_SRGB_TO_XYZ_D65: [[f32; 3]; 3] = [
+    [506_752. / 1_228_815., 87_881. / 245_763., 12_673. / 70_218.],
+    [87_098. / 409_605., 175_762. / 245_763., 12_673. / 175_545.],
+    [
+        7_918. / 409_605.,
+        87_881. / 737_289.,
+        100_1167. / 1_053_270.,
+    ],
+];
+
 impl ColorSpace for XyzD65 {
     const IS_LINEAR: bool = true;
 
@@ -1133,34 +1597,20 @@ impl ColorSpace for XyzD65 {
     const WHITE_COMPONENTS: [f32; 3] = [3127. / 3290., 1., 3583. / 3290.];
 
     fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        const XYZ_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
-            [12_831. / 3_959., -329. / 214., -1_974. / 3_959.],
-            [
-                -851_781. / 878_810.,
-                1_648_619. / 878_810.,
-                36_519. / 878_810.,
-            ],
-            [705. / 12_673., -2_585. / 12_673., 705. / 667.],
-        ];
-        matvecmul(&XYZ_TO_LINEAR_SRGB, src)
+        matvecmul(&XYZ_D65_TO_LINEAR_SRGB, src)
     }
 
     fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        const LINEAR_SRGB_TO_XYZ: [[f32; 3]; 3] = [
-            [506_752. / 1_228_815., 87_881. / 245_763., 12_673. / 70_218.],
-            [87_098. / 409_605., 175_762. / 245_763., 12_673. / 175_545.],
-            [
-                7_918. / 409_605.,
-                87_881. / 737_289.,
-                100_1167. / 1_053_270.,
-            ],
-        ];
-        matvecmul(&LINEAR_SRGB_TO_XYZ, src)
+        matvecmul(&LINEAR_SRGB_TO_XYZ_D65, src)
     }
 
     fn clip([x, y, z]: [f32; 3]) -> [f32; 3] {
         [x, y, z]
     }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
 }
 
 impl From<XyzD65> for ColorSpaceTag {
@@ -1194,25 +1644,25 @@ pub struct Oklab;
 // Matrices taken from [Oklab] blog post, precision reduced to f32
 //
 // [Oklab]: https://bottosson.github.io/posts/oklab/
-const OKLAB_LAB_TO_LMS: [[f32; 3]; 3] = [
+pub(crate) const OKLAB_LAB_TO_LMS: [[f32; 3]; 3] = [
     [1.0, 0.396_337_78, 0.215_803_76],
     [1.0, -0.105_561_346, -0.063_854_17],
     [1.0, -0.089_484_18, -1.291_485_5],
 ];
 
-const OKLAB_LMS_TO_SRGB: [[f32; 3]; 3] = [
+pub(crate) const OKLAB_LMS_TO_SRGB: [[f32; 3]; 3] = [
     [4.076_741_7, -3.307_711_6, 0.230_969_94],
     [-1.268_438, 2.609_757_4, -0.341_319_38],
     [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
 ];
 
-const OKLAB_SRGB_TO_LMS: [[f32; 3]; 3] = [
+pub(crate) const OKLAB_SRGB_TO_LMS: [[f32; 3]; 3] = [
     [0.412_221_46, 0.536_332_55, 0.051_445_995],
     [0.211_903_5, 0.680_699_5, 0.107_396_96],
     [0.088_302_46, 0.281_718_85, 0.629_978_7],
 ];
 
-const OKLAB_LMS_TO_LAB: [[f32; 3]; 3] = [
+pub(crate) const OKLAB_LMS_TO_LAB: [[f32; 3]; 3] = [
     [0.210_454_26, 0.793_617_8, -0.004_072_047],
     [1.977_998_5, -2.428_592_2, 0.450_593_7],
     [0.025_904_037, 0.782_771_77, -0.808_675_77],
@@ -1251,6 +1701,10 @@ impl ColorSpace for Oklab {
     fn clip([l, a, b]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 1.), a, b]
     }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
 }
 
 impl From<Oklab> for ColorSpaceTag {
@@ -1320,6 +1774,10 @@ impl ColorSpace for Oklch {
     fn clip([l, c, h]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 1.), c.max(0.), h]
     }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
 }
 
 impl From<Oklch> for ColorSpaceTag {
@@ -1434,6 +1892,10 @@ impl ColorSpace for Lab {
     fn clip([l, a, b]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 100.), a, b]
     }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
 }
 
 impl From<Lab> for ColorSpaceTag {
@@ -1487,6 +1949,10 @@ impl ColorSpace for Lch {
     fn clip([l, c, h]: [f32; 3]) -> [f32; 3] {
         [l.clamp(0., 100.), c.max(0.), h]
     }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
 }
 
 impl From<Lch> for ColorSpaceTag {
@@ -1495,92 +1961,1297 @@ impl From<Lch> for ColorSpaceTag {
     }
 }
 
-/// 🌌 The HSL color space
+/// 🌌 The CIELUV color space
 ///
-/// The HSL color space is fairly widely used and convenient, but it is
-/// not based on sound color science. Among its flaws, colors with the
-/// same "lightness" value can have wildly varying perceptual lightness.
+/// The CIE L\*u\*v\* color space was created in 1976 alongside [CIELAB](Lab), and is commonly
+/// used for emissive displays and lighting. Its chromaticity plane is a projective transform of
+/// CIE 1931 xy chromaticity, so additive mixtures of colors lie on straight lines in it.
 ///
-/// Its components are `[H, S, L]` with
-/// - `H` - the hue angle in degrees, with red at 0, green at 120, and blue at 240.
-/// - `S` - the saturation, where 0 is gray and 100 is maximally saturated.
-/// - `L` - the lightness, where 0 is black and 100 is white.
+/// Its components are `[L, u, v]` with
+/// - `L` - the lightness as in [`Lab`], with a natural bound between 0 and 100;
+/// - `u` - how green/red the color is; and
+/// - `v` - how blue/yellow the color is.
 ///
-/// This corresponds to the color space in [CSS Color Module Level 4 § 7][css-sec].
+/// `u` and `v` are unbounded, but are usually between -100 and 200 for colors within the sRGB
+/// gamut.
 ///
-/// [css-sec]: https://www.w3.org/TR/css-color-4/#the-hsl-notation
+/// Unlike [`Lab`], this color space is defined here relative to a D65 white point, matching
+/// [`Hsluv`] and [`Hpluv`], so no chromatic adaptation is needed for conversion from
+/// sRGB.
 #[derive(Clone, Copy, Debug)]
-pub struct Hsl;
+pub struct Luv;
 
-/// Convert HSL to RGB.
-///
-/// Reference: § 7.1 of CSS Color 4 spec.
-fn hsl_to_rgb([h, s, l]: [f32; 3]) -> [f32; 3] {
-    // Don't need mod 360 for hue, it's subsumed by mod 12 below.
-    let sat = s * 0.01;
-    let light = l * 0.01;
-    let a = sat * light.min(1.0 - light);
-    [0.0, 8.0, 4.0].map(|n| {
-        let x = n + h * (1.0 / 30.0);
-        let k = x - 12.0 * (x * (1.0 / 12.0)).floor();
-        light - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
-    })
+// The reference white of CIELUV is D65, and these are its u' and v' chromaticity coordinates.
+const LUV_WHITE_U: f32 = (4. * 3127. / 3290.) / (3127. / 3290. + 15. + 3. * 3583. / 3290.);
+const LUV_WHITE_V: f32 = 9. / (3127. / 3290. + 15. + 3. * 3583. / 3290.);
+
+/// Convert CIELUV lightness to relative luminance `Y`.
+fn luv_lightness_to_y(l: f32) -> f32 {
+    if l > KAPPA * EPSILON {
+        let f = l * (1. / 116.) + (16. / 116.);
+        f * f * f
+    } else {
+        l * (1. / KAPPA)
+    }
 }
 
-/// Convert RGB to HSL.
-///
-/// Reference: § 7.2 of CSS Color 4 spec.
-///
-/// See <https://github.com/w3c/csswg-drafts/issues/10695> for an
-/// explanation of why `hue_hack` is needed.
-fn rgb_to_hsl([r, g, b]: [f32; 3], hue_hack: bool) -> [f32; 3] {
-    let max = r.max(g).max(b);
-    let min = r.min(g).min(b);
-    let mut hue = 0.0;
-    let mut sat = 0.0;
-    let light = 0.5 * (min + max);
-    let d = max - min;
+impl ColorSpace for Luv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Luv);
 
-    const EPSILON: f32 = 1e-6;
-    if d > EPSILON {
-        let denom = light.min(1.0 - light);
-        if denom.abs() > EPSILON {
-            sat = (max - light) / denom;
+    const WHITE_COMPONENTS: [f32; 3] = [100., 0., 0.];
+
+    fn to_linear_srgb([l, u, v]: [f32; 3]) -> [f32; 3] {
+        if l == 0. {
+            return [0., 0., 0.];
         }
-        hue = if max == r {
-            (g - b) / d
-        } else if max == g {
-            (b - r) / d + 2.0
+        let u_prime = u / (13. * l) + LUV_WHITE_U;
+        let v_prime = v / (13. * l) + LUV_WHITE_V;
+        let y = luv_lightness_to_y(l);
+        let x = y * 9. * u_prime / (4. * v_prime);
+        let z = y * (12. - 3. * u_prime - 20. * v_prime) / (4. * v_prime);
+        XyzD65::to_linear_srgb([x, y, z])
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let [x, y, z] = XyzD65::from_linear_srgb(src);
+        let l = if y > EPSILON {
+            116. * y.cbrt() - 16.
         } else {
-            // max == b
-            (r - g) / d + 4.0
+            KAPPA * y
         };
-        hue *= 60.0;
-        // Deal with negative saturation from out of gamut colors
+        let denom = x + 15. * y + 3. * z;
+        if denom == 0. {
+            return [l, 0., 0.];
+        }
+        let u = 13. * l * (4. * x / denom - LUV_WHITE_U);
+        let v = 13. * l * (9. * y / denom - LUV_WHITE_V);
+        [l, u, v]
+    }
+
+    fn scale_chroma([l, u, v]: [f32; 3], scale: f32) -> [f32; 3] {
+        [l, u * scale, v * scale]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<LchUv>() {
+            lab_to_lch(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([l, u, v]: [f32; 3]) -> [f32; 3] {
+        [l.clamp(0., 100.), u, v]
+    }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
+}
+
+impl From<Luv> for ColorSpaceTag {
+    fn from(_: Luv) -> Self {
+        Self::Luv
+    }
+}
+
+/// 🌌 The cylindrical version of the [Luv] color space, also known as LCh(uv).
+///
+/// Its components are `[L, C, h]` with
+/// - `L` - the lightness as in [`Luv`];
+/// - `C` - the chromatic intensity, the natural lower bound of 0 being achromatic, usually not
+///   exceeding 180 for colors within the sRGB gamut; and
+/// - `h` - the hue angle in degrees.
+#[derive(Clone, Copy, Debug)]
+pub struct LchUv;
+
+impl ColorSpace for LchUv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::LchUv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueThird;
+
+    const WHITE_COMPONENTS: [f32; 3] = [100., 0., 0.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        lab_to_lch(Luv::from_linear_srgb(src))
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Luv::to_linear_srgb(lch_to_lab(src))
+    }
+
+    fn scale_chroma([l, c, h]: [f32; 3], scale: f32) -> [f32; 3] {
+        [l, c * scale, h]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Luv>() {
+            lch_to_lab(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsluv>() {
+            lchuv_to_hsluv(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hpluv>() {
+            lchuv_to_hpluv(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([l, c, h]: [f32; 3]) -> [f32; 3] {
+        [l.clamp(0., 100.), c.max(0.), h]
+    }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
+}
+
+impl From<LchUv> for ColorSpaceTag {
+    fn from(_: LchUv) -> Self {
+        Self::LchUv
+    }
+}
+
+/// The lines bounding the sRGB gamut in the `u`/`v` plane of [`Luv`] at lightness `l`, as
+/// `[slope, intercept]` pairs of `v = slope * u + intercept`.
+///
+/// Each line is where one of the sRGB channels is 0 or 1.
+fn luv_srgb_bounds(l: f32) -> [[f32; 2]; 6] {
+    let y = luv_lightness_to_y(l);
+    let mut bounds = [[0.; 2]; 6];
+    for (i, [m1, m2, m3]) in XYZ_D65_TO_LINEAR_SRGB.into_iter().enumerate() {
+        for (j, channel) in [0., 1.].into_iter().enumerate() {
+            // Substitute the inverse transform of `Luv` in `m1 * x + m2 * y + m3 * z = channel`,
+            // which is linear in `u'` and `v'`.
+            let a = (9. * m1 - 3. * m3) * y;
+            let b = (4. * m2 - 20. * m3) * y - 4. * channel;
+            let c = a * LUV_WHITE_U + b * LUV_WHITE_V + 12. * m3 * y;
+            bounds[2 * i + j] = [-a / b, -13. * l * c / b];
+        }
+    }
+    bounds
+}
+
+/// The maximum chroma within the sRGB gamut at the given [`LchUv`] lightness and hue.
+fn luv_max_chroma_for_hue(l: f32, h: f32) -> f32 {
+    let (sin, cos) = h.to_radians().sin_cos();
+    luv_srgb_bounds(l)
+        .into_iter()
+        .map(|[slope, intercept]| intercept / (sin - slope * cos))
+        .filter(|length| *length >= 0.)
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// The maximum chroma within the sRGB gamut at the given [`LchUv`] lightness, for all hues.
+fn luv_max_safe_chroma(l: f32) -> f32 {
+    luv_srgb_bounds(l)
+        .into_iter()
+        .map(|[slope, intercept]| intercept.abs() / (slope * slope + 1.).sqrt())
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// Lightness within this distance of 0 or 100 is considered black or white in [`Hsluv`] and
+/// [`Hpluv`], where the gamut shrinks to a point.
+const HSLUV_LIGHTNESS_EPSILON: f32 = 1e-5;
+
+fn lchuv_to_hsluv([l, c, h]: [f32; 3]) -> [f32; 3] {
+    if !(HSLUV_LIGHTNESS_EPSILON..=100. - HSLUV_LIGHTNESS_EPSILON).contains(&l) {
+        return [h, 0., l];
+    }
+    [h, c / luv_max_chroma_for_hue(l, h) * 100., l]
+}
+
+fn hsluv_to_lchuv([h, s, l]: [f32; 3]) -> [f32; 3] {
+    if !(HSLUV_LIGHTNESS_EPSILON..=100. - HSLUV_LIGHTNESS_EPSILON).contains(&l) {
+        return [l, 0., h];
+    }
+    [l, luv_max_chroma_for_hue(l, h) * 0.01 * s, h]
+}
+
+fn lchuv_to_hpluv([l, c, h]: [f32; 3]) -> [f32; 3] {
+    if !(HSLUV_LIGHTNESS_EPSILON..=100. - HSLUV_LIGHTNESS_EPSILON).contains(&l) {
+        return [h, 0., l];
+    }
+    [h, c / luv_max_safe_chroma(l) * 100., l]
+}
+
+fn hpluv_to_lchuv([h, p, l]: [f32; 3]) -> [f32; 3] {
+    if !(HSLUV_LIGHTNESS_EPSILON..=100. - HSLUV_LIGHTNESS_EPSILON).contains(&l) {
+        return [l, 0., h];
+    }
+    [l, luv_max_safe_chroma(l) * 0.01 * p, h]
+}
+
+/// 🌌 The Hsluv color space
+///
+/// Hsluv is a human-friendly alternative to [Hsl], built on [LCh(uv)](LchUv). Its lightness and
+/// hue are those of [`LchUv`], and its saturation is the chroma as a percentage of the maximum
+/// chroma within the sRGB gamut at that lightness and hue.
+///
+/// Its components are `[H, S, L]` with
+/// - `H` - the hue angle in degrees, as in [`LchUv`];
+/// - `S` - the saturation, where 0 is gray and 100 is at the edge of the sRGB gamut; and
+/// - `L` - the lightness as in [`Luv`], where 0 is black and 100 is white.
+///
+/// Colors with saturation and lightness between 0 and 100 are within the sRGB gamut.
+///
+/// See [hsluv.org](https://www.hsluv.org/) for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct Hsluv;
+
+impl ColorSpace for Hsluv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hsluv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        lchuv_to_hsluv(LchUv::from_linear_srgb(src))
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        LchUv::to_linear_srgb(hsluv_to_lchuv(src))
+    }
+
+    fn scale_chroma([h, s, l]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, l]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<LchUv>() {
+            hsluv_to_lchuv(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([h, s, l]: [f32; 3]) -> [f32; 3] {
+        [h, s.clamp(0., 100.), l.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Hsluv> for ColorSpaceTag {
+    fn from(_: Hsluv) -> Self {
+        Self::Hsluv
+    }
+}
+
+/// 🌌 The Hpluv color space
+///
+/// Hpluv is a variant of [`Hsluv`] whose saturation is relative to the maximum chroma
+/// within the sRGB gamut for *any* hue at a given lightness, rather than for the color's own hue.
+/// Colors with equal saturation and lightness therefore have equal [LCh(uv)](LchUv) chroma,
+/// which suits pastel palettes, but not all saturated colors are reachable.
+///
+/// Its components are `[H, P, L]` with
+/// - `H` - the hue angle in degrees, as in [`LchUv`];
+/// - `P` - the saturation, where 0 is gray and 100 is the largest chroma in the sRGB gamut for
+///   all hues; and
+/// - `L` - the lightness as in [`Luv`], where 0 is black and 100 is white.
+///
+/// Colors with saturation and lightness between 0 and 100 are within the sRGB gamut, though
+/// colors with a higher saturation may be as well.
+///
+/// See [hsluv.org](https://www.hsluv.org/) for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct Hpluv;
+
+impl ColorSpace for Hpluv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hpluv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        lchuv_to_hpluv(LchUv::from_linear_srgb(src))
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        LchUv::to_linear_srgb(hpluv_to_lchuv(src))
+    }
+
+    fn scale_chroma([h, p, l]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, p * scale, l]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<LchUv>() {
+            hpluv_to_lchuv(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([h, p, l]: [f32; 3]) -> [f32; 3] {
+        [h, p.max(0.), l.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Hpluv> for ColorSpaceTag {
+    fn from(_: Hpluv) -> Self {
+        Self::Hpluv
+    }
+}
+
+/// The luminance in cd/m² of SDR reference white in HDR content, following ITU-R BT.2408.
+const SDR_WHITE_LUMINANCE: f32 = 203.;
+
+/// Constants of the [`Jzazbz`] color space, as given by Safdar et al.
+const JZ_D: f32 = -0.56;
+const JZ_D0: f32 = 1.629_55e-11;
+
+/// The exponent `p` of the perceptual quantizer as modified for [`Jzazbz`].
+const JZ_PQ_M2: f32 = 1.7 * 2523. / 32.;
+
+/// Linear sRGB to the LMS cone responses of [`Jzazbz`].
+///
+/// This is `M1 * XYZ_adjust * lin_sRGB_to_XYZ`, where `XYZ_adjust` is the linear adjustment of X
+/// and Y by the constants `b = 1.15` and `g = 0.66`.
+const JZAZBZ_SRGB_TO_LMS: [[f32; 3]; 3] = [
+    [0.358_515_6, 0.509_182_1, 0.104_099_48],
+    [0.220_448_03, 0.592_272_9, 0.159_543_68],
+    [0.079_388_34, 0.230_332_14, 0.663_198_96],
+];
+
+const JZAZBZ_LMS_TO_SRGB: [[f32; 3]; 3] = [
+    [5.929_591, -5.224_543_4, 0.326_109_5],
+    [-2.223_887_8, 3.822_134_3, -0.570_404_67],
+    [0.062_564_07, -0.702_040_5, 1.666_910_3],
+];
+
+const JZAZBZ_LMS_TO_IAB: [[f32; 3]; 3] = [
+    [0.5, 0.5, 0.],
+    [3.524, -4.066_708, 0.542_708],
+    [0.199_076, 1.096_799, -1.295_875],
+];
+
+const JZAZBZ_IAB_TO_LMS: [[f32; 3]; 3] = [
+    [1., 0.138_605_04, 0.058_047_316],
+    [1., -0.138_605_04, -0.058_047_316],
+    [1., -0.096_019_24, -0.811_891_9],
+];
+
+/// The perceptual quantizer of SMPTE ST 2084, mapping a luminance relative to 10,000 cd/m² to a
+/// perceptually uniform signal.
+///
+/// The exponent `m2` is a parameter, as [`Jzazbz`] uses a modified value. Negative values are
+/// mirrored.
+pub(crate) fn pq_from_linear(y: f32, m2: f32) -> f32 {
+    const M1: f32 = 2610. / 16384.;
+    const C1: f32 = 3424. / 4096.;
+    const C2: f32 = 2413. / 128.;
+    const C3: f32 = 2392. / 128.;
+
+    let y_m1 = y.abs().powf(M1);
+    ((C1 + C2 * y_m1) / (1. + C3 * y_m1)).powf(m2).copysign(y)
+}
+
+/// The inverse of [`pq_from_linear`].
+pub(crate) fn pq_to_linear(e: f32, m2: f32) -> f32 {
+    const M1: f32 = 2610. / 16384.;
+    const C1: f32 = 3424. / 4096.;
+    const C2: f32 = 2413. / 128.;
+    const C3: f32 = 2392. / 128.;
+
+    let e_m2 = e.abs().powf(1. / m2);
+    ((e_m2 - C1).max(0.) / (C2 - C3 * e_m2))
+        .powf(1. / M1)
+        .copysign(e)
+}
+
+/// 🌌 The Jzazbz color space, a perceptually uniform color space for high dynamic range.
+///
+/// Jzazbz was published by Safdar et al. in [*Perceptually uniform color space for image signals
+/// including high dynamic range and wide gamut*][paper]. Like [`Oklab`], it has a lightness
+/// component and two opponent axes, but it is built on the perceptual quantizer of SMPTE ST 2084,
+/// and so remains perceptually uniform for luminances far above diffuse white.
+///
+/// Its components are `[Jz, az, bz]` with
+/// - `Jz` - the lightness, where 0 is black and 1 is a luminance of 10,000 cd/m²;
+/// - `az` - how green/red the color is; and
+/// - `bz` - how blue/yellow the color is.
+///
+/// `az` and `bz` are unbounded, but are usually between -0.2 and 0.2 for colors within common
+/// gamuts.
+///
+/// Jzazbz is defined in terms of absolute luminance. The conversions of [`ColorSpace`] map linear
+/// sRGB white to [`Jzazbz::SDR_WHITE_LUMINANCE`], so sRGB white has a lightness of about 0.222.
+/// Use [`Jzazbz::from_linear_srgb_with_luminance`] and [`Jzazbz::to_linear_srgb_with_luminance`]
+/// to convert with a different luminance for white.
+///
+/// [paper]: https://doi.org/10.1364/OE.25.015131
+#[derive(Clone, Copy, Debug)]
+pub struct Jzazbz;
+
+impl Jzazbz {
+    /// The luminance in cd/m² of linear sRGB white in the conversions of [`ColorSpace`].
+    ///
+    /// This is the reference white of [ITU-R BT.2408][bt2408] for mixing SDR and HDR content.
+    ///
+    /// [bt2408]: https://www.itu.int/pub/R-REP-BT.2408
+    pub const SDR_WHITE_LUMINANCE: f32 = SDR_WHITE_LUMINANCE;
+
+    /// Convert an opaque color from linear sRGB, where linear sRGB white has the luminance
+    /// `white_luminance` in cd/m².
+    pub fn from_linear_srgb_with_luminance(src: [f32; 3], white_luminance: f32) -> [f32; 3] {
+        let scale = white_luminance / 10_000.;
+        let lms = matvecmul(&JZAZBZ_SRGB_TO_LMS, src).map(|x| pq_from_linear(x * scale, JZ_PQ_M2));
+        let [iz, az, bz] = matvecmul(&JZAZBZ_LMS_TO_IAB, lms);
+        let jz = (1. + JZ_D) * iz / (1. + JZ_D * iz) - JZ_D0;
+        [jz, az, bz]
+    }
+
+    /// Convert an opaque color to linear sRGB, where linear sRGB white has the luminance
+    /// `white_luminance` in cd/m².
+    pub fn to_linear_srgb_with_luminance([jz, az, bz]: [f32; 3], white_luminance: f32) -> [f32; 3] {
+        let scale = 10_000. / white_luminance;
+        let jz = jz + JZ_D0;
+        let iz = jz / (1. + JZ_D - JZ_D * jz);
+        let lms = matvecmul(&JZAZBZ_IAB_TO_LMS, [iz, az, bz]).map(|x| pq_to_linear(x, JZ_PQ_M2));
+        matvecmul(&JZAZBZ_LMS_TO_SRGB, lms).map(|x| x * scale)
+    }
+}
+
+impl ColorSpace for Jzazbz {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Jzazbz);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.222_065_25, -0.000_160_624_93, -0.000_117_034];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Self::from_linear_srgb_with_luminance(src, Self::SDR_WHITE_LUMINANCE)
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Self::to_linear_srgb_with_luminance(src, Self::SDR_WHITE_LUMINANCE)
+    }
+
+    fn scale_chroma([jz, az, bz]: [f32; 3], scale: f32) -> [f32; 3] {
+        [jz, az * scale, bz * scale]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<JzCzhz>() {
+            lab_to_lch(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([jz, az, bz]: [f32; 3]) -> [f32; 3] {
+        [jz.clamp(0., 1.), az, bz]
+    }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
+}
+
+impl From<Jzazbz> for ColorSpaceTag {
+    fn from(_: Jzazbz) -> Self {
+        Self::Jzazbz
+    }
+}
+
+/// 🌌 The cylindrical version of the [Jzazbz] color space.
+///
+/// Its components are `[Jz, Cz, hz]` with
+/// - `Jz` - the lightness as in [`Jzazbz`];
+/// - `Cz` - the chromatic intensity, the natural lower bound of 0 being achromatic, usually not
+///   exceeding 0.3 for colors within common gamuts; and
+/// - `hz` - the hue angle in degrees.
+///
+/// Like [`Jzazbz`], the conversions of [`ColorSpace`] map linear sRGB white to
+/// [`Jzazbz::SDR_WHITE_LUMINANCE`].
+#[derive(Clone, Copy, Debug)]
+pub struct JzCzhz;
+
+impl JzCzhz {
+    /// Convert an opaque color from linear sRGB, where linear sRGB white has the luminance
+    /// `white_luminance` in cd/m².
+    ///
+    /// See [`Jzazbz::from_linear_srgb_with_luminance`].
+    pub fn from_linear_srgb_with_luminance(src: [f32; 3], white_luminance: f32) -> [f32; 3] {
+        lab_to_lch(Jzazbz::from_linear_srgb_with_luminance(
+            src,
+            white_luminance,
+        ))
+    }
+
+    /// Convert an opaque color to linear sRGB, where linear sRGB white has the luminance
+    /// `white_luminance` in cd/m².
+    ///
+    /// See [`Jzazbz::to_linear_srgb_with_luminance`].
+    pub fn to_linear_srgb_with_luminance(src: [f32; 3], white_luminance: f32) -> [f32; 3] {
+        Jzazbz::to_linear_srgb_with_luminance(lch_to_lab(src), white_luminance)
+    }
+}
+
+impl ColorSpace for JzCzhz {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::JzCzhz);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueThird;
+
+    // White is not exactly achromatic in Jzazbz. Its tiny chroma makes the hue sensitive to
+    // rounding, so these are the components as computed in `f32`.
+    const WHITE_COMPONENTS: [f32; 3] = [0.222_065_43, 0.000_204_204_79, 214.105_21];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        lab_to_lch(Jzazbz::from_linear_srgb(src))
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Jzazbz::to_linear_srgb(lch_to_lab(src))
+    }
+
+    fn scale_chroma([jz, cz, hz]: [f32; 3], scale: f32) -> [f32; 3] {
+        [jz, cz * scale, hz]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Jzazbz>() {
+            lch_to_lab(src)
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([jz, cz, hz]: [f32; 3]) -> [f32; 3] {
+        [jz.clamp(0., 1.), cz.max(0.), hz]
+    }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
+}
+
+impl From<JzCzhz> for ColorSpaceTag {
+    fn from(_: JzCzhz) -> Self {
+        Self::JzCzhz
+    }
+}
+
+/// The exponent `m2` of the perceptual quantizer of SMPTE ST 2084.
+pub(crate) const PQ_M2: f32 = 2523. / 4096. * 128.;
+
+// These are the parameters of the HLG transfer function defined in ITU-R BT.2100.
+const HLG_A: f32 = 0.178_832_77;
+const HLG_B: f32 = 1. - 4. * HLG_A;
+const HLG_C: f32 = 0.559_910_7;
+
+/// The relative scene light of HLG reference white, which has a signal of 0.75.
+///
+/// Linear sRGB white is mapped to this value, following ITU-R BT.2408.
+pub(crate) const HLG_REFERENCE_WHITE: f32 = 0.264_962_56;
+
+/// The HLG opto-electronic transfer function of ITU-R BT.2100, mapping relative scene light to a
+/// signal. Negative values are mirrored.
+pub(crate) fn hlg_from_linear(e: f32) -> f32 {
+    let x = e.abs();
+    if x <= 1. / 12. {
+        (3. * x).sqrt().copysign(e)
+    } else {
+        (HLG_A * (12. * x - HLG_B).ln() + HLG_C).copysign(e)
+    }
+}
+
+/// The inverse of [`hlg_from_linear`].
+pub(crate) fn hlg_to_linear(e: f32) -> f32 {
+    let x = e.abs();
+    if x <= 0.5 {
+        (x * x / 3.).copysign(e)
+    } else {
+        ((((x - HLG_C) / HLG_A).exp() + HLG_B) / 12.).copysign(e)
+    }
+}
+
+/// Linear Rec. 2020 to the LMS cone responses of IC<sub>T</sub>C<sub>P</sub>, as given by
+/// ITU-R BT.2100.
+const ICTCP_REC2020_TO_LMS: [[f32; 3]; 3] = [
+    [1688. / 4096., 2146. / 4096., 262. / 4096.],
+    [683. / 4096., 2951. / 4096., 462. / 4096.],
+    [99. / 4096., 309. / 4096., 3688. / 4096.],
+];
+
+const ICTCP_LMS_TO_REC2020: [[f32; 3]; 3] = [
+    [3.436_606_7, -2.506_452, 0.069_845_42],
+    [-0.791_329_56, 1.983_600_4, -0.192_270_9],
+    [-0.025_949_9, -0.098_913_71, 1.124_863_6],
+];
+
+const ICTCP_SRGB_TO_LMS: [[f32; 3]; 3] = matmatmul(&ICTCP_REC2020_TO_LMS, &LINEAR_SRGB_TO_REC2020);
+
+const ICTCP_LMS_TO_SRGB: [[f32; 3]; 3] = matmatmul(&LINEAR_REC2020_TO_SRGB, &ICTCP_LMS_TO_REC2020);
+
+/// 🌌 The IC<sub>T</sub>C<sub>P</sub> color space with the PQ transfer function.
+///
+/// IC<sub>T</sub>C<sub>P</sub> is defined in [ITU-R BT.2100][bt2100] for high dynamic range and
+/// wide color gamut video. It is derived from the linear [Rec. 2020](Rec2020) primaries through an
+/// LMS cone response space, with the perceptual quantizer of SMPTE ST 2084 applied to each cone
+/// response.
+///
+/// Its components are `[I, Ct, Cp]` with
+/// - `I` - the intensity, where 0 is black and 1 is a luminance of 10,000 cd/m²;
+/// - `Ct` - the tritan (blue/yellow) axis; and
+/// - `Cp` - the protan (red/green) axis.
+///
+/// `Ct` and `Cp` are unbounded, but are usually between -0.5 and 0.5.
+///
+/// Like [`Jzazbz`], this color space is defined in terms of absolute luminance. The conversions of
+/// [`ColorSpace`] map linear sRGB white to [`IctcpPq::SDR_WHITE_LUMINANCE`]. Use
+/// [`IctcpPq::from_linear_srgb_with_luminance`] and [`IctcpPq::to_linear_srgb_with_luminance`] to
+/// convert with a different luminance for white.
+///
+/// Color differences in this color space are measured by the deltaE ITP metric of ITU-R BT.2124,
+/// see [`OpaqueColor::delta_e_itp`](crate::OpaqueColor::delta_e_itp).
+///
+/// [bt2100]: https://www.itu.int/rec/R-REC-BT.2100
+#[derive(Clone, Copy, Debug)]
+pub struct IctcpPq;
+
+impl IctcpPq {
+    /// The luminance in cd/m² of linear sRGB white in the conversions of [`ColorSpace`].
+    ///
+    /// This is the reference white of [ITU-R BT.2408][bt2408] for mixing SDR and HDR content.
+    ///
+    /// [bt2408]: https://www.itu.int/pub/R-REP-BT.2408
+    pub const SDR_WHITE_LUMINANCE: f32 = SDR_WHITE_LUMINANCE;
+
+    const LMS_TO_ICTCP: [[f32; 3]; 3] = [
+        [2048. / 4096., 2048. / 4096., 0.],
+        [6610. / 4096., -13613. / 4096., 7003. / 4096.],
+        [17933. / 4096., -17390. / 4096., -543. / 4096.],
+    ];
+
+    const ICTCP_TO_LMS: [[f32; 3]; 3] = [
+        [1., 0.008_609_037, 0.111_029_625],
+        [1., -0.008_609_037, -0.111_029_625],
+        [1., 0.560_031_34, -0.320_627_18],
+    ];
+
+    /// Convert an opaque color from linear sRGB, where linear sRGB white has the luminance
+    /// `white_luminance` in cd/m².
+    pub fn from_linear_srgb_with_luminance(src: [f32; 3], white_luminance: f32) -> [f32; 3] {
+        let scale = white_luminance / 10_000.;
+        let lms = matvecmul(&ICTCP_SRGB_TO_LMS, src).map(|x| pq_from_linear(x * scale, PQ_M2));
+        matvecmul(&Self::LMS_TO_ICTCP, lms)
+    }
+
+    /// Convert an opaque color to linear sRGB, where linear sRGB white has the luminance
+    /// `white_luminance` in cd/m².
+    pub fn to_linear_srgb_with_luminance(src: [f32; 3], white_luminance: f32) -> [f32; 3] {
+        let scale = 10_000. / white_luminance;
+        let lms = matvecmul(&Self::ICTCP_TO_LMS, src).map(|x| pq_to_linear(x, PQ_M2));
+        matvecmul(&ICTCP_LMS_TO_SRGB, lms).map(|x| x * scale)
+    }
+}
+
+impl ColorSpace for IctcpPq {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::IctcpPq);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.580_688_8, 0., 0.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Self::from_linear_srgb_with_luminance(src, Self::SDR_WHITE_LUMINANCE)
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        Self::to_linear_srgb_with_luminance(src, Self::SDR_WHITE_LUMINANCE)
+    }
+
+    fn scale_chroma([i, ct, cp]: [f32; 3], scale: f32) -> [f32; 3] {
+        [i, ct * scale, cp * scale]
+    }
+
+    fn clip([i, ct, cp]: [f32; 3]) -> [f32; 3] {
+        [i.clamp(0., 1.), ct, cp]
+    }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
+}
+
+impl From<IctcpPq> for ColorSpaceTag {
+    fn from(_: IctcpPq) -> Self {
+        Self::IctcpPq
+    }
+}
+
+/// 🌌 The IC<sub>T</sub>C<sub>P</sub> color space with the HLG transfer function.
+///
+/// This is the variant of [`IctcpPq`] defined in [ITU-R BT.2100][bt2100] for hybrid log-gamma
+/// video, where the HLG transfer function is applied to each cone response instead of the
+/// perceptual quantizer.
+///
+/// Its components are `[I, Ct, Cp]` with
+/// - `I` - the intensity, where 0 is black and 1 is the peak of the HLG signal;
+/// - `Ct` - the tritan (blue/yellow) axis; and
+/// - `Cp` - the protan (red/green) axis.
+///
+/// HLG is defined in terms of scene light relative to the peak, rather than absolute luminance.
+/// Linear sRGB white is mapped to HLG reference white, which has an intensity of 0.75, following
+/// [ITU-R BT.2408][bt2408].
+///
+/// [bt2100]: https://www.itu.int/rec/R-REC-BT.2100
+/// [bt2408]: https://www.itu.int/pub/R-REP-BT.2408
+#[derive(Clone, Copy, Debug)]
+pub struct IctcpHlg;
+
+impl IctcpHlg {
+    const LMS_TO_ICTCP: [[f32; 3]; 3] = [
+        [2048. / 4096., 2048. / 4096., 0.],
+        [3625. / 4096., -7465. / 4096., 3840. / 4096.],
+        [9500. / 4096., -9212. / 4096., -288. / 4096.],
+    ];
+
+    const ICTCP_TO_LMS: [[f32; 3]; 3] = [
+        [1., 0.015_718_58, 0.209_581_07],
+        [1., -0.015_718_58, -0.209_581_07],
+        [1., 1.021_271_1, -0.605_274_5],
+    ];
+}
+
+impl ColorSpace for IctcpHlg {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::IctcpHlg);
+
+    const WHITE_COMPONENTS: [f32; 3] = [0.75, 0., 0.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let lms =
+            matvecmul(&ICTCP_SRGB_TO_LMS, src).map(|x| hlg_from_linear(x * HLG_REFERENCE_WHITE));
+        matvecmul(&Self::LMS_TO_ICTCP, lms)
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let lms =
+            matvecmul(&Self::ICTCP_TO_LMS, src).map(|x| hlg_to_linear(x) / HLG_REFERENCE_WHITE);
+        matvecmul(&ICTCP_LMS_TO_SRGB, lms)
+    }
+
+    fn scale_chroma([i, ct, cp]: [f32; 3], scale: f32) -> [f32; 3] {
+        [i, ct * scale, cp * scale]
+    }
+
+    fn clip([i, ct, cp]: [f32; 3]) -> [f32; 3] {
+        [i.clamp(0., 1.), ct, cp]
+    }
+
+    fn is_in_gamut(_: [f32; 3], _: f32) -> bool {
+        true
+    }
+}
+
+impl From<IctcpHlg> for ColorSpaceTag {
+    fn from(_: IctcpHlg) -> Self {
+        Self::IctcpHlg
+    }
+}
+
+/// 🌌 The HSL color space
+///
+/// The HSL color space is fairly widely used and convenient, but it is
+/// not based on sound color science. Among its flaws, colors with the
+/// same "lightness" value can have wildly varying perceptual lightness.
+///
+/// Its components are `[H, S, L]` with
+/// - `H` - the hue angle in degrees, with red at 0, green at 120, and blue at 240.
+/// - `S` - the saturation, where 0 is gray and 100 is maximally saturated.
+/// - `L` - the lightness, where 0 is black and 100 is white.
+///
+/// This corresponds to the color space in [CSS Color Module Level 4 § 7][css-sec].
+///
+/// [css-sec]: https://www.w3.org/TR/css-color-4/#the-hsl-notation
+#[derive(Clone, Copy, Debug)]
+pub struct Hsl;
+
+/// Convert HSL to RGB.
+///
+/// Reference: § 7.1 of CSS Color 4 spec.
+fn hsl_to_rgb([h, s, l]: [f32; 3]) -> [f32; 3] {
+    // Don't need mod 360 for hue, it's subsumed by mod 12 below.
+    let sat = s * 0.01;
+    let light = l * 0.01;
+    let a = sat * light.min(1.0 - light);
+    [0.0, 8.0, 4.0].map(|n| {
+        let x = n + h * (1.0 / 30.0);
+        let k = x - 12.0 * (x * (1.0 / 12.0)).floor();
+        light - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    })
+}
+
+/// Convert RGB to HSL.
+///
+/// Reference: § 7.2 of CSS Color 4 spec.
+///
+/// See <https://github.com/w3c/csswg-drafts/issues/10695> for an
+/// explanation of why `hue_hack` is needed.
+fn rgb_to_hsl([r, g, b]: [f32; 3], hue_hack: bool) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let mut hue = 0.0;
+    let mut sat = 0.0;
+    let light = 0.5 * (min + max);
+    let d = max - min;
+
+    const EPSILON: f32 = 1e-6;
+    if d > EPSILON {
+        let denom = light.min(1.0 - light);
+        if denom.abs() > EPSILON {
+            sat = (max - light) / denom;
+        }
+        hue = if max == r {
+            (g - b) / d
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            // max == b
+            (r - g) / d + 4.0
+        };
+        hue *= 60.0;
+        // Deal with negative saturation from out of gamut colors
         if hue_hack && sat < 0.0 {
             hue += 180.0;
             sat = sat.abs();
         }
-        hue -= 360. * (hue * (1.0 / 360.0)).floor();
+        hue -= 360. * (hue * (1.0 / 360.0)).floor();
+    }
+    [hue, sat * 100.0, light * 100.0]
+}
+
+impl ColorSpace for Hsl {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hsl);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = Srgb::from_linear_srgb(src);
+        rgb_to_hsl(rgb, true)
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = hsl_to_rgb(src);
+        Srgb::to_linear_srgb(rgb)
+    }
+
+    fn scale_chroma([h, s, l]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, l]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Srgb>() {
+            hsl_to_rgb(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hwb>() {
+            rgb_to_hwb(hsl_to_rgb(src))
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsv>() {
+            rgb_to_hsv(hsl_to_rgb(src))
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([h, s, l]: [f32; 3]) -> [f32; 3] {
+        [h, s.max(0.), l.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Hsl> for ColorSpaceTag {
+    fn from(_: Hsl) -> Self {
+        Self::Hsl
+    }
+}
+
+/// 🌌 The HWB color space
+///
+/// The HWB color space is a convenient way to represent colors. It corresponds
+/// closely to popular color pickers, both a triangle with white, black, and
+/// fully saturated color at the corner, and also a rectangle with a hue spectrum
+/// at the top and black at the bottom, with whiteness as a separate slider. It
+/// was proposed in [HWB–A More Intuitive Hue-Based Color Model].
+///
+/// Its components are `[H, W, B]` with
+/// - `H` - the hue angle in degrees, with red at 0, green at 120, and blue at 240.
+/// - `W` - an amount of whiteness to mix in, with 100 being white.
+/// - `B` - an amount of blackness to mix in, with 100 being black.
+///
+/// The hue angle is the same as in [Hsl], and thus has the same flaw of poor hue
+/// uniformity.
+///
+/// This corresponds to the color space in [CSS Color Module Level 4 § 8][css-sec].
+///
+/// [css-sec]: https://www.w3.org/TR/css-color-4/#the-hwb-notation
+/// [HWB–A More Intuitive Hue-Based Color Model]: http://alvyray.com/Papers/CG/HWB_JGTv208.pdf
+#[derive(Clone, Copy, Debug)]
+pub struct Hwb;
+
+/// Convert HWB to RGB.
+///
+/// Reference: § 8.1 of CSS Color 4 spec.
+fn hwb_to_rgb([h, w, b]: [f32; 3]) -> [f32; 3] {
+    let white = w * 0.01;
+    let black = b * 0.01;
+    if white + black >= 1.0 {
+        let gray = white / (white + black);
+        [gray, gray, gray]
+    } else {
+        let rgb = hsl_to_rgb([h, 100., 50.]);
+        rgb.map(|x| white + x * (1.0 - white - black))
+    }
+}
+
+/// Convert RGB to HWB.
+///
+/// Reference: § 8.2 of CSS Color 4 spec.
+fn rgb_to_hwb([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let hsl = rgb_to_hsl([r, g, b], false);
+    let white = r.min(g).min(b);
+    let black = 1.0 - r.max(g).max(b);
+    [hsl[0], white * 100., black * 100.]
+}
+
+impl ColorSpace for Hwb {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hwb);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 100., 0.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = Srgb::from_linear_srgb(src);
+        rgb_to_hwb(rgb)
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = hwb_to_rgb(src);
+        Srgb::to_linear_srgb(rgb)
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Srgb>() {
+            hwb_to_rgb(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsl>() {
+            rgb_to_hsl(hwb_to_rgb(src), true)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsv>() {
+            rgb_to_hsv(hwb_to_rgb(src))
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([h, w, b]: [f32; 3]) -> [f32; 3] {
+        [h, w.clamp(0., 100.), b.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
+    }
+}
+
+impl From<Hwb> for ColorSpaceTag {
+    fn from(_: Hwb) -> Self {
+        Self::Hwb
+    }
+}
+
+/// 🌌 The HSV color space
+///
+/// The HSV color space, also known as HSB, is used by many color pickers and design tools. It is
+/// closely related to [Hwb]: its value is the complement of HWB's blackness. It was proposed in
+/// [Color Gamut Transform Pairs].
+///
+/// Its components are `[H, S, V]` with
+/// - `H` - the hue angle in degrees, with red at 0, green at 120, and blue at 240.
+/// - `S` - the saturation, where 0 is gray and 100 is maximally saturated.
+/// - `V` - the value (or brightness), where 0 is black and 100 is white or a fully saturated color.
+///
+/// The hue angle is the same as in [Hsl], and thus has the same flaw of poor hue
+/// uniformity.
+///
+/// [Color Gamut Transform Pairs]: https://doi.org/10.1145/965139.807361
+#[derive(Clone, Copy, Debug)]
+pub struct Hsv;
+
+/// Convert HSV to RGB.
+fn hsv_to_rgb([h, s, v]: [f32; 3]) -> [f32; 3] {
+    // Don't need mod 360 for hue, it's subsumed by mod 6 below.
+    let sat = s * 0.01;
+    let value = v * 0.01;
+    [5.0, 3.0, 1.0].map(|n| {
+        let x = n + h * (1.0 / 60.0);
+        let k = x - 6.0 * (x * (1.0 / 6.0)).floor();
+        value - value * sat * k.min(4.0 - k).clamp(0.0, 1.0)
+    })
+}
+
+/// Convert RGB to HSV.
+fn rgb_to_hsv([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let hsl = rgb_to_hsl([r, g, b], false);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+
+    const EPSILON: f32 = 1e-6;
+    let sat = if d > EPSILON && max.abs() > EPSILON {
+        d / max
+    } else {
+        0.0
+    };
+    [hsl[0], sat * 100., max * 100.]
+}
+
+impl ColorSpace for Hsv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hsv);
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
+
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = Srgb::from_linear_srgb(src);
+        rgb_to_hsv(rgb)
+    }
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let rgb = hsv_to_rgb(src);
+        Srgb::to_linear_srgb(rgb)
+    }
+
+    fn scale_chroma([h, s, v]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, v]
+    }
+
+    fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+        if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
+            src
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Srgb>() {
+            hsv_to_rgb(src)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsl>() {
+            rgb_to_hsl(hsv_to_rgb(src), true)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hwb>() {
+            rgb_to_hwb(hsv_to_rgb(src))
+        } else {
+            let lin_rgb = Self::to_linear_srgb(src);
+            TargetCS::from_linear_srgb(lin_rgb)
+        }
+    }
+
+    fn clip([h, s, v]: [f32; 3]) -> [f32; 3] {
+        [h, s.clamp(0., 100.), v.clamp(0., 100.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
     }
-    [hue, sat * 100.0, light * 100.0]
 }
 
-impl ColorSpace for Hsl {
-    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hsl);
+impl From<Hsv> for ColorSpaceTag {
+    fn from(_: Hsv) -> Self {
+        Self::Hsv
+    }
+}
+
+/// Chroma below which colors are considered achromatic in [`Okhsl`] and [`Okhsv`].
+const OKHSX_ACHROMATIC_EPSILON: f32 = 1e-6;
+
+/// The toe function mapping [`Oklab`] lightness to the lightness estimate `L_r`, which is closer
+/// to CIELAB's lightness in the dark range.
+///
+/// Reference: <https://bottosson.github.io/posts/colorpicker/#intermission---a-new-lightness-estimate-for-oklab>
+fn oklab_toe(x: f32) -> f32 {
+    const K_1: f32 = 0.206;
+    const K_2: f32 = 0.03;
+    const K_3: f32 = (1. + K_1) / (1. + K_2);
+    let y = K_3 * x - K_1;
+    0.5 * (y + (y * y + 4. * K_2 * K_3 * x).sqrt())
+}
+
+/// The inverse of [`oklab_toe`].
+fn oklab_toe_inv(x: f32) -> f32 {
+    const K_1: f32 = 0.206;
+    const K_2: f32 = 0.03;
+    const K_3: f32 = (1. + K_1) / (1. + K_2);
+    (x * x + K_1 * x) / (K_3 * (x + K_2))
+}
+
+/// The slopes `[S, T]` of the triangle approximating the sRGB gamut at a hue with the given cusp.
+fn cusp_to_st(cusp: GamutCusp) -> [f32; 2] {
+    [
+        cusp.chroma / cusp.lightness,
+        cusp.chroma / (1. - cusp.lightness),
+    ]
+}
+
+/// A smooth approximation of the slopes `[S, T]` of the sRGB gamut at the hue given by the
+/// normalized `a` and `b` components, used for the mid-saturation chroma of [`Okhsl`].
+fn okhsl_st_mid(a: f32, b: f32) -> [f32; 2] {
+    let s = 0.115_169_93
+        + 1. / (7.447_789_7
+            + 4.159_012_4 * b
+            + a * (-2.195_573_5
+                + 1.751_984 * b
+                + a * (-2.137_049_5 - 10.023_01 * b
+                    + a * (-4.248_945_7 + 5.387_708 * b + 4.698_91 * a))));
+    let t = 0.112_396_42
+        + 1. / (1.613_203_2 - 0.681_243_8 * b
+            + a * (0.403_706_12
+                + 0.901_481_2 * b
+                + a * (-0.270_879_43
+                    + 0.612_239_9 * b
+                    + a * (0.002_992_15 - 0.453_995_68 * b - 0.146_618_72 * a))));
+    [s, t]
+}
+
+/// The chroma values `[C_0, C_mid, C_max]` of [`Okhsl`] at saturations 0 (in the limit), 0.8 and
+/// 1, for the given [`Oklab`] lightness and the hue given by the normalized `a` and `b`
+/// components.
+fn okhsl_chromas(l: f32, a: f32, b: f32) -> [f32; 3] {
+    let cusp = RgbGamut::SRGB.cusp_ab(a, b);
+    let c_max = RgbGamut::SRGB.intersect(a, b, [l, 1.], l, cusp);
+    let [s_max, t_max] = cusp_to_st(cusp);
+
+    // Compensate for the curved part of the gamut.
+    let k = c_max / (l * s_max).min((1. - l) * t_max);
+
+    // Smoothly approximate the gamut's triangular shape with a soft minimum.
+    let [s_mid, t_mid] = okhsl_st_mid(a, b);
+    let c_a = l * s_mid;
+    let c_b = (1. - l) * t_mid;
+    let c_mid = 0.9
+        * k
+        * (1. / (1. / (c_a * c_a * c_a * c_a) + 1. / (c_b * c_b * c_b * c_b)))
+            .sqrt()
+            .sqrt();
+
+    // The shape used for `C_0` is independent of hue.
+    let c_a = l * 0.4;
+    let c_b = (1. - l) * 0.8;
+    let c_0 = (1. / (1. / (c_a * c_a) + 1. / (c_b * c_b))).sqrt();
+
+    [c_0, c_mid, c_max]
+}
+
+/// The saturation of [`Okhsl`] at which the chroma is `C_mid`.
+const OKHSL_MID: f32 = 0.8;
+
+fn okhsl_to_oklab([h, s, l]: [f32; 3]) -> [f32; 3] {
+    let l = oklab_toe_inv(l);
+    if !(l > 0. && l < 1.) {
+        return [l, 0., 0.];
+    }
+    let (b_, a_) = h.to_radians().sin_cos();
+    let [c_0, c_mid, c_max] = okhsl_chromas(l, a_, b_);
+
+    let c = if s < OKHSL_MID {
+        let t = s / OKHSL_MID;
+        let k_1 = OKHSL_MID * c_0;
+        let k_2 = 1. - k_1 / c_mid;
+        t * k_1 / (1. - k_2 * t)
+    } else {
+        let t = (s - OKHSL_MID) / (1. - OKHSL_MID);
+        let k_0 = c_mid;
+        let k_1 = (1. - OKHSL_MID) * c_mid * c_mid / (OKHSL_MID * OKHSL_MID * c_0);
+        let k_2 = 1. - k_1 / (c_max - c_mid);
+        k_0 + t * k_1 / (1. - k_2 * t)
+    };
+    [l, c * a_, c * b_]
+}
+
+fn oklab_to_okhsl([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let [_, c, h] = lab_to_lch([l, a, b]);
+    if c < OKHSX_ACHROMATIC_EPSILON || !(l > 0. && l < 1.) {
+        return [0., 0., oklab_toe(l)];
+    }
+    let (a_, b_) = (a / c, b / c);
+    let [c_0, c_mid, c_max] = okhsl_chromas(l, a_, b_);
+
+    // This inverts the interpolation in `okhsl_to_oklab`.
+    let s = if c < c_mid {
+        let k_1 = OKHSL_MID * c_0;
+        let k_2 = 1. - k_1 / c_mid;
+        let t = c / (k_1 + k_2 * c);
+        t * OKHSL_MID
+    } else {
+        let k_0 = c_mid;
+        let k_1 = (1. - OKHSL_MID) * c_mid * c_mid / (OKHSL_MID * OKHSL_MID * c_0);
+        let k_2 = 1. - k_1 / (c_max - c_mid);
+        let t = (c - k_0) / (k_1 + k_2 * (c - k_0));
+        OKHSL_MID + (1. - OKHSL_MID) * t
+    };
+    [h, s, oklab_toe(l)]
+}
+
+/// 🌌 The Okhsl color space
+///
+/// Okhsl is a hue, saturation and lightness color space built on [Oklab], designed by Björn
+/// Ottosson as a perceptual alternative to [Hsl] for color pickers. Unlike [Hsl], its lightness
+/// and hue are perceptually meaningful, and its saturation is normalized to the [sRGB](Srgb)
+/// gamut.
+///
+/// Its components are `[h, s, l]` with
+/// - `h` - the hue angle in degrees, as in [Oklch];
+/// - `s` - the saturation, where 0 is gray and 1 is at the edge of the sRGB gamut; and
+/// - `l` - the lightness, where 0 is black and 1 is white. This is a lightness estimate derived
+///   from [Oklab]'s lightness, which is closer to the lightness of [Lab] in the dark range.
+///
+/// Colors with saturation and lightness between 0 and 1 are within the sRGB gamut.
+///
+/// See [Okhsv and Okhsl: two new color spaces for color picking][okhsl] for more information.
+///
+/// [okhsl]: https://bottosson.github.io/posts/colorpicker/
+#[derive(Clone, Copy, Debug)]
+pub struct Okhsl;
+
+impl ColorSpace for Okhsl {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Okhsl);
 
     const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
 
-    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 100.];
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 1.];
 
     fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        let rgb = Srgb::from_linear_srgb(src);
-        rgb_to_hsl(rgb, true)
+        oklab_to_okhsl(Oklab::from_linear_srgb(src))
     }
 
     fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        let rgb = hsl_to_rgb(src);
-        Srgb::to_linear_srgb(rgb)
+        Oklab::to_linear_srgb(okhsl_to_oklab(src))
     }
 
     fn scale_chroma([h, s, l]: [f32; 3], scale: f32) -> [f32; 3] {
@@ -1590,10 +3261,8 @@ impl ColorSpace for Hsl {
     fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
         if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
             src
-        } else if TypeId::of::<TargetCS>() == TypeId::of::<Srgb>() {
-            hsl_to_rgb(src)
-        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hwb>() {
-            rgb_to_hwb(hsl_to_rgb(src))
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Oklab>() {
+            okhsl_to_oklab(src)
         } else {
             let lin_rgb = Self::to_linear_srgb(src);
             TargetCS::from_linear_srgb(lin_rgb)
@@ -1601,102 +3270,139 @@ impl ColorSpace for Hsl {
     }
 
     fn clip([h, s, l]: [f32; 3]) -> [f32; 3] {
-        [h, s.max(0.), l.clamp(0., 100.)]
+        [h, s.clamp(0., 1.), l.clamp(0., 1.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
     }
 }
 
-impl From<Hsl> for ColorSpaceTag {
-    fn from(_: Hsl) -> Self {
-        Self::Hsl
+impl From<Okhsl> for ColorSpaceTag {
+    fn from(_: Okhsl) -> Self {
+        Self::Okhsl
     }
 }
 
-/// 🌌 The HWB color space
+/// The saturation slope `S` of [`Okhsv`] at its value of 0.5.
+const OKHSV_S_0: f32 = 0.5;
+
+/// The factor by which [`Okhsv`] scales a color computed for a triangular gamut, so that it
+/// touches the curved top part of the sRGB gamut.
+fn okhsv_scale(l_v: f32, c_v: f32, a: f32, b: f32) -> f32 {
+    let l_vt = oklab_toe_inv(l_v);
+    let c_vt = c_v * l_vt / l_v;
+    let [r, g, b] = Oklab::to_linear_srgb([l_vt, a * c_vt, b * c_vt]);
+    (1. / r.max(g).max(b).max(0.)).cbrt()
+}
+
+fn okhsv_to_oklab([h, s, v]: [f32; 3]) -> [f32; 3] {
+    if v <= 0. {
+        return [0., 0., 0.];
+    }
+    let (b_, a_) = h.to_radians().sin_cos();
+    let [s_max, t_max] = cusp_to_st(RgbGamut::SRGB.cusp_ab(a_, b_));
+    let k = 1. - OKHSV_S_0 / s_max;
+
+    // The lightness and chroma at `v = 1`, as if the gamut were a triangle.
+    let d = OKHSV_S_0 + t_max - t_max * k * s;
+    let l_v = 1. - s * OKHSV_S_0 / d;
+    let c_v = s * t_max * OKHSV_S_0 / d;
+
+    // Compensate for the toe and the curved top part of the gamut.
+    let l = v * l_v;
+    let l_new = oklab_toe_inv(l);
+    let c = v * c_v * l_new / l;
+    let scale = okhsv_scale(l_v, c_v, a_, b_);
+    let (l, c) = (l_new * scale, c * scale);
+    [l, c * a_, c * b_]
+}
+
+fn oklab_to_okhsv([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let [_, c, h] = lab_to_lch([l, a, b]);
+    if l <= 0. {
+        return [0., 0., 0.];
+    }
+    if c < OKHSX_ACHROMATIC_EPSILON {
+        return [0., 0., oklab_toe(l)];
+    }
+    let (a_, b_) = (a / c, b / c);
+    let [s_max, t_max] = cusp_to_st(RgbGamut::SRGB.cusp_ab(a_, b_));
+    let k = 1. - OKHSV_S_0 / s_max;
+
+    // This inverts the steps in `okhsv_to_oklab`.
+    let t = t_max / (c + l * t_max);
+    let l_v = t * l;
+    let c_v = t * c;
+    let l = l / okhsv_scale(l_v, c_v, a_, b_);
+
+    let v = oklab_toe(l) / l_v;
+    let s = (OKHSV_S_0 + t_max) * c_v / (t_max * OKHSV_S_0 + t_max * k * c_v);
+    [h, s, v]
+}
+
+/// 🌌 The Okhsv color space
 ///
-/// The HWB color space is a convenient way to represent colors. It corresponds
-/// closely to popular color pickers, both a triangle with white, black, and
-/// fully saturated color at the corner, and also a rectangle with a hue spectrum
-/// at the top and black at the bottom, with whiteness as a separate slider. It
-/// was proposed in [HWB–A More Intuitive Hue-Based Color Model].
+/// Okhsv is a hue, saturation and value color space built on [Oklab], designed by Björn
+/// Ottosson as a perceptual alternative to HSV for color pickers. As with [Okhsl], its hue is
+/// perceptually meaningful and its components are normalized to the [sRGB](Srgb) gamut.
 ///
-/// Its components are `[H, W, B]` with
-/// - `H` - the hue angle in degrees, with red at 0, green at 120, and blue at 240.
-/// - `W` - an amount of whiteness to mix in, with 100 being white.
-/// - `B` - an amount of blackness to mix in, with 100 being black.
+/// Its components are `[h, s, v]` with
+/// - `h` - the hue angle in degrees, as in [Oklch];
+/// - `s` - the saturation, where 0 is achromatic and 1 is at the edge of the sRGB gamut; and
+/// - `v` - the value, where 0 is black and 1 is either white or a fully saturated color at the
+///   edge of the sRGB gamut.
 ///
-/// The hue angle is the same as in [Hsl], and thus has the same flaw of poor hue
-/// uniformity.
+/// Colors with saturation and value between 0 and 1 are within the sRGB gamut.
 ///
-/// This corresponds to the color space in [CSS Color Module Level 4 § 8][css-sec].
+/// See [Okhsv and Okhsl: two new color spaces for color picking][okhsv] for more information.
 ///
-/// [css-sec]: https://www.w3.org/TR/css-color-4/#the-hwb-notation
-/// [HWB–A More Intuitive Hue-Based Color Model]: http://alvyray.com/Papers/CG/HWB_JGTv208.pdf
+/// [okhsv]: https://bottosson.github.io/posts/colorpicker/
 #[derive(Clone, Copy, Debug)]
-pub struct Hwb;
-
-/// Convert HWB to RGB.
-///
-/// Reference: § 8.1 of CSS Color 4 spec.
-fn hwb_to_rgb([h, w, b]: [f32; 3]) -> [f32; 3] {
-    let white = w * 0.01;
-    let black = b * 0.01;
-    if white + black >= 1.0 {
-        let gray = white / (white + black);
-        [gray, gray, gray]
-    } else {
-        let rgb = hsl_to_rgb([h, 100., 50.]);
-        rgb.map(|x| white + x * (1.0 - white - black))
-    }
-}
-
-/// Convert RGB to HWB.
-///
-/// Reference: § 8.2 of CSS Color 4 spec.
-fn rgb_to_hwb([r, g, b]: [f32; 3]) -> [f32; 3] {
-    let hsl = rgb_to_hsl([r, g, b], false);
-    let white = r.min(g).min(b);
-    let black = 1.0 - r.max(g).max(b);
-    [hsl[0], white * 100., black * 100.]
-}
+pub struct Okhsv;
 
-impl ColorSpace for Hwb {
-    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Hwb);
+impl ColorSpace for Okhsv {
+    const TAG: Option<ColorSpaceTag> = Some(ColorSpaceTag::Okhsv);
 
     const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::HueFirst;
 
-    const WHITE_COMPONENTS: [f32; 3] = [0., 100., 0.];
+    const WHITE_COMPONENTS: [f32; 3] = [0., 0., 1.];
 
     fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        let rgb = Srgb::from_linear_srgb(src);
-        rgb_to_hwb(rgb)
+        oklab_to_okhsv(Oklab::from_linear_srgb(src))
     }
 
     fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
-        let rgb = hwb_to_rgb(src);
-        Srgb::to_linear_srgb(rgb)
+        Oklab::to_linear_srgb(okhsv_to_oklab(src))
+    }
+
+    fn scale_chroma([h, s, v]: [f32; 3], scale: f32) -> [f32; 3] {
+        [h, s * scale, v]
     }
 
     fn convert<TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
         if TypeId::of::<Self>() == TypeId::of::<TargetCS>() {
             src
-        } else if TypeId::of::<TargetCS>() == TypeId::of::<Srgb>() {
-            hwb_to_rgb(src)
-        } else if TypeId::of::<TargetCS>() == TypeId::of::<Hsl>() {
-            rgb_to_hsl(hwb_to_rgb(src), true)
+        } else if TypeId::of::<TargetCS>() == TypeId::of::<Oklab>() {
+            okhsv_to_oklab(src)
         } else {
             let lin_rgb = Self::to_linear_srgb(src);
             TargetCS::from_linear_srgb(lin_rgb)
         }
     }
 
-    fn clip([h, w, b]: [f32; 3]) -> [f32; 3] {
-        [h, w.clamp(0., 100.), b.clamp(0., 100.)]
+    fn clip([h, s, v]: [f32; 3]) -> [f32; 3] {
+        [h, s.clamp(0., 1.), v.clamp(0., 1.)]
+    }
+
+    fn is_in_gamut(src: [f32; 3], epsilon: f32) -> bool {
+        Srgb::is_in_gamut(Self::convert::<Srgb>(src), epsilon)
     }
 }
 
-impl From<Hwb> for ColorSpaceTag {
-    fn from(_: Hwb) -> Self {
-        Self::Hwb
+impl From<Okhsv> for ColorSpaceTag {
+    fn from(_: Okhsv) -> Self {
+        Self::Okhsv
     }
 }
 
@@ -1704,9 +3410,13 @@ impl From<Hwb> for ColorSpaceTag {
 mod tests {
     extern crate alloc;
 
+    use super::{srgb_to_lin, PROPHOTO_TRANSFER};
     use crate::{
-        A98Rgb, Aces2065_1, AcesCg, Chromaticity, ColorSpace, DisplayP3, Hsl, Hwb, Lab, Lch,
-        LinearSrgb, Oklab, Oklch, OpaqueColor, ProphotoRgb, Rec2020, Srgb, XyzD50, XyzD65,
+        A98Rgb, Aces2065_1, AcesCc, AcesCct, AcesCg, ChromaticAdaptation, Chromaticity, ColorSpace,
+        DisplayP3, Hpluv, Hsl, Hsluv, Hsv, Hwb, IctcpHlg, IctcpPq, JzCzhz, Jzazbz, Lab, Lch, LchUv,
+        LinearA98Rgb, LinearDisplayP3, LinearProphotoRgb, LinearRec2020, LinearSrgb, Luv, Okhsl,
+        Okhsv, Oklab, Oklch, OpaqueColor, ProphotoRgb, Rec2020, Rec2100Hlg, Rec2100Pq, Rec709,
+        Srgb, TransferFunction, XyzD50, XyzD65,
     };
     use alloc::vec::Vec;
 
@@ -1774,11 +3484,27 @@ mod tests {
         test_roundtrips::<DisplayP3, Srgb>(&rectangular_values);
         test_roundtrips::<A98Rgb, Srgb>(&rectangular_values);
         test_roundtrips::<ProphotoRgb, Srgb>(&rectangular_values);
+        test_roundtrips::<Rec709, Srgb>(&rectangular_values);
         test_roundtrips::<Rec2020, Srgb>(&rectangular_values);
         test_roundtrips::<Aces2065_1, Srgb>(&rectangular_values);
         test_roundtrips::<AcesCg, Srgb>(&rectangular_values);
         test_roundtrips::<XyzD50, Srgb>(&rectangular_values);
         test_roundtrips::<XyzD65, Srgb>(&rectangular_values);
+        test_roundtrips::<LinearDisplayP3, Srgb>(&rectangular_values);
+        test_roundtrips::<LinearA98Rgb, Srgb>(&rectangular_values);
+        test_roundtrips::<LinearProphotoRgb, Srgb>(&rectangular_values);
+        test_roundtrips::<LinearRec2020, Srgb>(&rectangular_values);
+
+        // The logarithmic ACES encodings are limited to the half-precision range, and ACEScc maps
+        // all values at and below zero to a single encoding.
+        let aces_log_values = [
+            [0., 0., 0.],
+            [0.554_794_5, 0.554_794_5, 0.554_794_5],
+            [0.1, 0.4, 1.2],
+            [1.4, -0.2, 0.6],
+        ];
+        test_roundtrips::<AcesCc, Srgb>(&aces_log_values);
+        test_roundtrips::<AcesCct, Srgb>(&aces_log_values);
 
         test_roundtrips::<Oklab, Srgb>(&[
             [0., 0., 0.],
@@ -1806,20 +3532,392 @@ mod tests {
         check_white::<A98Rgb>();
         check_white::<DisplayP3>();
         check_white::<Hsl>();
+        check_white::<Hsv>();
         check_white::<Hwb>();
         check_white::<Lab>();
         check_white::<Lch>();
+        check_white::<Luv>();
+        check_white::<LchUv>();
+        check_white::<Hsluv>();
+        check_white::<Hpluv>();
+        check_white::<Jzazbz>();
+        check_white::<JzCzhz>();
+        check_white::<IctcpPq>();
+        check_white::<IctcpHlg>();
         check_white::<LinearSrgb>();
+        check_white::<LinearDisplayP3>();
+        check_white::<LinearA98Rgb>();
+        check_white::<LinearProphotoRgb>();
+        check_white::<LinearRec2020>();
         check_white::<Oklab>();
         check_white::<Oklch>();
+        check_white::<Okhsl>();
+        check_white::<Okhsv>();
         check_white::<ProphotoRgb>();
+        check_white::<Rec709>();
         check_white::<Rec2020>();
+        check_white::<Rec2100Pq>();
+        check_white::<Rec2100Hlg>();
         check_white::<Aces2065_1>();
         check_white::<AcesCg>();
+        check_white::<AcesCc>();
+        check_white::<AcesCct>();
         check_white::<XyzD50>();
         check_white::<XyzD65>();
     }
 
+    #[test]
+    fn hsv_srgb() {
+        for (srgb, hsv) in [
+            ([0.1, 0.2, 0.3], [210., 66.666_67, 30.]),
+            ([1., 0.5, 0.], [30., 100., 100.]),
+            ([0.5, 0.5, 0.5], [0., 0., 50.]),
+            ([0., 0., 0.], [0., 0., 0.]),
+        ] {
+            assert!(almost_equal::<Srgb>(srgb, Hsv::convert::<Srgb>(hsv), 1e-4));
+            assert!(almost_equal::<Hsv>(hsv, Srgb::convert::<Hsv>(srgb), 1e-4));
+            assert!(almost_equal::<Srgb>(
+                srgb,
+                LinearSrgb::convert::<Srgb>(Hsv::to_linear_srgb(hsv)),
+                1e-4
+            ));
+        }
+
+        // The specialized conversions between the sRGB-derived color spaces.
+        assert!(almost_equal::<Hsv>(
+            [120., 66.666_67, 37.5],
+            Hsl::convert::<Hsv>([120., 50., 25.]),
+            1e-4
+        ));
+        assert!(almost_equal::<Hsl>(
+            [120., 50., 25.],
+            Hsv::convert::<Hsl>([120., 66.666_67, 37.5]),
+            1e-4
+        ));
+        assert!(almost_equal::<Hsv>(
+            [30., 71.428_57, 70.],
+            Hwb::convert::<Hsv>([30., 20., 30.]),
+            1e-4
+        ));
+        assert!(almost_equal::<Hwb>(
+            [30., 20., 30.],
+            Hsv::convert::<Hwb>([30., 71.428_57, 70.]),
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn okhsl_okhsv() {
+        // Reference values from Björn Ottosson's implementation.
+        for (srgb, okhsl, okhsv) in [
+            ([1., 0., 0.], [29.233_89, 1., 0.568_08], [29.233_89, 1., 1.]),
+            (
+                [0.2, 0.6, 0.3],
+                [148.332_14, 0.918_65, 0.542_72],
+                [148.332_14, 0.794_13, 0.625_97],
+            ),
+            (
+                [0.1, 0.2, 0.9],
+                [266.207_33, 0.975_77, 0.375_15],
+                [266.207_33, 0.928_39, 0.900_12],
+            ),
+            (
+                [0.9, 0.8, 0.2],
+                [99.159_37, 0.883_71, 0.816_24],
+                [99.159_37, 0.863_57, 0.909_7],
+            ),
+        ] {
+            assert!(almost_equal::<Okhsl>(
+                okhsl,
+                Srgb::convert::<Okhsl>(srgb),
+                1e-3
+            ));
+            assert!(almost_equal::<Okhsv>(
+                okhsv,
+                Srgb::convert::<Okhsv>(srgb),
+                1e-3
+            ));
+            assert!(almost_equal::<Srgb>(
+                srgb,
+                Okhsl::convert::<Srgb>(okhsl),
+                1e-3
+            ));
+            assert!(almost_equal::<Srgb>(
+                srgb,
+                Okhsv::convert::<Srgb>(okhsv),
+                1e-3
+            ));
+        }
+
+        // Colors within the sRGB gamut have saturation and lightness or value between 0 and 1.
+        let steps = [0., 0.1, 0.35, 0.5, 0.8, 1.];
+        for r in steps {
+            for g in steps {
+                for b in steps {
+                    let srgb = [r, g, b];
+                    for [_, s, l] in [Srgb::convert::<Okhsl>(srgb), Srgb::convert::<Okhsv>(srgb)] {
+                        assert!(
+                            (-1e-4..=1. + 1e-4).contains(&s),
+                            "{srgb:?} has saturation {s}"
+                        );
+                        assert!(
+                            (-1e-4..=1. + 1e-4).contains(&l),
+                            "{srgb:?} has lightness {l}"
+                        );
+                    }
+                    assert!(almost_equal::<Srgb>(
+                        srgb,
+                        Okhsl::convert::<Srgb>(Srgb::convert::<Okhsl>(srgb)),
+                        1e-4
+                    ));
+                    assert!(almost_equal::<Srgb>(
+                        srgb,
+                        Okhsv::convert::<Srgb>(Srgb::convert::<Okhsv>(srgb)),
+                        1e-4
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn luv_hsluv() {
+        // Reference values from the HSLuv reference implementation's snapshot, which uses a slightly
+        // different white point than `XyzD65`.
+        let srgb = [1., 0., 0.];
+        let luv = [53.237_12, 175.015_1, 37.756_4];
+        let lchuv = [53.237_12, 179.038_1, 12.177_05];
+        let hsluv = [12.177_05, 100., 53.237_12];
+        let hpluv = [12.177_05, 426.746_6, 53.237_12];
+        assert!(almost_equal::<Luv>(luv, Srgb::convert::<Luv>(srgb), 2e-2));
+        assert!(almost_equal::<LchUv>(
+            lchuv,
+            Srgb::convert::<LchUv>(srgb),
+            1e-2
+        ));
+        assert!(almost_equal::<Hsluv>(
+            hsluv,
+            Srgb::convert::<Hsluv>(srgb),
+            1e-2
+        ));
+        assert!(almost_equal::<Hpluv>(
+            hpluv,
+            Srgb::convert::<Hpluv>(srgb),
+            1e-1
+        ));
+
+        // Colors within the sRGB gamut roundtrip, and have an HSLuv saturation of at most 100.
+        let steps = [0., 0.1, 0.35, 0.5, 0.8, 1.];
+        for r in steps {
+            for g in steps {
+                for b in steps {
+                    let srgb = [r, g, b];
+                    let hsluv = Srgb::convert::<Hsluv>(srgb);
+                    assert!(hsluv[1] <= 100. + 1e-2, "{srgb:?} -> {hsluv:?}");
+                    assert!(almost_equal::<Srgb>(
+                        srgb,
+                        Hsluv::convert::<Srgb>(hsluv),
+                        1e-4
+                    ));
+                    assert!(almost_equal::<Srgb>(
+                        srgb,
+                        Hpluv::convert::<Srgb>(Srgb::convert::<Hpluv>(srgb)),
+                        1e-4
+                    ));
+                    assert!(almost_equal::<Srgb>(
+                        srgb,
+                        LchUv::convert::<Srgb>(Srgb::convert::<LchUv>(srgb)),
+                        1e-4
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn jzazbz() {
+        // Reference values computed with the formulas of Safdar et al. in double precision.
+        let srgb = [1., 0., 0.];
+        let jzazbz = [0.134_384_73, 0.117_885_26, 0.111_878_11];
+        let jzczhz = [0.134_384_73, 0.162_522_76, 43.502_345];
+        let lin_srgb = Srgb::to_linear_srgb(srgb);
+        assert!(almost_equal::<Jzazbz>(
+            jzazbz,
+            Srgb::convert::<Jzazbz>(srgb),
+            1e-5
+        ));
+        assert!(almost_equal::<JzCzhz>(
+            jzczhz,
+            Srgb::convert::<JzCzhz>(srgb),
+            2e-3
+        ));
+        assert!(almost_equal::<LinearSrgb>(
+            lin_srgb,
+            Jzazbz::to_linear_srgb(jzazbz),
+            1e-4
+        ));
+
+        // Black has zero lightness, and the lightness increases with the luminance of white.
+        assert!(Jzazbz::from_linear_srgb([0., 0., 0.])[0].abs() < 1e-6);
+        let mut prev = 0.;
+        for luminance in [80., 203., 1_000., 4_000., 10_000.] {
+            let jz = Jzazbz::from_linear_srgb_with_luminance([1., 1., 1.], luminance);
+            assert!(jz[0] > prev, "{luminance} cd/m² gives Jz {}", jz[0]);
+            prev = jz[0];
+            let roundtripped = Jzazbz::to_linear_srgb_with_luminance(jz, luminance);
+            assert!(almost_equal::<LinearSrgb>([1., 1., 1.], roundtripped, 1e-3));
+        }
+
+        // Colors outside the sRGB gamut and brighter than white roundtrip.
+        for lin_srgb in [[1.5, -0.2, 0.1], [4., 4., 4.], [0.01, 0.2, 0.9]] {
+            let jzczhz = JzCzhz::from_linear_srgb_with_luminance(lin_srgb, 100.);
+            let roundtripped = JzCzhz::to_linear_srgb_with_luminance(jzczhz, 100.);
+            assert!(
+                almost_equal::<LinearSrgb>(lin_srgb, roundtripped, magnitude(lin_srgb) * 1e-3),
+                "{lin_srgb:?} -> {roundtripped:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ictcp() {
+        // Reference values computed with the formulas of ITU-R BT.2100 in double precision.
+        let srgb = [1., 0., 0.];
+        let pq = [0.427_880_3, -0.115_704_36, 0.278_728_95];
+        let hlg = [0.418_664_1, -0.056_467_984, 0.320_310_1];
+        assert!(almost_equal::<IctcpPq>(
+            pq,
+            Srgb::convert::<IctcpPq>(srgb),
+            1e-4
+        ));
+        assert!(almost_equal::<IctcpHlg>(
+            hlg,
+            Srgb::convert::<IctcpHlg>(srgb),
+            1e-5
+        ));
+        assert!(almost_equal::<Srgb>(
+            srgb,
+            IctcpPq::convert::<Srgb>(pq),
+            1e-3
+        ));
+        assert!(almost_equal::<Srgb>(
+            srgb,
+            IctcpHlg::convert::<Srgb>(hlg),
+            1e-4
+        ));
+
+        // The intensity of white increases with its luminance.
+        let mut prev = 0.;
+        for luminance in [80., 203., 1_000., 4_000., 10_000.] {
+            let ictcp = IctcpPq::from_linear_srgb_with_luminance([1., 1., 1.], luminance);
+            assert!(ictcp[0] > prev, "{luminance} cd/m² gives I {}", ictcp[0]);
+            assert!(ictcp[1].abs() < 1e-6 && ictcp[2].abs() < 1e-6);
+            prev = ictcp[0];
+            let roundtripped = IctcpPq::to_linear_srgb_with_luminance(ictcp, luminance);
+            assert!(almost_equal::<LinearSrgb>([1., 1., 1.], roundtripped, 1e-3));
+        }
+
+        // Colors brighter than white roundtrip in HLG.
+        for lin_srgb in [[2., 2., 2.], [3., 0.5, 0.1], [0.01, 0.2, 0.9]] {
+            let ictcp = IctcpHlg::from_linear_srgb(lin_srgb);
+            assert!(almost_equal::<LinearSrgb>(
+                lin_srgb,
+                IctcpHlg::to_linear_srgb(ictcp),
+                1e-4
+            ));
+        }
+    }
+
+    #[test]
+    fn rec2100() {
+        // Reference values computed with the formulas of ITU-R BT.2100 in double precision.
+        let srgb = [1., 0., 0.];
+        let pq = [0.532_546, 0.327_023_2, 0.220_069_42];
+        let hlg = [0.655_873_6, 0.234_359_95, 0.114_146_19];
+        assert!(almost_equal::<Rec2100Pq>(
+            pq,
+            Srgb::convert::<Rec2100Pq>(srgb),
+            1e-4
+        ));
+        assert!(almost_equal::<Rec2100Hlg>(
+            hlg,
+            Srgb::convert::<Rec2100Hlg>(srgb),
+            1e-5
+        ));
+        assert!(almost_equal::<Srgb>(
+            srgb,
+            Rec2100Pq::convert::<Srgb>(pq),
+            1e-3
+        ));
+        assert!(almost_equal::<Srgb>(
+            srgb,
+            Rec2100Hlg::convert::<Srgb>(hlg),
+            1e-4
+        ));
+
+        // The peak signal is far brighter than SDR white.
+        let peak = Rec2100Pq::to_linear_srgb([1., 1., 1.]);
+        assert!(almost_equal::<LinearSrgb>([10_000. / 203.; 3], peak, 1e-1));
+        assert!(Rec2100Hlg::to_linear_srgb([1., 1., 1.])[0] > 3.);
+    }
+
+    #[test]
+    fn linear_variants() {
+        fn check<CS: ColorSpace, LinearCS: ColorSpace>(transfer_to_linear: fn(f32) -> f32) {
+            assert!(LinearCS::IS_LINEAR);
+            for color in [[0.5, 0.2, 0.9], [1., 0., 0.], [-0.2, 1.2, 0.4]] {
+                assert!(almost_equal::<LinearCS>(
+                    color.map(transfer_to_linear),
+                    CS::convert::<LinearCS>(color),
+                    1e-5
+                ));
+                assert!(almost_equal::<LinearCS>(
+                    color.map(transfer_to_linear),
+                    CS::convert_absolute::<LinearCS>(color),
+                    1e-5
+                ));
+            }
+        }
+
+        check::<DisplayP3, LinearDisplayP3>(srgb_to_lin);
+        check::<A98Rgb, LinearA98Rgb>(|x| x.abs().powf(563. / 256.).copysign(x));
+        check::<ProphotoRgb, LinearProphotoRgb>(|x| PROPHOTO_TRANSFER.to_linear(x));
+        check::<Rec2020, LinearRec2020>(|x| TransferFunction::Rec2020.to_linear(x));
+    }
+
+    #[test]
+    fn aces_log_acescg() {
+        // The ACEScc and ACEScct encodings of 18% gray and of the breakpoint of the ACEScct toe,
+        // from the ACES specifications.
+        for (acescg, acescc, acescct) in [
+            (0.18, 0.413_588_4, 0.413_588_4),
+            (0.007_812_5, 0.155_251_14, 0.155_251_14),
+            (0., -0.358_447_5, 0.072_905_53),
+        ] {
+            assert!(almost_equal::<AcesCc>(
+                [acescc; 3],
+                AcesCg::convert::<AcesCc>([acescg; 3]),
+                1e-5
+            ));
+            assert!(almost_equal::<AcesCct>(
+                [acescct; 3],
+                AcesCg::convert::<AcesCct>([acescg; 3]),
+                1e-5
+            ));
+        }
+
+        // The two encodings agree above the toe, and differ in the shadows.
+        let color = [0.6, 0.1, 0.3];
+        let acescc = AcesCct::convert::<AcesCc>(color);
+        assert!((acescc[0] - 0.6).abs() < 1e-5);
+        assert!(acescc[1] < 0.1 - 0.01);
+        assert!(almost_equal::<AcesCg>(
+            AcesCct::convert::<AcesCg>(color),
+            AcesCc::convert::<AcesCg>(acescc),
+            1e-5
+        ));
+    }
+
     #[test]
     fn a98rgb_srgb() {
         for (srgb, a98) in [
@@ -1923,6 +4021,53 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn chromatic_adaptation_methods() {
+        let src = [0.5, 0.2, 0.4];
+        assert_eq!(
+            Srgb::chromatically_adapt_with(
+                src,
+                Chromaticity::D65,
+                Chromaticity::D50,
+                ChromaticAdaptation::Bradford
+            ),
+            Srgb::chromatically_adapt(src, Chromaticity::D65, Chromaticity::D50),
+        );
+
+        let d50 = Chromaticity::D50.to_xyz();
+        let bradford = XyzD65::chromatically_adapt(src, Chromaticity::D65, Chromaticity::D50);
+        for method in [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::Cat02,
+            ChromaticAdaptation::Cat16,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::XyzScaling,
+        ] {
+            // Every transform maps the source white to the destination white.
+            let white = XyzD65::chromatically_adapt_with(
+                Chromaticity::D65.to_xyz(),
+                Chromaticity::D65,
+                Chromaticity::D50,
+                method,
+            );
+            assert!(almost_equal::<XyzD50>(white, d50, 1e-5), "{method:?}");
+
+            // And the transforms agree roughly on other colors.
+            let adapted =
+                XyzD65::chromatically_adapt_with(src, Chromaticity::D65, Chromaticity::D50, method);
+            assert!(
+                almost_equal::<XyzD50>(adapted, bradford, 2e-2),
+                "{method:?}"
+            );
+            if method != ChromaticAdaptation::Bradford {
+                assert!(
+                    !almost_equal::<XyzD50>(adapted, bradford, 1e-4),
+                    "{method:?}"
+                );
+            }
+        }
+    }
+
     /// Test whether `ColorSpace::convert` with implicit chromatic adaptation results in the same
     /// color as `ColorSpace::convert_absolute` in combination with explicit chromatic adaptation
     /// through `Colorspace::chromatically_adapt`.
@@ -1987,4 +4132,35 @@ mod tests {
         test::<AcesCg, XyzD50>([0.5, 0.2, 0.4]);
         test::<AcesCg, XyzD65>([0.5, 0.2, 0.4]);
     }
+
+    #[test]
+    fn in_gamut() {
+        // Display P3 green is outside of sRGB, but within Display P3 and Rec. 2020.
+        let p3_green = DisplayP3::convert::<LinearSrgb>([0., 1., 0.]);
+        assert!(!Srgb::is_in_gamut(
+            LinearSrgb::convert::<Srgb>(p3_green),
+            1e-4
+        ));
+        assert!(DisplayP3::is_in_gamut(
+            LinearSrgb::convert::<DisplayP3>(p3_green),
+            1e-4
+        ));
+        assert!(Rec2020::is_in_gamut(
+            LinearSrgb::convert::<Rec2020>(p3_green),
+            1e-4
+        ));
+
+        // HSL and HWB share the gamut of sRGB.
+        assert!(Hsl::is_in_gamut([200., 100., 50.], 1e-4));
+        assert!(!Hsl::is_in_gamut([200., 120., 50.], 1e-4));
+        assert!(Hwb::is_in_gamut([200., 80., 80.], 1e-4));
+        assert!(!Hwb::is_in_gamut([200., -10., 20.], 1e-4));
+
+        // Spaces without gamut limits.
+        assert!(Oklch::is_in_gamut([0.8, 0.5, 145.], 1e-4));
+        assert!(XyzD65::is_in_gamut([-0.5, 2., 0.1], 1e-4));
+
+        // Small conversion errors are tolerated.
+        assert!(Srgb::is_in_gamut([1.000_01, -0.000_01, 0.5], 1e-4));
+    }
 }
@@ -0,0 +1,451 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Color spaces registered at runtime.
+
+#![allow(
+    unsafe_code,
+    reason = "the registry stores `&'static` references as atomic pointers"
+)]
+
+use core::error::Error;
+use core::fmt;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::{
+    matinv, matvecmul,
+    rgb::{xyz_matrix_to_linear_srgb, xyz_matrix_to_linear_srgb_absolute},
+    ChromaticAdaptation, Chromaticity, ColorSpaceTag, RgbSpace, TransferFunction,
+};
+
+/// The maximum number of color spaces that can be registered.
+const REGISTRY_CAPACITY: usize = 256;
+
+/// The registered color spaces, filled front to back. Slots are never cleared.
+static REGISTRY: [AtomicPtr<DynamicColorSpace>; REGISTRY_CAPACITY] =
+    [const { AtomicPtr::new(ptr::null_mut()) }; REGISTRY_CAPACITY];
+
+/// An RGB color space described at runtime.
+///
+/// This describes a color space by its conversion matrices, transfer function and white point,
+/// for color spaces only known at runtime, such as one defined by a CSS `@color-profile` rule or
+/// loaded from a configuration file. Once [registered](DynamicColorSpace::register), the color
+/// space has a [`ColorSpaceTag::Custom`] tag, which can be used like any other tag with
+/// [`DynamicColor`](crate::DynamicColor): for conversion, interpolation and gradients. Colors in
+/// the color space are parsed and serialized using the CSS `color()` function with the color
+/// space's name, e.g., `color(--brand-wide 1 0.5 0)`.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` white. The natural bounds of the channels are `[0, 1]`.
+///
+/// For RGB color spaces known at compile-time, consider implementing
+/// [`RgbColorSpace`](crate::RgbColorSpace) instead.
+///
+/// # Example
+///
+/// ```rust
+/// use color::{parse_color, Chromaticity, DynamicColorSpace, RgbSpace, TransferFunction};
+///
+/// let space = DynamicColorSpace::new(
+///     "--brand-wide",
+///     RgbSpace::new(
+///         Chromaticity { x: 0.700, y: 0.300 },
+///         Chromaticity { x: 0.170, y: 0.790 },
+///         Chromaticity { x: 0.140, y: 0.050 },
+///         Chromaticity::D65,
+///         TransferFunction::Gamma(2.2),
+///     ),
+/// );
+/// // Color spaces loaded at runtime can be leaked to obtain a `'static` reference.
+/// let tag = Box::leak(Box::new(space)).register().unwrap();
+///
+/// let color = parse_color("color(--brand-wide 1 0.5 0)").unwrap();
+/// assert_eq!(color.cs, tag);
+/// assert_eq!(color.to_string(), "color(--brand-wide 1 0.5 0)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynamicColorSpace {
+    name: &'static str,
+    white_point: Chromaticity,
+    transfer_function: TransferFunction,
+    to_linear_srgb: [[f32; 3]; 3],
+    from_linear_srgb: [[f32; 3]; 3],
+    to_linear_srgb_absolute: [[f32; 3]; 3],
+    from_linear_srgb_absolute: [[f32; 3]; 3],
+}
+
+impl DynamicColorSpace {
+    /// Describe the RGB color space given by its primaries, white point and transfer function.
+    ///
+    /// The `name` is the CSS dashed identifier the color space is parsed and serialized with, such
+    /// as `--brand-wide`.
+    pub const fn new(name: &'static str, space: RgbSpace) -> Self {
+        Self::from_xyz_matrix(
+            name,
+            space.to_xyz_matrix(),
+            space.white_point,
+            space.transfer_function,
+        )
+    }
+
+    /// Describe the RGB color space given by the matrix converting its linear-light components to
+    /// XYZ, its white point and its transfer function.
+    ///
+    /// The XYZ values are relative to `white_point`. This matches, e.g., the colorant tags of
+    /// matrix-based ICC profiles, after undoing their adaptation to the D50 profile connection
+    /// space.
+    ///
+    /// The `name` is the CSS dashed identifier the color space is parsed and serialized with, such
+    /// as `--brand-wide`.
+    pub const fn from_xyz_matrix(
+        name: &'static str,
+        to_xyz: [[f32; 3]; 3],
+        white_point: Chromaticity,
+        transfer_function: TransferFunction,
+    ) -> Self {
+        let to_linear_srgb = xyz_matrix_to_linear_srgb(&to_xyz, white_point);
+        let to_linear_srgb_absolute = xyz_matrix_to_linear_srgb_absolute(&to_xyz);
+        Self {
+            name,
+            white_point,
+            transfer_function,
+            to_linear_srgb,
+            from_linear_srgb: matinv(&to_linear_srgb),
+            to_linear_srgb_absolute,
+            from_linear_srgb_absolute: matinv(&to_linear_srgb_absolute),
+        }
+    }
+
+    /// The name of the color space, as used in the CSS `color()` function.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The white point of the color space.
+    pub const fn white_point(&self) -> Chromaticity {
+        self.white_point
+    }
+
+    /// The transfer function of the color space.
+    pub const fn transfer_function(&self) -> TransferFunction {
+        self.transfer_function
+    }
+
+    /// Register the color space, returning its tag.
+    ///
+    /// Registered color spaces live for the remainder of the program. Up to 256 color spaces can
+    /// be registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not a CSS dashed identifier (starting with `--`), if a
+    /// different color space with the same name is already registered, or if the registry is full.
+    /// Registering the same color space twice returns the existing tag.
+    ///
+    /// Note that the names of built-in color spaces, such as `--okhsl`, take precedence when
+    /// parsing.
+    pub fn register(&'static self) -> Result<ColorSpaceTag, RegisterError> {
+        if !self.name.starts_with("--") || self.name.len() <= 2 {
+            return Err(RegisterError::InvalidName);
+        }
+        let new = ptr::from_ref(self).cast_mut();
+        for (id, slot) in (0..=u8::MAX).zip(&REGISTRY) {
+            // Concurrent registrations all compete for the first free slot, so a color space with
+            // the same name is always seen by the losers.
+            let existing = match slot.compare_exchange(
+                ptr::null_mut(),
+                new,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return Ok(ColorSpaceTag::Custom(CustomColorSpaceId(id))),
+                Err(existing) => existing,
+            };
+            // Safety: non-null pointers in the registry come from `&'static` references.
+            let existing = unsafe { &*existing };
+            if existing.name == self.name {
+                return if existing == self {
+                    Ok(ColorSpaceTag::Custom(CustomColorSpaceId(id)))
+                } else {
+                    Err(RegisterError::DuplicateName)
+                };
+            }
+        }
+        Err(RegisterError::RegistryFull)
+    }
+
+    /// Look up the tag of a registered color space by its name.
+    pub fn lookup(name: &str) -> Option<ColorSpaceTag> {
+        (0..=u8::MAX)
+            .zip(&REGISTRY)
+            .map(|(id, slot)| (id, slot.load(Ordering::Acquire)))
+            .take_while(|(_, space)| !space.is_null())
+            // Safety: non-null pointers in the registry come from `&'static` references.
+            .find(|(_, space)| unsafe { &**space }.name == name)
+            .map(|(id, _)| ColorSpaceTag::Custom(CustomColorSpaceId(id)))
+    }
+
+    /// Convert an opaque color to linear sRGB.
+    ///
+    /// This is the runtime counterpart of [`ColorSpace::to_linear_srgb`](crate::ColorSpace::to_linear_srgb).
+    pub fn to_linear_srgb(&self, src: [f32; 3]) -> [f32; 3] {
+        let tf = self.transfer_function;
+        matvecmul(&self.to_linear_srgb, src.map(|x| tf.to_linear(x)))
+    }
+
+    /// Convert an opaque color from linear sRGB.
+    ///
+    /// This is the runtime counterpart of [`ColorSpace::from_linear_srgb`](crate::ColorSpace::from_linear_srgb).
+    pub fn from_linear_srgb(&self, src: [f32; 3]) -> [f32; 3] {
+        let tf = self.transfer_function;
+        matvecmul(&self.from_linear_srgb, src).map(|x| tf.from_linear(x))
+    }
+
+    /// Convert an opaque color to linear sRGB, without chromatic adaptation.
+    ///
+    /// This is the runtime counterpart of
+    /// [`ColorSpace::to_linear_srgb_absolute`](crate::ColorSpace::to_linear_srgb_absolute).
+    pub fn to_linear_srgb_absolute(&self, src: [f32; 3]) -> [f32; 3] {
+        let tf = self.transfer_function;
+        matvecmul(&self.to_linear_srgb_absolute, src.map(|x| tf.to_linear(x)))
+    }
+
+    /// Convert an opaque color from linear sRGB, without chromatic adaptation.
+    ///
+    /// This is the runtime counterpart of
+    /// [`ColorSpace::from_linear_srgb_absolute`](crate::ColorSpace::from_linear_srgb_absolute).
+    pub fn from_linear_srgb_absolute(&self, src: [f32; 3]) -> [f32; 3] {
+        let tf = self.transfer_function;
+        matvecmul(&self.from_linear_srgb_absolute, src).map(|x| tf.from_linear(x))
+    }
+
+    /// Chromatically adapt the color between the given white point chromaticities.
+    ///
+    /// This is the runtime counterpart of
+    /// [`ColorSpace::chromatically_adapt`](crate::ColorSpace::chromatically_adapt).
+    pub fn chromatically_adapt(
+        &self,
+        src: [f32; 3],
+        from: Chromaticity,
+        to: Chromaticity,
+    ) -> [f32; 3] {
+        self.chromatically_adapt_with(src, from, to, ChromaticAdaptation::Bradford)
+    }
+
+    /// Chromatically adapt the color between the given white point chromaticities, using the
+    /// given chromatic adaptation transform.
+    ///
+    /// This is the runtime counterpart of
+    /// [`ColorSpace::chromatically_adapt_with`](crate::ColorSpace::chromatically_adapt_with).
+    pub fn chromatically_adapt_with(
+        &self,
+        src: [f32; 3],
+        from: Chromaticity,
+        to: Chromaticity,
+        method: ChromaticAdaptation,
+    ) -> [f32; 3] {
+        if from == to {
+            return src;
+        }
+        let lin_srgb_adapted = matvecmul(
+            &from.linear_srgb_chromatic_adaptation_matrix(to, method),
+            self.to_linear_srgb_absolute(src),
+        );
+        self.from_linear_srgb_absolute(lin_srgb_adapted)
+    }
+
+    /// Clip the color's components to fit within the natural gamut of the color space.
+    ///
+    /// This is the runtime counterpart of [`ColorSpace::clip`](crate::ColorSpace::clip).
+    pub fn clip(&self, [r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+
+    /// Whether the color is within the gamut of the color space.
+    ///
+    /// This is the runtime counterpart of [`ColorSpace::is_in_gamut`](crate::ColorSpace::is_in_gamut).
+    pub fn is_in_gamut(&self, src: [f32; 3], epsilon: f32) -> bool {
+        let clipped = self.clip(src);
+        (0..3).all(|i| (clipped[i] - src[i]).abs() <= epsilon)
+    }
+}
+
+/// The identifier of a [registered](DynamicColorSpace::register) color space.
+///
+/// This is the payload of [`ColorSpaceTag::Custom`]. Identifiers are only handed out by the
+/// registry, so an identifier always refers to a registered color space. The numeric value of an
+/// identifier depends on the order of registration, so serialization uses the color space's name
+/// instead.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct CustomColorSpaceId(u8);
+
+impl CustomColorSpaceId {
+    /// The registered color space.
+    pub fn space(self) -> &'static DynamicColorSpace {
+        let space = REGISTRY[self.0 as usize].load(Ordering::Acquire);
+        debug_assert!(!space.is_null(), "identifiers refer to registered spaces");
+        // Safety: identifiers are only handed out for filled slots, which are never cleared, and
+        // non-null pointers in the registry come from `&'static` references.
+        unsafe { &*space }
+    }
+}
+
+/// Error type for [registering](DynamicColorSpace::register) color spaces.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RegisterError {
+    /// The name is not a CSS dashed identifier
+    InvalidName,
+    /// A different color space with the same name is already registered
+    DuplicateName,
+    /// The maximum number of color spaces is already registered
+    RegistryFull,
+}
+
+impl Error for RegisterError {}
+
+impl fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match *self {
+            Self::InvalidName => "color space name is not a dashed identifier",
+            Self::DuplicateName => "a color space with this name is already registered",
+            Self::RegistryFull => "too many color spaces registered",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CustomColorSpaceId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.space().name)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CustomColorSpaceId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NameVisitor;
+
+        impl serde::de::Visitor<'_> for NameVisitor {
+            type Value = CustomColorSpaceId;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("the name of a registered color space")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Self::Value, E> {
+                match DynamicColorSpace::lookup(name) {
+                    Some(ColorSpaceTag::Custom(id)) => Ok(id),
+                    _ => Err(E::invalid_value(serde::de::Unexpected::Str(name), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(NameVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::{DynamicColorSpace, RegisterError};
+    use crate::{
+        Chromaticity, ColorSpace, ColorSpaceTag, DisplayP3, DynamicColor, HueDirection, RgbSpace,
+        Srgb, TransferFunction,
+    };
+
+    const P3: RgbSpace = RgbSpace::new(
+        Chromaticity { x: 0.680, y: 0.320 },
+        Chromaticity { x: 0.265, y: 0.690 },
+        Chromaticity { x: 0.150, y: 0.060 },
+        Chromaticity::D65,
+        TransferFunction::Srgb,
+    );
+
+    static CUSTOM_P3: DynamicColorSpace = DynamicColorSpace::new("--custom-p3", P3);
+
+    fn assert_close(a: [f32; 3], b: [f32; 3]) {
+        assert!(
+            (0..3).all(|i| (a[i] - b[i]).abs() < 1e-4),
+            "{a:?} and {b:?} differ"
+        );
+    }
+
+    #[test]
+    fn register() {
+        let tag = CUSTOM_P3.register().unwrap();
+        assert!(matches!(tag, ColorSpaceTag::Custom(_)));
+        assert_eq!(CUSTOM_P3.register(), Ok(tag));
+        assert_eq!(DynamicColorSpace::lookup("--custom-p3"), Some(tag));
+        assert_eq!(tag.custom_space(), Some(&CUSTOM_P3));
+
+        static IMPOSTOR: DynamicColorSpace = DynamicColorSpace::new(
+            "--custom-p3",
+            P3.with_transfer_function(TransferFunction::Linear),
+        );
+        assert_eq!(IMPOSTOR.register(), Err(RegisterError::DuplicateName));
+
+        static UNDASHED: DynamicColorSpace = DynamicColorSpace::new("custom-p3", P3);
+        assert_eq!(UNDASHED.register(), Err(RegisterError::InvalidName));
+        assert_eq!(DynamicColorSpace::lookup("--unregistered"), None);
+    }
+
+    #[test]
+    fn convert() {
+        let tag = CUSTOM_P3.register().unwrap();
+        for components in [[1., 1., 1.], [1., 0., 0.], [0.2, 0.8, 0.4]] {
+            assert_close(
+                tag.convert(ColorSpaceTag::Srgb, components),
+                DisplayP3::convert::<Srgb>(components),
+            );
+            assert_close(
+                ColorSpaceTag::Srgb.convert(tag, components),
+                Srgb::convert::<DisplayP3>(components),
+            );
+        }
+    }
+
+    #[test]
+    fn parse_and_interpolate() {
+        static WIDE_D50: DynamicColorSpace = DynamicColorSpace::new(
+            "--wide-d50",
+            RgbSpace::new(
+                Chromaticity { x: 0.735, y: 0.265 },
+                Chromaticity { x: 0.115, y: 0.826 },
+                Chromaticity { x: 0.157, y: 0.018 },
+                Chromaticity::D50,
+                TransferFunction::Gamma(2.2),
+            ),
+        );
+        let tag = WIDE_D50.register().unwrap();
+
+        let color: DynamicColor = "color(--wide-d50 1 50% 0 / 0.5)".parse().unwrap();
+        assert_eq!(color.cs, tag);
+        assert_eq!(color.components, [1., 0.5, 0., 0.5]);
+        assert_eq!(alloc::format!("{color}"), "color(--wide-d50 1 0.5 0 / 0.5)");
+
+        // White is white, no matter the white point.
+        let white = DynamicColor::from_alpha_color(crate::AlphaColor::<Srgb>::WHITE);
+        assert_close(
+            white
+                .convert(tag)
+                .to_alpha_color::<Srgb>()
+                .discard_alpha()
+                .components,
+            [1., 1., 1.],
+        );
+
+        let black = DynamicColor::from_alpha_color(crate::AlphaColor::<Srgb>::BLACK);
+        let mid = black
+            .interpolate(white, tag, HueDirection::default())
+            .eval(0.5);
+        assert_eq!(mid.cs, tag);
+        assert_close(
+            [mid.components[0], mid.components[1], mid.components[2]],
+            [0.5, 0.5, 0.5],
+        );
+    }
+}
@@ -6,8 +6,9 @@
 use crate::{
     cache_key::{BitEq, BitHash},
     color::{add_alpha, fixup_hues_for_interpolate, split_alpha, InterpolationAlphaSpace},
-    AlphaColor, Chromaticity, ColorSpace, ColorSpaceLayout, ColorSpaceTag, Flags, HueDirection,
-    LinearSrgb, Missing,
+    gamut, palette, AlphaColor, ChromaticAdaptation, Chromaticity, ColorSpace, ColorSpaceLayout,
+    ColorSpaceTag, ColorTemperature, ColorTemperatureMethod, Flags, GamutMapMethod, HueDirection,
+    LinearSrgb, Missing, Oklab,
 };
 use core::hash::{Hash, Hasher};
 
@@ -177,15 +178,34 @@ impl DynamicColor {
     ///
     /// The color is assumed to be under a reference white point of `from` and is chromatically
     /// adapted to the given white point `to`. The linear Bradford transform is used to perform the
-    /// chromatic adaptation.
+    /// chromatic adaptation. See [`DynamicColor::chromatically_adapt_with`] to use a different
+    /// transform.
     pub fn chromatically_adapt(self, from: Chromaticity, to: Chromaticity) -> Self {
+        self.chromatically_adapt_with(from, to, ChromaticAdaptation::Bradford)
+    }
+
+    #[must_use]
+    /// Chromatically adapt the color between the given white point chromaticities, using the
+    /// given chromatic adaptation transform.
+    ///
+    /// The color is assumed to be under a reference white point of `from` and is chromatically
+    /// adapted to the given white point `to`.
+    pub fn chromatically_adapt_with(
+        self,
+        from: Chromaticity,
+        to: Chromaticity,
+        method: ChromaticAdaptation,
+    ) -> Self {
         if from == to {
             return self;
         }
 
         // Treat missing components as zero, as per CSS Color Module Level 4 § 4.4.
         let (opaque, alpha) = split_alpha(self.zero_missing_components().components);
-        let components = add_alpha(self.cs.chromatically_adapt(opaque, from, to), alpha);
+        let components = add_alpha(
+            self.cs.chromatically_adapt_with(opaque, from, to, method),
+            alpha,
+        );
         Self {
             cs: self.cs,
             // After chromatically adapting the color, components may no longer be missing. Don't
@@ -291,6 +311,97 @@ impl DynamicColor {
         }
     }
 
+    /// Whether the color is within the gamut of the given color space.
+    ///
+    /// The color is converted to the color space, after which its components are checked using
+    /// [`ColorSpaceTag::is_in_gamut`] with a small tolerance for conversion errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color::{parse_color, ColorSpaceTag};
+    ///
+    /// let color = parse_color("color(display-p3 0 1 0)").unwrap();
+    /// assert!(color.is_in_gamut_of(ColorSpaceTag::DisplayP3));
+    /// assert!(color.is_in_gamut_of(ColorSpaceTag::Rec2020));
+    /// assert!(!color.is_in_gamut_of(ColorSpaceTag::Srgb));
+    /// ```
+    #[must_use]
+    pub fn is_in_gamut_of(self, cs: ColorSpaceTag) -> bool {
+        let (opaque, _) = split_alpha(self.convert(cs).components);
+        cs.is_in_gamut(opaque, gamut::IN_GAMUT_EPSILON)
+    }
+
+    /// Map the color into the gamut of the given color space.
+    ///
+    /// Colors that are already within the gamut of the target color space are converted
+    /// unchanged. Other colors have their chroma reduced in [Oklch] until clipping the color
+    /// results in a difference that is not noticeable, as described in
+    /// [CSS Color Module Level 4 § 13.2][css-sec]. Very light and very dark colors are mapped to
+    /// white and black respectively. Color spaces without gamut limits, such as [Oklab] and
+    /// [Lab], are not clipped.
+    ///
+    /// [`ColorSpaceTag::Hsl`], [`ColorSpaceTag::Hwb`], [`ColorSpaceTag::Hsv`],
+    /// [`ColorSpaceTag::Hsluv`], [`ColorSpaceTag::Hpluv`], [`ColorSpaceTag::Okhsl`] and
+    /// [`ColorSpaceTag::Okhsv`] are mapped to the gamut of sRGB.
+    ///
+    /// If the color is changed by gamut mapping, its components are no longer considered missing.
+    /// The alpha channel is preserved.
+    ///
+    /// [css-sec]: https://www.w3.org/TR/css-color-4/#binsearch
+    /// [Oklch]: crate::Oklch
+    /// [Oklab]: crate::Oklab
+    /// [Lab]: crate::Lab
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color::{parse_color, ColorSpaceTag};
+    ///
+    /// let color = parse_color("oklch(0.8 0.4 145)").unwrap();
+    /// let mapped = color.map_to_gamut(ColorSpaceTag::Srgb);
+    /// assert_eq!(mapped.cs, ColorSpaceTag::Srgb);
+    /// assert!(mapped.components.iter().all(|x| (0. ..=1.).contains(x)));
+    /// ```
+    #[must_use]
+    pub fn map_to_gamut(self, cs: ColorSpaceTag) -> Self {
+        self.map_to_gamut_with(cs, GamutMapMethod::Css)
+    }
+
+    /// Map the color into the gamut of the given color space, using the given method.
+    ///
+    /// See [`GamutMapMethod`] for the available methods and the color spaces they support. As
+    /// with [`DynamicColor::map_to_gamut`], colors that are already within the gamut are converted
+    /// unchanged, and the alpha channel is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use color::{parse_color, ColorSpaceTag, GamutMapMethod, Oklch};
+    ///
+    /// let color = parse_color("oklch(0.8 0.4 145)").unwrap();
+    /// let mapped = color.map_to_gamut_with(ColorSpaceTag::Srgb, GamutMapMethod::PreserveLightness);
+    /// assert!(mapped.components.iter().all(|x| (0. ..=1.).contains(x)));
+    ///
+    /// // The lightness is preserved.
+    /// let [l, _, _, _] = mapped.to_alpha_color::<Oklch>().components;
+    /// assert!((l - 0.8).abs() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn map_to_gamut_with(self, cs: ColorSpaceTag, method: GamutMapMethod) -> Self {
+        let converted = self.convert(cs);
+        if cs.is_in_gamut(split_alpha(converted.components).0, gamut::IN_GAMUT_EPSILON) {
+            return converted;
+        }
+        let (oklch, alpha) = split_alpha(self.convert(ColorSpaceTag::Oklch).components);
+        let components = gamut::map_oklch_to_gamut(oklch, cs, method);
+        Self {
+            cs,
+            flags: Flags::default(),
+            components: add_alpha(components, alpha),
+        }
+    }
+
     fn split(self, alpha_type: InterpolationAlphaSpace) -> ([f32; 3], f32) {
         // Reference: §12.3 of Color 4 spec
         let (opaque, alpha) = split_alpha(self.components);
@@ -317,7 +428,15 @@ impl DynamicColor {
 
         match self.cs {
             // See CSS Color Module level 4 § 7, § 9.3, and § 9.4 (HSL, LCH, Oklch).
-            ColorSpaceTag::Hsl | ColorSpaceTag::Lch | ColorSpaceTag::Oklch
+            ColorSpaceTag::Hsl
+            | ColorSpaceTag::Hsluv
+            | ColorSpaceTag::Hpluv
+            | ColorSpaceTag::Lch
+            | ColorSpaceTag::LchUv
+            | ColorSpaceTag::Oklch
+            | ColorSpaceTag::JzCzhz
+            | ColorSpaceTag::Okhsl
+            | ColorSpaceTag::Okhsv
                 if self.components[1] < 1e-6 =>
             {
                 let mut missing = self.flags.missing();
@@ -331,6 +450,13 @@ impl DynamicColor {
                 self.cs.set_h_missing(&mut missing, &mut self.components);
                 self.flags.set_missing(missing);
             }
+
+            // Like HWB, HSV black has no hue.
+            ColorSpaceTag::Hsv if self.components[1] < 1e-6 || self.components[2] < 1e-4 => {
+                let mut missing = self.flags.missing();
+                self.cs.set_h_missing(&mut missing, &mut self.components);
+                self.flags.set_missing(missing);
+            }
             _ => {}
         }
     }
@@ -494,6 +620,35 @@ impl DynamicColor {
         0.2126 * r + 0.7152 * g + 0.0722 * b
     }
 
+    /// Find the CSS named color closest to this color.
+    ///
+    /// This returns the name of the color, with the deltaEOK distance to it. See
+    /// [`palette::css::nearest_named_color`] for details.
+    ///
+    /// ```rust
+    /// use color::parse_color;
+    ///
+    /// let color = parse_color("#6495ef").unwrap();
+    /// assert_eq!(color.nearest_named_color().0, "cornflowerblue");
+    /// ```
+    ///
+    /// [`palette::css::nearest_named_color`]: crate::palette::css::nearest_named_color
+    #[must_use]
+    pub fn nearest_named_color(self) -> (&'static str, f32) {
+        let [l, a, b, _] = self.to_alpha_color::<Oklab>().components;
+        palette::css::nearest_named_color_oklab([l, a, b])
+    }
+
+    /// Estimate the correlated color temperature and Duv of the color.
+    ///
+    /// The chromaticity of the color is determined through [`XyzD65`](crate::XyzD65), ignoring
+    /// alpha. See [`ColorTemperature`] for more details.
+    #[must_use]
+    pub fn color_temperature(self, method: ColorTemperatureMethod) -> ColorTemperature {
+        let (xyz, _) = split_alpha(self.convert(ColorSpaceTag::XyzD65).components);
+        ColorTemperature::from_chromaticity(Chromaticity::from_xyz(xyz), method)
+    }
+
     /// Map components.
     #[must_use]
     pub fn map(self, f: impl Fn(f32, f32, f32, f32) -> [f32; 4]) -> Self {
@@ -529,13 +684,16 @@ impl DynamicColor {
     #[must_use]
     pub fn map_lightness(self, f: impl Fn(f32) -> f32) -> Self {
         match self.cs {
-            ColorSpaceTag::Lab | ColorSpaceTag::Lch => {
+            ColorSpaceTag::Lab | ColorSpaceTag::Lch | ColorSpaceTag::Luv | ColorSpaceTag::LchUv => {
                 self.map(|l, c1, c2, a| [100.0 * f(l * 0.01), c1, c2, a])
             }
             ColorSpaceTag::Oklab | ColorSpaceTag::Oklch => {
                 self.map(|l, c1, c2, a| [f(l), c1, c2, a])
             }
-            ColorSpaceTag::Hsl => self.map(|h, s, l, a| [h, s, 100.0 * f(l * 0.01), a]),
+            ColorSpaceTag::Hsl | ColorSpaceTag::Hsluv | ColorSpaceTag::Hpluv => {
+                self.map(|h, s, l, a| [h, s, 100.0 * f(l * 0.01), a])
+            }
+            ColorSpaceTag::Okhsl => self.map(|h, s, l, a| [h, s, f(l), a]),
             _ => self.map_in(ColorSpaceTag::Oklab, |l, a, b, alpha| [f(l), a, b, alpha]),
         }
     }
@@ -719,6 +877,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn preserves_jzczhz_chroma_missingness() {
+        let c = parse_color("color(--jzczhz 0.1 none 240)").unwrap();
+        assert_eq!(c.flags.missing(), Missing::single(1));
+        assert!(c.convert(ColorSpaceTag::Oklch).flags.missing().contains(1));
+
+        // Chroma is also carried over to and back from the saturation of non-analogous spaces.
+        let hsl = c.convert(ColorSpaceTag::Hsl);
+        assert!(hsl.flags.missing().contains(1));
+        assert!(hsl
+            .convert(ColorSpaceTag::JzCzhz)
+            .flags
+            .missing()
+            .contains(1));
+    }
+
     #[test]
     fn achromatic_sets_hue_powerless() {
         let c = parse_color("oklab(0.2 0 0)").unwrap();
@@ -729,6 +903,10 @@ mod tests {
             c.convert(ColorSpaceTag::Hsl).flags.missing(),
             Missing::single(0)
         );
+        assert_eq!(
+            c.convert(ColorSpaceTag::Hsv).flags.missing(),
+            Missing::single(0)
+        );
     }
 
     #[test]
@@ -753,6 +931,12 @@ mod tests {
             ("hsl(240 0.01 50)", &[]),
             // ... and colorful colors don't either.
             ("hsl(240 0.6 50)", &[]),
+            // HSV black results in powerless hue, whatever its saturation...
+            ("color(--hsv 240 60 0)", &[0]),
+            ("color(--hsv 240 60 0.00001)", &[0]),
+            ("color(--hsv 240 0 50)", &[0]),
+            // ... but dark colorful colors don't.
+            ("color(--hsv 240 60 0.01)", &[]),
             // In lab-like spaces, zero lightness does not (currently) result in powerless
             // components.
             ("lab(0 0.4 -0.3)", &[]),
@@ -50,11 +50,16 @@ define_float_funcs! {
     fn ceil(self) -> Self => ceilf;
     // This is not needed once the MSRV is 1.84 or later.
     fn copysign(self, sign: Self) -> Self => copysignf;
+    fn exp(self) -> Self => expf;
+    fn exp2(self) -> Self => exp2f;
     fn floor(self) -> Self => floorf;
     fn hypot(self, other: Self) -> Self => hypotf;
     // Note: powi is missing because its libm implementation is not efficient
+    fn ln(self) -> Self => logf;
+    fn log2(self) -> Self => log2f;
     fn powf(self, n: Self) -> Self => powf;
     fn round(self) -> Self => roundf;
+    fn sin(self) -> Self => sinf;
     fn sin_cos(self) -> (Self, Self) => sincosf;
     fn sqrt(self) -> Self => sqrtf;
 }
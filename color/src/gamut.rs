@@ -0,0 +1,892 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Gamut mapping.
+//!
+//! [`RgbGamut`] describes the gamut of RGB color spaces in [Oklab].
+//!
+//! This also implements the gamut mapping algorithm of [CSS Color Module Level 4 § 13.2][css-sec],
+//! which reduces the chroma of a color in [Oklch] until it is within the just noticeable
+//! difference of its clipped form, along with the alternatives listed in [`GamutMapMethod`].
+//!
+//! [css-sec]: https://www.w3.org/TR/css-color-4/#binsearch
+
+use core::marker::PhantomData;
+
+use crate::{
+    colorspace::{
+        LINEAR_DISPLAYP3_TO_SRGB, LINEAR_REC2020_TO_SRGB, LINEAR_SRGB_TO_DISPLAYP3,
+        LINEAR_SRGB_TO_REC2020, OKLAB_LAB_TO_LMS, OKLAB_LMS_TO_LAB, OKLAB_LMS_TO_SRGB,
+        OKLAB_SRGB_TO_LMS,
+    },
+    matmatmul, matvecmul, ColorSpace, ColorSpaceTag, Oklab, Oklch, Srgb,
+};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The just noticeable difference, in deltaEOK, between a color and its clipped form.
+const JND: f32 = 0.02;
+
+/// The chroma precision at which the binary search stops.
+const CHROMA_EPSILON: f32 = 0.0001;
+
+/// The tolerance for considering a component to be within the gamut.
+pub(crate) const IN_GAMUT_EPSILON: f32 = 0.000_075;
+
+/// The minimum chroma used for the projection methods, so the hue is always well-defined.
+const PROJECTION_MIN_CHROMA: f32 = 0.000_01;
+
+/// The number of iterations of the ray trace method.
+const RAY_TRACE_ITERATIONS: usize = 4;
+
+/// The inset from the edges of the linear RGB cube used by the ray trace method.
+const RAY_TRACE_EPSILON: f32 = 0.000_001;
+
+/// The method used to map colors into a gamut.
+///
+/// All methods map colors that are within the gamut unchanged, and map colors with an [Oklch]
+/// lightness of at least 1 or at most 0 to white and black respectively.
+///
+/// The projection methods and [`GamutMapMethod::RayTrace`] operate on the geometry of an RGB gamut,
+/// and are available for [sRGB](crate::Srgb) (including [linear sRGB](crate::LinearSrgb),
+/// [HSL](crate::Hsl), [HWB](crate::Hwb), [HSV](crate::Hsv), [Hsluv](crate::Hsluv),
+/// [Hpluv](crate::Hpluv), [Okhsl](crate::Okhsl) and [Okhsv](crate::Okhsv)),
+/// [Display P3](crate::DisplayP3) and [Rec. 2020](crate::Rec2020) (each including its linear
+/// variant). For other color spaces, these fall back to [`GamutMapMethod::Css`].
+///
+/// The projection methods move the color along a straight line in the Oklab plane of constant
+/// hue, towards a point on the achromatic axis with lightness `L0`, until it hits the gamut
+/// boundary. They are described on [Björn Ottosson's blog][bjorn].
+///
+/// [bjorn]: https://bottosson.github.io/posts/gamutclipping/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum GamutMapMethod {
+    /// The binary search algorithm of [CSS Color Module Level 4 § 13.2][css-sec].
+    ///
+    /// This reduces the chroma in [Oklch] until clipping the color results in a difference that
+    /// is not noticeable.
+    ///
+    /// [css-sec]: https://www.w3.org/TR/css-color-4/#binsearch
+    #[default]
+    Css,
+    /// Clip the components of the color to the target color space.
+    ///
+    /// This is the cheapest method, but may shift the hue and lightness noticeably.
+    Clip,
+    /// Reduce the chroma, keeping the [Oklch] lightness and hue.
+    PreserveLightness,
+    /// Project towards the achromatic point with lightness 0.5, keeping the hue.
+    ProjectToMidGray,
+    /// Project towards the achromatic point with the lightness of the gamut's cusp at the color's
+    /// hue, keeping the hue.
+    ///
+    /// This keeps the chroma as high as possible, at the cost of lightness.
+    ProjectToCusp,
+    /// Project towards an achromatic point between the color's lightness and 0.5.
+    ///
+    /// Colors with higher chroma are projected more strongly towards 0.5, as controlled by
+    /// `alpha`. Björn Ottosson suggests `0.05`.
+    AdaptiveMidGray {
+        /// The strength of the adaptation.
+        alpha: f32,
+    },
+    /// Project towards an achromatic point between the color's lightness and the lightness of
+    /// the gamut's cusp.
+    ///
+    /// Colors with higher chroma are projected more strongly towards the cusp's lightness, as
+    /// controlled by `alpha`. Björn Ottosson suggests `0.05`.
+    AdaptiveCusp {
+        /// The strength of the adaptation.
+        alpha: f32,
+    },
+    /// Cast rays through the linear RGB cube, correcting the [Oklch] lightness and hue of the
+    /// intersection with its surface after each step.
+    ///
+    /// This is the ray trace approach proposed for CSS Color, as implemented by [ColorAide].
+    /// It approximates the result of [`GamutMapMethod::PreserveLightness`].
+    ///
+    /// [ColorAide]: https://facelessuser.github.io/coloraide/gamut/#ray-tracing-chroma-reduction
+    RayTrace,
+}
+
+/// The operations needed to map colors into the gamut of a target color space.
+///
+/// This abstracts over static color spaces and [`ColorSpaceTag`], so both the static and dynamic
+/// color types can share the gamut mapping algorithm.
+trait Gamut: Copy {
+    /// Whether the components are within the gamut of the target color space.
+    fn is_in_gamut(self, src: [f32; 3]) -> bool;
+
+    /// Convert components from [`Oklch`] to the target color space.
+    fn oklch_to_target(self, src: [f32; 3]) -> [f32; 3];
+
+    /// Convert components from [`Srgb`] to the target color space.
+    fn srgb_to_target(self, src: [f32; 3]) -> [f32; 3];
+
+    /// Convert components from the target color space to [`Oklab`].
+    fn target_to_oklab(self, src: [f32; 3]) -> [f32; 3];
+
+    /// Clip components to the target color space.
+    fn clip(self, src: [f32; 3]) -> [f32; 3];
+}
+
+#[derive(Clone, Copy)]
+struct StaticGamut<CS>(PhantomData<CS>);
+
+impl<CS: ColorSpace> Gamut for StaticGamut<CS> {
+    fn is_in_gamut(self, src: [f32; 3]) -> bool {
+        CS::is_in_gamut(src, IN_GAMUT_EPSILON)
+    }
+
+    fn oklch_to_target(self, src: [f32; 3]) -> [f32; 3] {
+        Oklch::convert::<CS>(src)
+    }
+
+    fn srgb_to_target(self, src: [f32; 3]) -> [f32; 3] {
+        Srgb::convert::<CS>(src)
+    }
+
+    fn target_to_oklab(self, src: [f32; 3]) -> [f32; 3] {
+        CS::convert::<Oklab>(src)
+    }
+
+    fn clip(self, src: [f32; 3]) -> [f32; 3] {
+        CS::clip(src)
+    }
+}
+
+impl Gamut for ColorSpaceTag {
+    fn is_in_gamut(self, src: [f32; 3]) -> bool {
+        Self::is_in_gamut(self, src, IN_GAMUT_EPSILON)
+    }
+
+    fn oklch_to_target(self, src: [f32; 3]) -> [f32; 3] {
+        Self::Oklch.convert(self, src)
+    }
+
+    fn srgb_to_target(self, src: [f32; 3]) -> [f32; 3] {
+        Self::Srgb.convert(self, src)
+    }
+
+    fn target_to_oklab(self, src: [f32; 3]) -> [f32; 3] {
+        self.convert(Self::Oklab, src)
+    }
+
+    fn clip(self, src: [f32; 3]) -> [f32; 3] {
+        Self::clip(self, src)
+    }
+}
+
+/// The deltaEOK between an Oklch color and a color in the target color space.
+fn delta_eok(gamut: impl Gamut, oklch: [f32; 3], target: [f32; 3]) -> f32 {
+    let [l1, a1, b1] = Oklch::convert::<Oklab>(oklch);
+    let [l2, a2, b2] = gamut.target_to_oklab(target);
+    let (dl, da, db) = (l1 - l2, a1 - a2, b1 - b2);
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// Map the Oklch color into the gamut, returning components in the target color space.
+///
+/// This follows the binary search algorithm in [CSS Color Module Level 4 § 13.2][css-sec].
+///
+/// [css-sec]: https://www.w3.org/TR/css-color-4/#binsearch
+fn map_oklch(gamut: impl Gamut, oklch: [f32; 3]) -> [f32; 3] {
+    let origin = gamut.oklch_to_target(oklch);
+    if gamut.is_in_gamut(origin) {
+        return origin;
+    }
+
+    let [l, c, h] = oklch;
+    if l >= 1. {
+        return gamut.clip(gamut.srgb_to_target([1., 1., 1.]));
+    }
+    if l <= 0. {
+        return gamut.clip(gamut.srgb_to_target([0., 0., 0.]));
+    }
+
+    let mut clipped = gamut.clip(origin);
+    if delta_eok(gamut, oklch, clipped) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.;
+    let mut max = c;
+    let mut min_in_gamut = true;
+    while max - min > CHROMA_EPSILON {
+        let chroma = 0.5 * (min + max);
+        let current = [l, chroma, h];
+        let target = gamut.oklch_to_target(current);
+        if min_in_gamut && gamut.is_in_gamut(target) {
+            min = chroma;
+            continue;
+        }
+        clipped = gamut.clip(target);
+        let e = delta_eok(gamut, current, clipped);
+        if e < JND {
+            if JND - e < CHROMA_EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
+
+/// Map a color into the gamut of a static color space.
+pub(crate) fn map_to_gamut<CS: ColorSpace, TargetCS: ColorSpace>(src: [f32; 3]) -> [f32; 3] {
+    let oklch = CS::convert::<Oklch>(src);
+    match TargetCS::TAG {
+        // HSL, HWB, HSV, HSLuv, HPLuv, Okhsl and Okhsv are mapped to the gamut of sRGB.
+        Some(
+            ColorSpaceTag::Hsl
+            | ColorSpaceTag::Hwb
+            | ColorSpaceTag::Hsv
+            | ColorSpaceTag::Hsluv
+            | ColorSpaceTag::Hpluv
+            | ColorSpaceTag::Okhsl
+            | ColorSpaceTag::Okhsv,
+        ) => Srgb::convert::<TargetCS>(map_oklch(StaticGamut::<Srgb>(PhantomData), oklch)),
+        _ => map_oklch(StaticGamut::<TargetCS>(PhantomData), oklch),
+    }
+}
+
+/// Map a color given as [`Oklch`] components into the gamut of a color space given by a tag.
+pub(crate) fn map_oklch_to_gamut(
+    oklch: [f32; 3],
+    cs: ColorSpaceTag,
+    method: GamutMapMethod,
+) -> [f32; 3] {
+    let rgb_gamut = match cs {
+        // HSL, HWB, HSV, HSLuv, HPLuv, Okhsl and Okhsv are mapped to the gamut of sRGB.
+        ColorSpaceTag::Hsl
+        | ColorSpaceTag::Hwb
+        | ColorSpaceTag::Hsv
+        | ColorSpaceTag::Hsluv
+        | ColorSpaceTag::Hpluv
+        | ColorSpaceTag::Okhsl
+        | ColorSpaceTag::Okhsv => {
+            let srgb = map_oklch_to_gamut(oklch, ColorSpaceTag::Srgb, method);
+            return ColorSpaceTag::Srgb.convert(cs, srgb);
+        }
+        ColorSpaceTag::Srgb | ColorSpaceTag::LinearSrgb | ColorSpaceTag::Rec709 => {
+            Some(RgbGamut::SRGB)
+        }
+        ColorSpaceTag::DisplayP3 | ColorSpaceTag::LinearDisplayP3 => Some(RgbGamut::DISPLAY_P3),
+        ColorSpaceTag::Rec2020 | ColorSpaceTag::LinearRec2020 => Some(RgbGamut::REC2020),
+        _ => None,
+    };
+    let gamut = match (method, rgb_gamut) {
+        (GamutMapMethod::Clip, _) => {
+            return cs.clip(ColorSpaceTag::Oklch.convert(cs, oklch));
+        }
+        (GamutMapMethod::Css, _) | (_, None) => return map_oklch(cs, oklch),
+        (_, Some(gamut)) => gamut,
+    };
+
+    let origin = cs.oklch_to_target(oklch);
+    if Gamut::is_in_gamut(cs, origin) {
+        return origin;
+    }
+    let l = oklch[0];
+    if l >= 1. {
+        return cs.clip(cs.srgb_to_target([1., 1., 1.]));
+    }
+    if l <= 0. {
+        return cs.clip(cs.srgb_to_target([0., 0., 0.]));
+    }
+
+    let oklab = if method == GamutMapMethod::RayTrace {
+        gamut.linear_to_oklab(ray_trace(gamut, Oklch::convert::<Oklab>(oklch)))
+    } else {
+        project(gamut, method, Oklch::convert::<Oklab>(oklch))
+    };
+    cs.clip(ColorSpaceTag::Oklab.convert(cs, oklab))
+}
+
+/// Project an Oklab color onto the gamut boundary, towards the achromatic point selected by the
+/// projection method.
+fn project(gamut: RgbGamut, method: GamutMapMethod, [l, a, b]: [f32; 3]) -> [f32; 3] {
+    let c = (a * a + b * b).sqrt().max(PROJECTION_MIN_CHROMA);
+    let (a_, b_) = (a / c, b / c);
+    let cusp = gamut.cusp_ab(a_, b_);
+
+    let l0 = match method {
+        GamutMapMethod::ProjectToMidGray => 0.5,
+        GamutMapMethod::ProjectToCusp => cusp.lightness,
+        GamutMapMethod::AdaptiveMidGray { alpha } => {
+            let ld = l - 0.5;
+            let e1 = 0.5 + ld.abs() + alpha * c;
+            0.5 * (1. + ld.signum() * (e1 - (e1 * e1 - 2. * ld.abs()).sqrt()))
+        }
+        GamutMapMethod::AdaptiveCusp { alpha } => {
+            let ld = l - cusp.lightness;
+            let k = 2.
+                * if ld > 0. {
+                    1. - cusp.lightness
+                } else {
+                    cusp.lightness
+                };
+            let e1 = 0.5 * k + ld.abs() + alpha * c / k;
+            cusp.lightness + 0.5 * (ld.signum() * (e1 - (e1 * e1 - 2. * k * ld.abs()).sqrt()))
+        }
+        _ => l.clamp(0., 1.),
+    };
+
+    let t = gamut.intersect(a_, b_, [l, c], l0, cusp);
+    let c = t * c;
+    [l0 * (1. - t) + t * l, c * a_, c * b_]
+}
+
+/// Find the gamut boundary by casting rays through the linear RGB cube, returning linear RGB
+/// components.
+///
+/// Each ray is cast from an anchor on the achromatic axis through the current color, after which
+/// the lightness and hue of the intersection are corrected to those of the original color.
+fn ray_trace(gamut: RgbGamut, [l, a, b]: [f32; 3]) -> [f32; 3] {
+    let c = (a * a + b * b).sqrt().max(PROJECTION_MIN_CHROMA);
+    let (a_, b_) = (a / c, b / c);
+    let low = RAY_TRACE_EPSILON;
+    let high = 1. - RAY_TRACE_EPSILON;
+
+    let mut anchor = gamut.oklab_to_linear([l, 0., 0.]);
+    let mut current = gamut.oklab_to_linear([l, a, b]);
+    for i in 0..RAY_TRACE_ITERATIONS {
+        if i > 0 {
+            let [_, a, b] = gamut.linear_to_oklab(current);
+            let c = (a * a + b * b).sqrt();
+            current = gamut.oklab_to_linear([l, c * a_, c * b_]);
+        }
+        let intersection = ray_box_exit(anchor, current, low, high);
+        if i > 0 && current.iter().all(|&x| low < x && x < high) {
+            // Move the anchor closer to the surface when possible.
+            anchor = current;
+        }
+        match intersection {
+            Some(intersection) => current = intersection,
+            None => break,
+        }
+    }
+    current
+}
+
+/// The point where the ray from `start` through `end` exits the cube spanning `low` to `high`.
+///
+/// This is the slab method, specialized for a ray that starts within the cube.
+fn ray_box_exit(start: [f32; 3], end: [f32; 3], low: f32, high: f32) -> Option<[f32; 3]> {
+    let mut t_exit = f32::INFINITY;
+    for i in 0..3 {
+        let d = end[i] - start[i];
+        if d == 0. {
+            continue;
+        }
+        let bound = if d > 0. { high } else { low };
+        let t = (bound - start[i]) / d;
+        if t > 0. {
+            t_exit = t_exit.min(t);
+        }
+    }
+    if !t_exit.is_finite() {
+        return None;
+    }
+    Some([0, 1, 2].map(|i| start[i] + t_exit * (end[i] - start[i])))
+}
+
+/// The number of Halley's method iterations used to refine the analytic gamut approximations.
+const HALLEY_ITERATIONS: usize = 2;
+
+/// The gamut of an RGB color space, described in terms of [Oklab].
+///
+/// This allows efficiently querying the maximum chroma that fits within the gamut for a given
+/// [Oklch] lightness and hue, and the gamut's cusp: the point of maximum chroma at a given hue.
+///
+/// The computations follow the approach on [Björn Ottosson's blog][bjorn]: the maximum
+/// saturation at a given hue is estimated by a polynomial approximation, which is then refined
+/// with a few iterations of Halley's method (a higher-order variant of Newton's method). The
+/// resulting chroma is typically accurate to within `1e-4`.
+///
+/// [bjorn]: https://bottosson.github.io/posts/gamutclipping/
+///
+/// # Example
+///
+/// ```rust
+/// use color::RgbGamut;
+///
+/// // The maximum chroma of a mid-lightness pink is larger in wider gamuts.
+/// let srgb = RgbGamut::SRGB.max_chroma(0.6, 0.);
+/// let p3 = RgbGamut::DISPLAY_P3.max_chroma(0.6, 0.);
+/// let rec2020 = RgbGamut::REC2020.max_chroma(0.6, 0.);
+/// assert!(srgb < p3 && p3 < rec2020);
+///
+/// // The cusp has the highest chroma at a given hue.
+/// let cusp = RgbGamut::SRGB.cusp(0.);
+/// assert!(cusp.chroma >= srgb);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RgbGamut {
+    /// Conversion from Oklab's (linear) LMS to the linear RGB color space.
+    lms_to_rgb: [[f32; 3]; 3],
+    /// Conversion from the linear RGB color space to Oklab's (linear) LMS.
+    rgb_to_lms: [[f32; 3]; 3],
+    /// The line in the `a`/`b` plane past which the red channel is the first to go out of gamut
+    /// when increasing saturation.
+    red_region: [f32; 2],
+    /// As `red_region`, but for the green channel.
+    green_region: [f32; 2],
+    /// The coefficients of the polynomials approximating maximum saturation, for the regions in
+    /// which the red, green and blue channels respectively are the first to go out of gamut.
+    saturation_coefficients: [[f32; 5]; 3],
+}
+
+/// The cusp of an [`RgbGamut`] at some hue: the point of maximum chroma.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GamutCusp {
+    /// The [Oklch] lightness of the cusp.
+    pub lightness: f32,
+    /// The [Oklch] chroma of the cusp.
+    pub chroma: f32,
+}
+
+impl RgbGamut {
+    /// The gamut of [sRGB](crate::Srgb).
+    ///
+    /// The approximation coefficients are those given by Björn Ottosson.
+    pub const SRGB: Self = Self {
+        lms_to_rgb: OKLAB_LMS_TO_SRGB,
+        rgb_to_lms: OKLAB_SRGB_TO_LMS,
+        red_region: [-1.881_703_3, -0.809_364_9],
+        green_region: [1.814_441_1, -1.194_452_8],
+        saturation_coefficients: [
+            [
+                1.190_862_8,
+                1.765_767_3,
+                0.596_626_4,
+                0.755_152,
+                0.567_712_4,
+            ],
+            [
+                0.739_565_15,
+                -0.459_544_04,
+                0.082_854_27,
+                0.125_410_7,
+                0.145_032_04,
+            ],
+            [
+                1.357_336_5,
+                -0.009_157_99,
+                -1.151_302_1,
+                -0.505_596_06,
+                0.006_921_67,
+            ],
+        ],
+    };
+
+    /// The gamut of [Display P3](crate::DisplayP3).
+    pub const DISPLAY_P3: Self = Self {
+        lms_to_rgb: matmatmul(&LINEAR_SRGB_TO_DISPLAYP3, &OKLAB_LMS_TO_SRGB),
+        rgb_to_lms: matmatmul(&OKLAB_SRGB_TO_LMS, &LINEAR_DISPLAYP3_TO_SRGB),
+        red_region: [-1.772_344_1, -0.820_758_7],
+        green_region: [1.803_198_9, -1.193_281_5],
+        saturation_coefficients: [
+            [1.461_596_6, 2.056_82, 0.736_873_4, 0.840_645, 0.670_511_87],
+            [
+                0.776_115_8,
+                -0.456_748_6,
+                0.117_808_46,
+                0.136_930_2,
+                -0.173_686_88,
+            ],
+            [
+                1.477_891,
+                -0.031_100_877,
+                -1.240_449_1,
+                -0.531_479_9,
+                0.025_364_574,
+            ],
+        ],
+    };
+
+    /// The gamut of [Rec. 2020](crate::Rec2020).
+    pub const REC2020: Self = Self {
+        lms_to_rgb: matmatmul(&LINEAR_SRGB_TO_REC2020, &OKLAB_LMS_TO_SRGB),
+        rgb_to_lms: matmatmul(&OKLAB_SRGB_TO_LMS, &LINEAR_REC2020_TO_SRGB),
+        red_region: [-1.368_349, -0.466_647_65],
+        green_region: [2.011_508_2, -2.037_909_8],
+        saturation_coefficients: [
+            [
+                2.647_335_3,
+                3.972_327_5,
+                1.021_960_8,
+                1.717_684_9,
+                0.913_246_75,
+            ],
+            [
+                0.911_046,
+                -0.571_888_4,
+                0.209_036_47,
+                0.195_678_86,
+                -0.275_846_1,
+            ],
+            [
+                1.703_903,
+                -0.067_380_39,
+                -1.459_588_7,
+                -0.659_299_3,
+                0.062_848_45,
+            ],
+        ],
+    };
+
+    /// Convert Oklab components to the linear RGB color space.
+    fn oklab_to_linear(&self, src: [f32; 3]) -> [f32; 3] {
+        let lms = matvecmul(&OKLAB_LAB_TO_LMS, src).map(|x| x * x * x);
+        matvecmul(&self.lms_to_rgb, lms)
+    }
+
+    /// Convert components in the linear RGB color space to Oklab.
+    fn linear_to_oklab(&self, src: [f32; 3]) -> [f32; 3] {
+        let lms = matvecmul(&self.rgb_to_lms, src).map(f32::cbrt);
+        matvecmul(&OKLAB_LMS_TO_LAB, lms)
+    }
+
+    /// The maximum saturation `S = C / L` within the gamut, for the hue given by the normalized
+    /// `a` and `b` components.
+    fn max_saturation(&self, a: f32, b: f32) -> f32 {
+        let (k, w) = if self.red_region[0] * a + self.red_region[1] * b > 1. {
+            (self.saturation_coefficients[0], self.lms_to_rgb[0])
+        } else if self.green_region[0] * a + self.green_region[1] * b > 1. {
+            (self.saturation_coefficients[1], self.lms_to_rgb[1])
+        } else {
+            (self.saturation_coefficients[2], self.lms_to_rgb[2])
+        };
+
+        // Approximate the maximum saturation with a polynomial.
+        let mut s = k[0] + k[1] * a + k[2] * b + k[3] * a * a + k[4] * a * b;
+
+        // Refine, solving for the saturation at which the channel reaches 0.
+        let [k_l, k_m, k_s] = matvecmul(&OKLAB_LAB_TO_LMS, [0., a, b]);
+        for _ in 0..HALLEY_ITERATIONS {
+            let l_ = 1. + s * k_l;
+            let m_ = 1. + s * k_m;
+            let s_ = 1. + s * k_s;
+
+            let l = l_ * l_ * l_;
+            let m = m_ * m_ * m_;
+            let s3 = s_ * s_ * s_;
+
+            let l_ds = 3. * k_l * l_ * l_;
+            let m_ds = 3. * k_m * m_ * m_;
+            let s_ds = 3. * k_s * s_ * s_;
+
+            let l_ds2 = 6. * k_l * k_l * l_;
+            let m_ds2 = 6. * k_m * k_m * m_;
+            let s_ds2 = 6. * k_s * k_s * s_;
+
+            let f = w[0] * l + w[1] * m + w[2] * s3;
+            let f1 = w[0] * l_ds + w[1] * m_ds + w[2] * s_ds;
+            let f2 = w[0] * l_ds2 + w[1] * m_ds2 + w[2] * s_ds2;
+
+            s -= f * f1 / (f1 * f1 - 0.5 * f * f2);
+        }
+        s
+    }
+
+    /// The cusp for the hue given by the normalized `a` and `b` components.
+    pub(crate) fn cusp_ab(&self, a: f32, b: f32) -> GamutCusp {
+        let s = self.max_saturation(a, b);
+        let lms = matvecmul(&OKLAB_LAB_TO_LMS, [1., s * a, s * b]).map(|x| x * x * x);
+        let [r, g, b] = matvecmul(&self.lms_to_rgb, lms);
+        let lightness = (1. / r.max(g).max(b)).cbrt();
+        GamutCusp {
+            lightness,
+            chroma: lightness * s,
+        }
+    }
+
+    /// The cusp of the gamut at the given [Oklch] hue (in degrees).
+    ///
+    /// This is the point with maximum chroma within the gamut at that hue.
+    pub fn cusp(&self, hue: f32) -> GamutCusp {
+        let (b, a) = hue.to_radians().sin_cos();
+        self.cusp_ab(a, b)
+    }
+
+    /// The maximum chroma within the gamut at the given [Oklch] lightness and hue (in degrees).
+    ///
+    /// Returns 0 when the lightness is outside the range `(0, 1)`.
+    pub fn max_chroma(&self, lightness: f32, hue: f32) -> f32 {
+        if !(lightness > 0. && lightness < 1.) {
+            return 0.;
+        }
+        let (b, a) = hue.to_radians().sin_cos();
+        let cusp = self.cusp_ab(a, b);
+        self.intersect(a, b, [lightness, 1.], lightness, cusp)
+    }
+
+    /// Find the intersection of the line from `[l0, 0]` to `[l1, c1]` with the gamut boundary,
+    /// at the hue given by the normalized `a` and `b` components.
+    ///
+    /// The intersection is returned as the parameter `t` along the line, where `t = 0` is at
+    /// `[l0, 0]` and `t = 1` is at `[l1, c1]`. The lightness `l0` must be in the range `[0, 1]`.
+    pub(crate) fn intersect(
+        &self,
+        a: f32,
+        b: f32,
+        [l1, c1]: [f32; 2],
+        l0: f32,
+        cusp: GamutCusp,
+    ) -> f32 {
+        if (l1 - l0) * cusp.chroma - (cusp.lightness - l0) * c1 <= 0. {
+            // The lower half of the gamut is exactly triangular.
+            return cusp.chroma * l0 / (c1 * cusp.lightness + cusp.chroma * (l0 - l1));
+        }
+
+        // The upper half is approximated by a triangle, then refined to reach the boundary.
+        let mut t =
+            cusp.chroma * (l0 - 1.) / (c1 * (cusp.lightness - 1.) + cusp.chroma * (l0 - l1));
+
+        let dl = l1 - l0;
+        let [k_l, k_m, k_s] = matvecmul(&OKLAB_LAB_TO_LMS, [0., a, b]);
+        let l_dt = dl + c1 * k_l;
+        let m_dt = dl + c1 * k_m;
+        let s_dt = dl + c1 * k_s;
+        for _ in 0..HALLEY_ITERATIONS {
+            let l = l0 * (1. - t) + t * l1;
+            let c = t * c1;
+
+            let l_ = l + c * k_l;
+            let m_ = l + c * k_m;
+            let s_ = l + c * k_s;
+
+            let lms = [l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_];
+            let lms_dt = [
+                3. * l_dt * l_ * l_,
+                3. * m_dt * m_ * m_,
+                3. * s_dt * s_ * s_,
+            ];
+            let lms_dt2 = [
+                6. * l_dt * l_dt * l_,
+                6. * m_dt * m_dt * m_,
+                6. * s_dt * s_dt * s_,
+            ];
+
+            let rgb = matvecmul(&self.lms_to_rgb, lms);
+            let rgb_dt = matvecmul(&self.lms_to_rgb, lms_dt);
+            let rgb_dt2 = matvecmul(&self.lms_to_rgb, lms_dt2);
+
+            let mut step = f32::MAX;
+            for i in 0..3 {
+                let f = rgb[i] - 1.;
+                let u = rgb_dt[i] / (rgb_dt[i] * rgb_dt[i] - 0.5 * f * rgb_dt2[i]);
+                if u >= 0. {
+                    step = step.min(-f * u);
+                }
+            }
+            t += step;
+        }
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GamutMapMethod, RgbGamut};
+    use crate::{
+        AlphaColor, ColorSpace, ColorSpaceTag, DisplayP3, DynamicColor, Hsl, LinearSrgb, Oklch,
+        Rec2020, Srgb,
+    };
+
+    /// Find the maximum in-gamut chroma by bisection.
+    fn max_chroma_bisect<CS: ColorSpace>(l: f32, h: f32) -> f32 {
+        let (mut lo, mut hi) = (0., 0.5);
+        for _ in 0..40 {
+            let c = 0.5 * (lo + hi);
+            // Check in the linear space, as the transfer functions are not part of the gamut.
+            let rgb = Oklch::convert::<CS>([l, c, h]);
+            if rgb.iter().all(|x| (-1e-7..=1. + 1e-7).contains(x)) {
+                lo = c;
+            } else {
+                hi = c;
+            }
+        }
+        lo
+    }
+
+    #[test]
+    fn in_gamut_unchanged() {
+        let color = AlphaColor::<Srgb>::new([0.2, 0.5, 0.8, 0.5]);
+        let mapped = color.convert::<Oklch>().map_to_gamut::<Srgb>();
+        assert!(
+            color.difference(mapped) < 1e-5,
+            "in-gamut colors should not change"
+        );
+    }
+
+    #[test]
+    fn out_of_gamut_within_bounds() {
+        // A vivid Oklch green, well outside of sRGB and Display P3.
+        let color = AlphaColor::<Oklch>::new([0.8, 0.4, 145., 1.]);
+        for [r, g, b, a] in [
+            color.map_to_gamut::<Srgb>().components,
+            color.map_to_gamut::<DisplayP3>().components,
+        ] {
+            for x in [r, g, b] {
+                assert!((0. ..=1.).contains(&x), "{x} is out of gamut");
+            }
+            assert_eq!(a, 1., "alpha should be preserved");
+        }
+
+        // Chroma is reduced while lightness and hue are approximately preserved.
+        let [l, c, h, _] = color.map_to_gamut::<Srgb>().convert::<Oklch>().components;
+        assert!((l - 0.8).abs() < 0.02, "lightness changed too much: {l}");
+        assert!(c < 0.4, "chroma should be reduced: {c}");
+        assert!((h - 145.).abs() < 5., "hue changed too much: {h}");
+    }
+
+    #[test]
+    fn white_and_black() {
+        let white = AlphaColor::<Oklch>::new([1.1, 0.2, 30., 1.]).map_to_gamut::<Srgb>();
+        assert_eq!(white.components, [1., 1., 1., 1.]);
+        let black = AlphaColor::<Oklch>::new([-0.1, 0.2, 30., 1.]).map_to_gamut::<Srgb>();
+        assert_eq!(black.components, [0., 0., 0., 1.]);
+    }
+
+    #[test]
+    fn dynamic_matches_static() {
+        let color = AlphaColor::<Oklch>::new([0.7, 0.35, 20., 0.8]);
+        let expected = color.map_to_gamut::<Hsl>();
+        let mapped = DynamicColor::from_alpha_color(color).map_to_gamut(ColorSpaceTag::Hsl);
+        assert_eq!(mapped.cs, ColorSpaceTag::Hsl);
+        assert!(
+            expected.difference(mapped.to_alpha_color()) < 1e-3,
+            "dynamic and static gamut mapping should agree"
+        );
+    }
+
+    #[test]
+    fn unbounded_target() {
+        let color = DynamicColor::from_alpha_color(AlphaColor::<Srgb>::new([2., -1., 0.5, 1.]));
+        let mapped = color.map_to_gamut(ColorSpaceTag::Oklab);
+        assert_eq!(mapped.cs, ColorSpaceTag::Oklab);
+        assert_eq!(
+            mapped.components,
+            color.convert(ColorSpaceTag::Oklab).components
+        );
+    }
+
+    #[test]
+    fn methods() {
+        let methods = [
+            GamutMapMethod::Css,
+            GamutMapMethod::Clip,
+            GamutMapMethod::PreserveLightness,
+            GamutMapMethod::ProjectToMidGray,
+            GamutMapMethod::ProjectToCusp,
+            GamutMapMethod::AdaptiveMidGray { alpha: 0.05 },
+            GamutMapMethod::AdaptiveCusp { alpha: 0.05 },
+            GamutMapMethod::RayTrace,
+        ];
+        let targets = [
+            ColorSpaceTag::Srgb,
+            ColorSpaceTag::LinearSrgb,
+            ColorSpaceTag::Hsl,
+            ColorSpaceTag::DisplayP3,
+            ColorSpaceTag::Rec2020,
+            ColorSpaceTag::A98Rgb,
+        ];
+        for method in methods {
+            for cs in targets {
+                for hue in (0..360).step_by(30) {
+                    for l in [0.1, 0.5, 0.9] {
+                        let h = hue as f32;
+                        let color = DynamicColor::from_alpha_color(AlphaColor::<Oklch>::new([
+                            l, 0.5, h, 1.,
+                        ]));
+                        let mapped = color.map_to_gamut_with(cs, method);
+                        assert_eq!(mapped.cs, cs);
+                        assert!(
+                            mapped.is_in_gamut_of(cs),
+                            "{method:?} mapped L={l}, h={h} out of the gamut of {cs:?}"
+                        );
+
+                        let [ml, mc, mh, _] = mapped.to_alpha_color::<Oklch>().components;
+                        assert!(mc < 0.5, "{method:?} did not reduce chroma");
+
+                        // The geometric methods keep the hue, but fall back to the CSS
+                        // algorithm for A98 RGB.
+                        if matches!(method, GamutMapMethod::Css | GamutMapMethod::Clip)
+                            || cs == ColorSpaceTag::A98Rgb
+                        {
+                            continue;
+                        }
+                        if mc > 0.01 {
+                            let dh = (mh - h + 540.) % 360. - 180.;
+                            assert!(
+                                dh.abs() < 2.,
+                                "{method:?} changed the hue of L={l}, h={h} to {mh}"
+                            );
+                        }
+                        if matches!(
+                            method,
+                            GamutMapMethod::PreserveLightness | GamutMapMethod::RayTrace
+                        ) {
+                            assert!(
+                                (ml - l).abs() < 1e-3,
+                                "{method:?} changed the lightness of L={l}, h={h} to {ml}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_chroma() {
+        fn check<CS: ColorSpace>(gamut: RgbGamut) {
+            for hue in (0..360).step_by(5) {
+                let h = hue as f32;
+                for l in [0.05, 0.2, 0.4, 0.5, 0.6, 0.8, 0.95] {
+                    let expected = max_chroma_bisect::<CS>(l, h);
+                    let c = gamut.max_chroma(l, h);
+                    assert!(
+                        (c - expected).abs() < 1e-4,
+                        "max chroma at L={l}, h={h} was {c}, expected {expected}"
+                    );
+                }
+            }
+        }
+        check::<LinearSrgb>(RgbGamut::SRGB);
+        check::<Srgb>(RgbGamut::SRGB);
+        check::<DisplayP3>(RgbGamut::DISPLAY_P3);
+        check::<Rec2020>(RgbGamut::REC2020);
+
+        assert_eq!(RgbGamut::SRGB.max_chroma(0., 90.), 0.);
+        assert_eq!(RgbGamut::SRGB.max_chroma(1., 90.), 0.);
+    }
+
+    #[test]
+    fn cusp() {
+        for gamut in [RgbGamut::SRGB, RgbGamut::DISPLAY_P3, RgbGamut::REC2020] {
+            for hue in (0..360).step_by(15) {
+                let h = hue as f32;
+                let cusp = gamut.cusp(h);
+                // The cusp has the maximum chroma at its hue.
+                assert!(
+                    (gamut.max_chroma(cusp.lightness, h) - cusp.chroma).abs() < 1e-4,
+                    "cusp at h={h} is not on the gamut boundary"
+                );
+                for dl in [-0.02, 0.02] {
+                    assert!(
+                        gamut.max_chroma(cusp.lightness + dl, h) < cusp.chroma,
+                        "chroma near the cusp at h={h} exceeds the cusp chroma"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -47,31 +47,11 @@ unsafe impl bytemuck::Pod for Rgba8 {}
 // Safety: The struct is `repr(C)` and all members are bytemuck::Zeroable.
 unsafe impl bytemuck::Zeroable for Rgba8 {}
 
-// Safety: The enum is `repr(u8)` and has only fieldless variants.
-unsafe impl bytemuck::NoUninit for ColorSpaceTag {}
-
-// Safety: The enum is `repr(u8)` and `0` is a valid value.
+// Safety: The enum is `repr(u8)`, so its discriminant comes first, and `0` is the discriminant
+// of a fieldless variant. (The `Custom` variant carries data, so the enum does not implement
+// `NoUninit`, `CheckedBitPattern` or `Contiguous`.)
 unsafe impl bytemuck::Zeroable for ColorSpaceTag {}
 
-// Safety: The enum is `repr(u8)`.
-unsafe impl bytemuck::checked::CheckedBitPattern for ColorSpaceTag {
-    type Bits = u8;
-
-    fn is_valid_bit_pattern(bits: &u8) -> bool {
-        use bytemuck::Contiguous;
-        // Don't need to compare against MIN_VALUE as this is u8 and 0 is the MIN_VALUE.
-        *bits <= Self::MAX_VALUE
-    }
-}
-
-// Safety: The enum is `repr(u8)`. All values are `u8` and fall within
-// the min and max values.
-unsafe impl bytemuck::Contiguous for ColorSpaceTag {
-    type Int = u8;
-    const MIN_VALUE: u8 = Self::Srgb as u8;
-    const MAX_VALUE: u8 = Self::Aces2065_1 as u8;
-}
-
 // Safety: The enum is `repr(u8)` and has only fieldless variants.
 unsafe impl bytemuck::NoUninit for HueDirection {}
 
@@ -173,13 +153,6 @@ mod tests {
         let valid = bytemuck::bytes_of(&2_u8);
         let invalid = bytemuck::bytes_of(&200_u8);
 
-        assert_eq!(
-            Ok(&ColorSpaceTag::Lab),
-            try_from_bytes::<ColorSpaceTag>(valid)
-        );
-
-        assert!(try_from_bytes::<ColorSpaceTag>(invalid).is_err());
-
         assert_eq!(
             Ok(&HueDirection::Increasing),
             try_from_bytes::<HueDirection>(valid)
@@ -190,12 +163,6 @@ mod tests {
 
     #[test]
     fn contiguous() {
-        let cst1 = ColorSpaceTag::LinearSrgb;
-        let cst2 = ColorSpaceTag::from_integer(cst1.into_integer());
-        assert_eq!(Some(cst1), cst2);
-
-        assert_eq!(None, ColorSpaceTag::from_integer(255));
-
         let hd1 = HueDirection::Decreasing;
         let hd2 = HueDirection::from_integer(hd1.into_integer());
         assert_eq!(Some(hd1), hd2);
@@ -267,20 +234,6 @@ mod tests {
             value += 1;
         }
     };
-
-    /// Tests that the [`Contiguous`] impl for [`ColorSpaceTag`] is not trivially incorrect.
-    const _: () = {
-        let mut value = 0;
-        while value <= ColorSpaceTag::MAX_VALUE {
-            // Safety: In a const context, therefore if this makes an invalid ColorSpaceTag, that will be detected.
-            let it: ColorSpaceTag = unsafe { ptr::read((&raw const value).cast()) };
-            // Evaluate the enum value to ensure it actually has a valid tag
-            if it as u8 != value {
-                unreachable!();
-            }
-            value += 1;
-        }
-    };
 }
 
 #[cfg(doctest)]
@@ -305,22 +258,4 @@ mod doctests {
     /// }
     /// ```
     const _HUE_DIRECTION: () = {};
-
-    /// Validates that any new variants in `ColorSpaceTag` has led to a change in the `Contiguous` impl.
-    /// Note that to test this robustly, we'd need 256 tests, which is impractical.
-    /// We make the assumption that all new variants will maintain contiguousness.
-    ///
-    /// ```compile_fail,E0080
-    /// use bytemuck::Contiguous;
-    /// use color::ColorSpaceTag;
-    /// const {
-    ///     let value = ColorSpaceTag::MAX_VALUE + 1;
-    ///     let it: ColorSpaceTag = unsafe { core::ptr::read((&raw const value).cast()) };
-    ///     // Evaluate the enum value to ensure it actually has an invalid tag
-    ///     if it as u8 != value {
-    ///         unreachable!();
-    ///     }
-    /// }
-    /// ```
-    const _COLOR_SPACE_TAG: () = {};
 }
@@ -36,7 +36,8 @@
 //! Simplifications include:
 //!   * Always using `f32` to represent component values.
 //!   * Only handling 3-component color spaces (plus optional alpha).
-//!   * Choosing a fixed, curated set of color spaces for dynamic color types.
+//!   * Choosing a fixed, curated set of color spaces for dynamic color types, extensible only with
+//!     RGB color spaces registered at runtime.
 //!   * Choosing linear sRGB as the central color space.
 //!   * Keeping white point implicit in the general conversion operations.
 //!
@@ -51,7 +52,7 @@
 //!
 //! The [`Rgba8`] and [`PremulRgba8`] types are a partial exception to this last item, as
 //! those representation are ubiquitous and requires special logic for serializing to
-//! maximize compatibility.
+//! maximize compatibility. Likewise, [`YcbcrFormat`] quantizes the Y'CbCr components of video.
 //!
 //! Some of these capabilities may be added as other crates within the `color` repository,
 //! and we will also facilitate interoperability with other color crates in the Rust
@@ -90,14 +91,20 @@ pub mod cache_key;
 mod chromaticity;
 mod color;
 mod colorspace;
+mod custom;
 mod dynamic;
 mod flags;
+mod gamut;
 mod gradient;
 pub mod palette;
+mod rgb;
 mod rgba8;
 mod serialize;
 mod tag;
+mod temperature;
+mod transfer;
 mod x11_colors;
+mod ycbcr;
 
 // Note: this may become feature-gated; we'll decide this soon
 // (This line is isolated so that the comment binds to it with import ordering)
@@ -109,18 +116,26 @@ mod impl_bytemuck;
 #[cfg(all(not(feature = "std"), not(test)))]
 mod floatfuncs;
 
-pub use chromaticity::Chromaticity;
+pub use chromaticity::{ChromaticAdaptation, Chromaticity};
 pub use color::{AlphaColor, HueDirection, OpaqueColor, PremulColor};
 pub use colorspace::{
-    A98Rgb, Aces2065_1, AcesCg, ColorSpace, ColorSpaceLayout, DisplayP3, Hsl, Hwb, Lab, Lch,
-    LinearSrgb, Oklab, Oklch, ProphotoRgb, Rec2020, Srgb, XyzD50, XyzD65,
+    A98Rgb, Aces2065_1, AcesCc, AcesCct, AcesCg, ColorSpace, ColorSpaceLayout, DisplayP3, Hpluv,
+    Hsl, Hsluv, Hsv, Hwb, IctcpHlg, IctcpPq, JzCzhz, Jzazbz, Lab, Lch, LchUv, LinearA98Rgb,
+    LinearDisplayP3, LinearProphotoRgb, LinearRec2020, LinearSrgb, Luv, Okhsl, Okhsv, Oklab, Oklch,
+    ProphotoRgb, Rec2020, Rec2100Hlg, Rec2100Pq, Rec709, Srgb, XyzD50, XyzD65,
 };
+pub use custom::{CustomColorSpaceId, DynamicColorSpace, RegisterError};
 pub use dynamic::{DynamicColor, Interpolator, UnpremultipliedInterpolator};
 pub use flags::{Flags, Missing};
+pub use gamut::{GamutCusp, GamutMapMethod, RgbGamut};
 pub use gradient::{gradient, gradient_unpremultiplied, GradientIter, UnpremultipliedGradientIter};
 pub use parse::{parse_color, parse_color_prefix, ParseError};
+pub use rgb::{Rgb, RgbColorSpace, RgbSpace};
 pub use rgba8::{PremulRgba8, Rgba8};
 pub use tag::ColorSpaceTag;
+pub use temperature::{ColorTemperature, ColorTemperatureMethod};
+pub use transfer::{ParametricCurve, TransferFunction};
+pub use ycbcr::{YcbcrFormat, YcbcrMatrix, YcbcrRange};
 
 const fn u8_to_f32(x: u8) -> f32 {
     x as f32 * (1.0 / 255.0)
@@ -173,6 +188,34 @@ const fn matdiagmatmul(ma: &[[f32; 3]; 3], mb: [f32; 3]) -> [[f32; 3]; 3] {
     ]
 }
 
+/// Inverse `m^-1` of an invertible 3x3-matrix `m`.
+///
+/// The inverse is calculated from the adjugate matrix. The result is meaningless if `m` is
+/// singular.
+const fn matinv(m: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let c00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let c01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let c02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+    let det_recip = 1. / (m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02);
+    [
+        [
+            c00 * det_recip,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * det_recip,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * det_recip,
+        ],
+        [
+            c01 * det_recip,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * det_recip,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * det_recip,
+        ],
+        [
+            c02 * det_recip,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * det_recip,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * det_recip,
+        ],
+    ]
+}
+
 impl AlphaColor<Srgb> {
     /// Create a color from 8-bit rgba values.
     ///
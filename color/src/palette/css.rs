@@ -9,7 +9,7 @@
 //!
 //! [X11]: https://en.wikipedia.org/wiki/X11_color_names
 
-use crate::{AlphaColor, Srgb};
+use crate::{x11_colors, AlphaColor, ColorSpace, Oklab, OpaqueColor, Srgb};
 
 /// <div style="margin:2px 0"><span style="background-color:rgb(240, 248, 255);padding:0 0.7em;margin-right:0.5em;border:1px solid"></span>Alice blue (240, 248, 255, 255)</div>
 pub const ALICE_BLUE: AlphaColor<Srgb> = AlphaColor::from_rgb8(240, 248, 255);
@@ -295,3 +295,92 @@ pub const WHITE_SMOKE: AlphaColor<Srgb> = AlphaColor::from_rgb8(245, 245, 245);
 pub const YELLOW: AlphaColor<Srgb> = AlphaColor::from_rgb8(255, 255, 0);
 /// <div style="margin:2px 0"><span style="background-color:rgb(154, 205, 50);padding:0 0.7em;margin-right:0.5em;border:1px solid"></span>Yellow green (154, 205, 50, 255)</div>
 pub const YELLOW_GREEN: AlphaColor<Srgb> = AlphaColor::from_rgb8(154, 205, 50);
+
+/// Iterate over all CSS named colors, with their names.
+///
+/// The names are in lowercase, as in CSS, for example `"cornflowerblue"`. This includes
+/// `transparent`. The iteration order is unspecified.
+///
+/// ```rust
+/// use color::palette::css;
+///
+/// let (name, color) = css::named_colors().find(|(name, _)| *name == "cornflowerblue").unwrap();
+/// assert_eq!(color, css::CORNFLOWER_BLUE);
+/// ```
+pub fn named_colors() -> impl ExactSizeIterator<Item = (&'static str, AlphaColor<Srgb>)> + Clone {
+    x11_colors::NAMES
+        .iter()
+        .zip(x11_colors::COLORS)
+        .map(|(name, [r, g, b, a])| (*name, AlphaColor::from_rgba8(r, g, b, a)))
+}
+
+/// Find the CSS named color closest to the given color.
+///
+/// This returns the name of the color, with the deltaEOK distance to it: the Euclidean distance in
+/// [`Oklab`]. The alpha of `color` is ignored, and `transparent` is never returned. Of named colors
+/// that are equally close, such as `aqua` and `cyan`, the alphabetically first name is returned.
+///
+/// ```rust
+/// use color::{palette::css, AlphaColor, Srgb};
+///
+/// let color = AlphaColor::<Srgb>::from_rgb8(100, 150, 240);
+/// let (name, distance) = css::nearest_named_color(color);
+/// assert_eq!(name, "cornflowerblue");
+/// assert!(distance < 0.02);
+/// ```
+pub fn nearest_named_color<CS: ColorSpace>(color: AlphaColor<CS>) -> (&'static str, f32) {
+    nearest_named_color_oklab(color.split().0.convert::<Oklab>().components)
+}
+
+/// The nearest named color to the given color in [`Oklab`].
+///
+/// The named colors are compared using a precomputed table of their Oklab components.
+pub(crate) fn nearest_named_color_oklab(oklab: [f32; 3]) -> (&'static str, f32) {
+    let oklab = OpaqueColor::<Oklab>::new(oklab);
+    let mut nearest = ("", f32::INFINITY);
+    for (name, named) in x11_colors::NAMES.iter().zip(x11_colors::OKLAB) {
+        if *name == "transparent" {
+            continue;
+        }
+        let distance = oklab.difference(OpaqueColor::new(named));
+        if distance < nearest.1 || (distance == nearest.1 && *name < nearest.0) {
+            nearest = (name, distance);
+        }
+    }
+    nearest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{named_colors, nearest_named_color};
+    use crate::{parse_color, AlphaColor, Srgb};
+
+    #[test]
+    fn nearest_is_self() {
+        assert_eq!(named_colors().len(), 142);
+        for (name, color) in named_colors() {
+            if name == "transparent" {
+                continue;
+            }
+            assert_eq!(parse_color(name).unwrap().to_alpha_color::<Srgb>(), color);
+            let (nearest, distance) = nearest_named_color(color);
+            assert!(distance < 1e-5, "{name}: {nearest} {distance}");
+            // Duplicates resolve to the alphabetically first name.
+            match name {
+                "cyan" => assert_eq!(nearest, "aqua"),
+                "magenta" => assert_eq!(nearest, "fuchsia"),
+                _ => assert_eq!(nearest, name),
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_ignores_alpha() {
+        let color = AlphaColor::<Srgb>::from_rgba8(250, 0, 5, 0);
+        assert_eq!(nearest_named_color(color).0, "red");
+        assert_eq!(
+            parse_color("transparent").unwrap().nearest_named_color().0,
+            "black"
+        );
+    }
+}
@@ -10,8 +10,8 @@ use core::str;
 use core::str::FromStr;
 
 use crate::{
-    AlphaColor, ColorSpace, ColorSpaceTag, DynamicColor, Flags, Missing, OpaqueColor, PremulColor,
-    Srgb,
+    AlphaColor, ColorSpace, ColorSpaceLayout, ColorSpaceTag, DynamicColor, DynamicColorSpace,
+    Flags, HueDirection, Missing, OpaqueColor, PremulColor, Srgb,
 };
 
 // TODO: maybe include string offset
@@ -45,6 +45,10 @@ pub enum ParseError {
     ExpectedComma,
     /// Expected end of string
     ExpectedEndOfString,
+    /// Invalid `color-mix()` percentage
+    InvalidMixPercentage,
+    /// Hue interpolation method for a color space without hue
+    UnexpectedHueInterpolationMethod,
     /// Wrong number of hex digits
     WrongNumberOfHexDigits,
 }
@@ -66,6 +70,10 @@ impl fmt::Display for ParseError {
             Self::ExpectedColorSpaceIdentifier => "expected color space identifier",
             Self::ExpectedComma => "expected comma",
             Self::ExpectedEndOfString => "expected end of string",
+            Self::InvalidMixPercentage => "invalid color-mix percentage",
+            Self::UnexpectedHueInterpolationMethod => {
+                "hue interpolation method for a color space without hue"
+            }
             Self::WrongNumberOfHexDigits => "wrong number of hex digits",
         };
         f.write_str(msg)
@@ -232,6 +240,21 @@ impl<'a> Parser<'a> {
         None
     }
 
+    // Complies with dashed-ident production, with the same exceptions as `ident`.
+    fn dashed_ident(&mut self) -> Option<&'a str> {
+        // This does *not* strip initial whitespace.
+        let start = self.ix;
+        if !self.s[start..].starts_with("--") {
+            return None;
+        }
+        self.ix += 2;
+        if self.ident().is_none() {
+            self.ix = start;
+            return None;
+        }
+        Some(&self.s[start..self.ix])
+    }
+
     fn ch(&mut self, ch: u8) -> bool {
         if self.consume_comments().is_err() {
             return false;
@@ -462,25 +485,31 @@ impl<'a> Parser<'a> {
             return Err(ParseError::ExpectedArguments);
         }
         self.ws();
-        let Some(id) = self.ident() else {
+        let Some(id) = self.ident().or_else(|| self.dashed_ident()) else {
             return Err(ParseError::ExpectedColorSpaceIdentifier);
         };
         let mut buf = [0; LOWERCASE_BUF_SIZE];
         let id_lc = make_lowercase(id, &mut buf);
-        let cs = match id_lc {
-            "srgb" => ColorSpaceTag::Srgb,
-            "srgb-linear" => ColorSpaceTag::LinearSrgb,
-            "display-p3" => ColorSpaceTag::DisplayP3,
-            "a98-rgb" => ColorSpaceTag::A98Rgb,
-            "prophoto-rgb" => ColorSpaceTag::ProphotoRgb,
-            "rec2020" => ColorSpaceTag::Rec2020,
-            "xyz-d50" => ColorSpaceTag::XyzD50,
-            "xyz" | "xyz-d65" => ColorSpaceTag::XyzD65,
-            _ => return Err(ParseError::UnknownColorSpace),
+        let cs = predefined_color_space(id_lc)
+            // Custom color space names are case-sensitive.
+            .or_else(|| DynamicColorSpace::lookup(id))
+            .ok_or(ParseError::UnknownColorSpace)?;
+        // These color spaces have components with a natural range of 0 to 100, as in HSL and Lab.
+        let pct_scale = if matches!(
+            cs,
+            ColorSpaceTag::Hsv
+                | ColorSpaceTag::Luv
+                | ColorSpaceTag::LchUv
+                | ColorSpaceTag::Hsluv
+                | ColorSpaceTag::Hpluv
+        ) {
+            1.
+        } else {
+            0.01
         };
-        let r = self.scaled_component(1., 0.01)?;
-        let g = self.scaled_component(1., 0.01)?;
-        let b = self.scaled_component(1., 0.01)?;
+        let r = self.scaled_component(1., pct_scale)?;
+        let g = self.scaled_component(1., pct_scale)?;
+        let b = self.scaled_component(1., pct_scale)?;
         let alpha = self.alpha(Mode::Modern)?;
         self.ws();
         if !self.ch(b')') {
@@ -488,6 +517,168 @@ impl<'a> Parser<'a> {
         }
         Ok(color_from_components([r, g, b, alpha], cs))
     }
+
+    /// Parse the arguments of `color-mix()`, mixing the colors with [`DynamicColor::interpolate`].
+    ///
+    /// See [CSS Color Module Level 5 § 2][css-mix] for the syntax and the normalization of the
+    /// percentages.
+    ///
+    /// [css-mix]: https://drafts.csswg.org/css-color-5/#color-mix
+    fn color_mix(&mut self) -> Result<DynamicColor, ParseError> {
+        if !self.raw_ch(b'(') {
+            return Err(ParseError::ExpectedArguments);
+        }
+        self.ws();
+        let start = self.ix;
+        let (cs, direction) = if self.ident().is_some_and(|id| id.eq_ignore_ascii_case("in")) {
+            let method = self.color_interpolation_method()?;
+            self.optional_comma(true)?;
+            method
+        } else {
+            // The interpolation method defaults to Oklab.
+            self.ix = start;
+            (ColorSpaceTag::Oklab, HueDirection::default())
+        };
+        let (c1, p1) = self.color_mix_argument()?;
+        self.optional_comma(true)?;
+        let (c2, p2) = self.color_mix_argument()?;
+        self.ws();
+        if !self.ch(b')') {
+            return Err(ParseError::ExpectedClosingParenthesis);
+        }
+
+        let (p1, p2) = match (p1, p2) {
+            (None, None) => (50., 50.),
+            (Some(p1), None) => (p1, 100. - p1),
+            (None, Some(p2)) => (100. - p2, p2),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+        let sum = p1 + p2;
+        if sum == 0. {
+            return Err(ParseError::InvalidMixPercentage);
+        }
+        let mut color = c1.interpolate(c2, cs, direction).eval(p2 / sum);
+        // Percentages summing to less than 100% make the result partially transparent.
+        if sum < 100. {
+            color.components[3] *= sum * 0.01;
+        }
+        // Mixes in HSL and HWB are serialized as `rgb()`, like `hsl()` and `hwb()` colors.
+        if matches!(cs, ColorSpaceTag::Hsl | ColorSpaceTag::Hwb) {
+            color.flags.set_named_color_space();
+        }
+        Ok(color)
+    }
+
+    /// Parse a `<color-interpolation-method>` following the `in` keyword.
+    fn color_interpolation_method(&mut self) -> Result<(ColorSpaceTag, HueDirection), ParseError> {
+        if !self.ws() {
+            return Err(ParseError::ExpectedColorSpaceIdentifier);
+        }
+        let Some(id) = self.ident().or_else(|| self.dashed_ident()) else {
+            return Err(ParseError::ExpectedColorSpaceIdentifier);
+        };
+        let cs = ColorSpaceTag::from_str(id)?;
+
+        let start = self.ix;
+        self.ws();
+        let Some(id) = self.ident() else {
+            self.ix = start;
+            return Ok((cs, HueDirection::default()));
+        };
+        let mut buf = [0; LOWERCASE_BUF_SIZE];
+        let direction = match make_lowercase(id, &mut buf) {
+            "shorter" => Some(HueDirection::Shorter),
+            "longer" => Some(HueDirection::Longer),
+            "increasing" => Some(HueDirection::Increasing),
+            "decreasing" => Some(HueDirection::Decreasing),
+            _ => None,
+        };
+        let hue = self.ws()
+            && self
+                .ident()
+                .is_some_and(|id| id.eq_ignore_ascii_case("hue"));
+        let direction = match (direction, hue) {
+            (Some(direction), true) => direction,
+            // Anything else following the color space is reported by the caller, which expects a
+            // comma.
+            (None, false) => {
+                self.ix = start;
+                return Ok((cs, HueDirection::default()));
+            }
+            _ => return Err(ParseError::UnknownColorSyntax),
+        };
+        if cs.layout() == ColorSpaceLayout::Rectangular {
+            return Err(ParseError::UnexpectedHueInterpolationMethod);
+        }
+        Ok((cs, direction))
+    }
+
+    /// Parse a color with an optional percentage before or after it, as in `red 30%`.
+    fn color_mix_argument(&mut self) -> Result<(DynamicColor, Option<f32>), ParseError> {
+        self.ws();
+        let mut percentage = self.mix_percentage()?;
+        self.ws();
+        let (len, color) = parse_color_prefix(&self.s[self.ix..])?;
+        self.ix += len;
+        if percentage.is_none() {
+            self.ws();
+            percentage = self.mix_percentage()?;
+        }
+        Ok((color, percentage))
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "deliberate choice of f32 for colors"
+    )]
+    fn mix_percentage(&mut self) -> Result<Option<f32>, ParseError> {
+        let Some(n) = self.number() else {
+            return Ok(None);
+        };
+        if !self.raw_ch(b'%') || !(0. ..=100.).contains(&n) {
+            return Err(ParseError::InvalidMixPercentage);
+        }
+        Ok(Some(n as f32))
+    }
+}
+
+/// The color space of a lowercased predefined color space name of `color()`.
+fn predefined_color_space(id_lc: &str) -> Option<ColorSpaceTag> {
+    let cs = match id_lc {
+        "srgb" => ColorSpaceTag::Srgb,
+        "srgb-linear" => ColorSpaceTag::LinearSrgb,
+        "display-p3" => ColorSpaceTag::DisplayP3,
+        "display-p3-linear" => ColorSpaceTag::LinearDisplayP3,
+        "a98-rgb" => ColorSpaceTag::A98Rgb,
+        "a98-rgb-linear" => ColorSpaceTag::LinearA98Rgb,
+        "prophoto-rgb" => ColorSpaceTag::ProphotoRgb,
+        "prophoto-rgb-linear" => ColorSpaceTag::LinearProphotoRgb,
+        "rec2020" => ColorSpaceTag::Rec2020,
+        "rec2020-linear" => ColorSpaceTag::LinearRec2020,
+        "rec2100-pq" => ColorSpaceTag::Rec2100Pq,
+        "rec2100-hlg" => ColorSpaceTag::Rec2100Hlg,
+        "xyz-d50" => ColorSpaceTag::XyzD50,
+        "xyz" | "xyz-d65" => ColorSpaceTag::XyzD65,
+        // Color spaces not in CSS Color 4 use the syntax of custom color spaces.
+        "--aces2065-1" => ColorSpaceTag::Aces2065_1,
+        "--acescg" => ColorSpaceTag::AcesCg,
+        "--acescc" => ColorSpaceTag::AcesCc,
+        "--acescct" => ColorSpaceTag::AcesCct,
+        "--rec709" => ColorSpaceTag::Rec709,
+        "--okhsl" => ColorSpaceTag::Okhsl,
+        "--okhsv" => ColorSpaceTag::Okhsv,
+        "--hsv" => ColorSpaceTag::Hsv,
+        "--luv" => ColorSpaceTag::Luv,
+        "--lchuv" => ColorSpaceTag::LchUv,
+        "--hsluv" => ColorSpaceTag::Hsluv,
+        "--hpluv" => ColorSpaceTag::Hpluv,
+        "--jzazbz" => ColorSpaceTag::Jzazbz,
+        "--jzczhz" => ColorSpaceTag::JzCzhz,
+        "--ictcp-pq" => ColorSpaceTag::IctcpPq,
+        "--ictcp-hlg" => ColorSpaceTag::IctcpHlg,
+        _ => return None,
+    };
+    Some(cs)
 }
 
 /// Parse a color string prefix in CSS syntax into a color.
@@ -536,6 +727,7 @@ pub fn parse_color_prefix(s: &str) -> Result<(usize, DynamicColor), ParseError>
             "hsl" | "hsla" => parser.hsl().map(set_from_named_color_space),
             "hwb" => parser.hwb().map(set_from_named_color_space),
             "color" => parser.color(),
+            "color-mix" => parser.color_mix(),
             _ => {
                 if let Some(ix) = crate::x11_colors::lookup_palette_index(id_lc) {
                     let [r, g, b, a] = crate::x11_colors::COLORS[ix];
@@ -561,6 +753,9 @@ pub fn parse_color_prefix(s: &str) -> Result<(usize, DynamicColor), ParseError>
 /// [`ExpectedEndOfString`](ParseError::ExpectedEndOfString) parse error. Leading and trailing
 /// whitespace are ignored. See also [`parse_color_prefix`].
 ///
+/// Besides the color functions of CSS Color 4, this evaluates the `color-mix()` function of
+/// [CSS Color 5](https://drafts.csswg.org/css-color-5/#color-mix) to the mixed color.
+///
 /// # Errors
 ///
 /// Tries to return a suitable error for any invalid string, but may be
@@ -667,18 +862,16 @@ impl FromStr for ColorSpaceTag {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut buf = [0; LOWERCASE_BUF_SIZE];
         match make_lowercase(s, &mut buf) {
-            "srgb" => Ok(Self::Srgb),
-            "srgb-linear" => Ok(Self::LinearSrgb),
             "lab" => Ok(Self::Lab),
             "lch" => Ok(Self::Lch),
             "oklab" => Ok(Self::Oklab),
             "oklch" => Ok(Self::Oklch),
-            "display-p3" => Ok(Self::DisplayP3),
-            "a98-rgb" => Ok(Self::A98Rgb),
-            "prophoto-rgb" => Ok(Self::ProphotoRgb),
-            "xyz-d50" => Ok(Self::XyzD50),
-            "xyz" | "xyz-d65" => Ok(Self::XyzD65),
-            _ => Err(ParseError::UnknownColorSpace),
+            "hsl" => Ok(Self::Hsl),
+            "hwb" => Ok(Self::Hwb),
+            lc => predefined_color_space(lc)
+                // Custom color space names are case-sensitive.
+                .or_else(|| DynamicColorSpace::lookup(s))
+                .ok_or(ParseError::UnknownColorSpace),
         }
     }
 }
@@ -827,6 +1020,130 @@ mod tests {
         );
     }
 
+    #[test]
+    fn color_mix() {
+        for (mix, expected) in [
+            ("color-mix(in srgb, red, blue)", "color(srgb 0.5 0 0.5)"),
+            ("color-mix(in srgb, red 30%, blue)", "color(srgb 0.3 0 0.7)"),
+            ("color-mix(in srgb, 30% red, blue)", "color(srgb 0.3 0 0.7)"),
+            ("color-mix(in srgb, red, blue 70%)", "color(srgb 0.3 0 0.7)"),
+            (
+                "color-mix(in srgb, red 60%, blue 60%)",
+                "color(srgb 0.5 0 0.5)",
+            ),
+            // Percentages summing to less than 100% multiply the alpha.
+            (
+                "color-mix(in srgb, red 20%, blue 20%)",
+                "color(srgb 0.5 0 0.5 / 0.4)",
+            ),
+            // Mixing is done premultiplied.
+            (
+                "color-mix(in srgb, rgb(255 0 0 / 0.5), blue)",
+                "color(srgb 0.333333 0 0.666667 / 0.75)",
+            ),
+            ("color-mix(red, blue)", "color-mix(in oklab, red, blue)"),
+            ("color-mix(in hsl, red, lime)", "hsl(60 100% 50%)"),
+            (
+                "color-mix(in hsl longer hue, lime, red)",
+                "hsl(240 100% 50%)",
+            ),
+            (
+                "color-mix(in oklch, oklch(0.5 0.1 10), oklch(0.7 0.1 350))",
+                "oklch(0.6 0.1 0)",
+            ),
+            (
+                "color-mix(in oklch increasing hue, oklch(0.5 0.1 10), oklch(0.7 0.1 350))",
+                "oklch(0.6 0.1 180)",
+            ),
+            (
+                "COLOR-MIX(IN OKLCH DECREASING HUE, oklch(0.5 0.1 10), oklch(0.7 0.1 350))",
+                "oklch(0.6 0.1 0)",
+            ),
+            (
+                "color-mix(in srgb, color-mix(in srgb, red, blue), lime)",
+                "color(srgb 0.25 0.5 0.25)",
+            ),
+            ("color-mix(in --hsv, blue, red)", "color(--hsv 300 100 100)"),
+            (
+                "color-mix( in srgb , /* a */ red /* b */ 30% , blue )",
+                "color(srgb 0.3 0 0.7)",
+            ),
+        ] {
+            assert_close_color(parse_color(mix).unwrap(), parse_color(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn color_mix_missing_components() {
+        // A component missing in one color takes the value of the other color.
+        let mixed =
+            parse_color("color-mix(in oklch, oklch(0.5 none 30), oklch(0.7 0.1 90))").unwrap();
+        assert_close_color(mixed, parse_color("oklch(0.6 0.1 60)").unwrap());
+
+        // A component missing in both colors stays missing.
+        let mixed = parse_color("color-mix(in srgb, rgb(none 0 0), color(srgb none 1 0))").unwrap();
+        assert!(mixed.flags.missing().contains(0));
+        assert!(!mixed.flags.missing().contains(1));
+        assert!((mixed.components[1] - 0.5).abs() < 1e-4);
+
+        // Powerless hues are missing after conversion to the interpolation color space.
+        let mixed = parse_color("color-mix(in oklch, oklch(0.5 0.1 40), white)").unwrap();
+        assert!(!mixed.flags.missing().contains(1));
+        assert!((mixed.components[2] - 40.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn color_mix_errors() {
+        for (mix, err) in [
+            (
+                "color-mix(in srgb, red 0%, blue 0%)",
+                ParseError::InvalidMixPercentage,
+            ),
+            (
+                "color-mix(in srgb, red 120%, blue)",
+                ParseError::InvalidMixPercentage,
+            ),
+            (
+                "color-mix(in srgb, red -10%, blue)",
+                ParseError::InvalidMixPercentage,
+            ),
+            (
+                "color-mix(in srgb, red 30, blue)",
+                ParseError::InvalidMixPercentage,
+            ),
+            (
+                "color-mix(in srgb longer hue, red, blue)",
+                ParseError::UnexpectedHueInterpolationMethod,
+            ),
+            (
+                "color-mix(in oklch longer, red, blue)",
+                ParseError::UnknownColorSyntax,
+            ),
+            (
+                "color-mix(in oklch foo hue, red, blue)",
+                ParseError::UnknownColorSyntax,
+            ),
+            ("color-mix(in srgb red, blue)", ParseError::ExpectedComma),
+            ("color-mix(in srgb, red blue)", ParseError::ExpectedComma),
+            (
+                "color-mix(in, red, blue)",
+                ParseError::ExpectedColorSpaceIdentifier,
+            ),
+            (
+                "color-mix(in foo, red, blue)",
+                ParseError::UnknownColorSpace,
+            ),
+            (
+                "color-mix(in srgb, red, blue",
+                ParseError::ExpectedClosingParenthesis,
+            ),
+            ("color-mix(in srgb, red)", ParseError::ExpectedComma),
+            ("color-mix", ParseError::ExpectedArguments),
+        ] {
+            assert_err(mix, err);
+        }
+    }
+
     #[test]
     fn case_insensitive() {
         for (c1, c2) in [
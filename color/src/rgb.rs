@@ -0,0 +1,362 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::marker::PhantomData;
+
+use crate::{
+    colorspace::XYZ_D65_TO_LINEAR_SRGB, matdiagmatmul, matinv, matmatmul, matvecmul,
+    ChromaticAdaptation, Chromaticity, ColorSpace, ColorSpaceLayout, ColorSpaceTag,
+    TransferFunction,
+};
+
+/// The definition of an RGB color space by its primaries, white point and transfer function.
+///
+/// The matrices converting between the color space and linear sRGB are derived from the
+/// chromaticities of the primaries and white point. All methods are `const`, so a color space
+/// definition can be evaluated entirely at compile-time. See [`RgbColorSpace`] for how to turn a
+/// definition into a [`ColorSpace`].
+///
+/// # Example
+///
+/// ```rust
+/// use color::{Chromaticity, RgbSpace, TransferFunction};
+///
+/// // SMPTE-C, as used by NTSC television.
+/// const SMPTE_C: RgbSpace = RgbSpace::new(
+///     Chromaticity { x: 0.630, y: 0.340 },
+///     Chromaticity { x: 0.310, y: 0.595 },
+///     Chromaticity { x: 0.155, y: 0.070 },
+///     Chromaticity::D65,
+///     TransferFunction::Gamma(2.2),
+/// );
+///
+/// // The white point maps onto sRGB white.
+/// let [r, g, b] = SMPTE_C.to_linear_srgb_matrix()[0];
+/// assert!((r + g + b - 1.).abs() < 1e-5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RgbSpace {
+    /// The chromaticity of the red primary.
+    pub red: Chromaticity,
+
+    /// The chromaticity of the green primary.
+    pub green: Chromaticity,
+
+    /// The chromaticity of the blue primary.
+    pub blue: Chromaticity,
+
+    /// The chromaticity of the white point, which is the color with all components equal to 1.
+    pub white_point: Chromaticity,
+
+    /// The transfer function encoding the linear-light components.
+    pub transfer_function: TransferFunction,
+}
+
+impl RgbSpace {
+    /// Define an RGB color space from its primaries, white point and transfer function.
+    pub const fn new(
+        red: Chromaticity,
+        green: Chromaticity,
+        blue: Chromaticity,
+        white_point: Chromaticity,
+        transfer_function: TransferFunction,
+    ) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            white_point,
+            transfer_function,
+        }
+    }
+
+    /// Use the given white point, keeping the primaries and transfer function.
+    #[must_use]
+    pub const fn with_white_point(self, white_point: Chromaticity) -> Self {
+        Self {
+            white_point,
+            ..self
+        }
+    }
+
+    /// Use the given transfer function, keeping the primaries and white point.
+    #[must_use]
+    pub const fn with_transfer_function(self, transfer_function: TransferFunction) -> Self {
+        Self {
+            transfer_function,
+            ..self
+        }
+    }
+
+    /// The 3x3 matrix converting linear-light components of this color space to XYZ.
+    ///
+    /// The XYZ values are relative to the color space's own white point, which maps to `Y = 1`.
+    pub const fn to_xyz_matrix(&self) -> [[f32; 3]; 3] {
+        // The primaries are given as `xyz` chromaticities with `x + y + z = 1`, rather than as
+        // XYZ with `Y = 1`. This keeps the matrix well-conditioned for primaries with very small
+        // `y`, such as ProPhoto RGB's blue primary.
+        let Chromaticity { x: xr, y: yr } = self.red;
+        let Chromaticity { x: xg, y: yg } = self.green;
+        let Chromaticity { x: xb, y: yb } = self.blue;
+        let primaries = [
+            [xr, xg, xb],
+            [yr, yg, yb],
+            [1. - xr - yr, 1. - xg - yg, 1. - xb - yb],
+        ];
+        let scale = matvecmul(&matinv(&primaries), self.white_point.to_xyz());
+        matdiagmatmul(&primaries, scale)
+    }
+
+    /// The 3x3 matrix converting linear-light components of this color space to linear sRGB,
+    /// without chromatic adaptation.
+    pub const fn to_linear_srgb_absolute_matrix(&self) -> [[f32; 3]; 3] {
+        xyz_matrix_to_linear_srgb_absolute(&self.to_xyz_matrix())
+    }
+
+    /// The 3x3 matrix converting linear sRGB to linear-light components of this color space,
+    /// without chromatic adaptation.
+    pub const fn from_linear_srgb_absolute_matrix(&self) -> [[f32; 3]; 3] {
+        matinv(&self.to_linear_srgb_absolute_matrix())
+    }
+
+    /// The 3x3 matrix converting linear-light components of this color space to linear sRGB.
+    ///
+    /// This includes a linear Bradford chromatic adaptation from the color space's white point to
+    /// D65 if the two differ.
+    pub const fn to_linear_srgb_matrix(&self) -> [[f32; 3]; 3] {
+        xyz_matrix_to_linear_srgb(&self.to_xyz_matrix(), self.white_point)
+    }
+
+    /// The 3x3 matrix converting linear sRGB to linear-light components of this color space.
+    ///
+    /// This includes a linear Bradford chromatic adaptation from D65 to the color space's white
+    /// point if the two differ.
+    pub const fn from_linear_srgb_matrix(&self) -> [[f32; 3]; 3] {
+        matinv(&self.to_linear_srgb_matrix())
+    }
+}
+
+/// The 3x3 matrix converting linear-light components to linear sRGB without chromatic adaptation,
+/// given the matrix `to_xyz` converting those components to XYZ.
+pub(crate) const fn xyz_matrix_to_linear_srgb_absolute(to_xyz: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    matmatmul(&XYZ_D65_TO_LINEAR_SRGB, to_xyz)
+}
+
+/// The 3x3 matrix converting linear-light components to linear sRGB, given the matrix `to_xyz`
+/// converting those components to XYZ relative to `white_point`.
+///
+/// This includes a linear Bradford chromatic adaptation from `white_point` to D65 if the two
+/// differ.
+pub(crate) const fn xyz_matrix_to_linear_srgb(
+    to_xyz: &[[f32; 3]; 3],
+    white_point: Chromaticity,
+) -> [[f32; 3]; 3] {
+    let absolute = xyz_matrix_to_linear_srgb_absolute(to_xyz);
+    if white_point.x == Chromaticity::D65.x && white_point.y == Chromaticity::D65.y {
+        absolute
+    } else {
+        matmatmul(
+            &white_point.linear_srgb_chromatic_adaptation_matrix(
+                Chromaticity::D65,
+                ChromaticAdaptation::Bradford,
+            ),
+            &absolute,
+        )
+    }
+}
+
+/// An RGB color space defined by an [`RgbSpace`].
+///
+/// Implementing this trait for a type `S` makes [`Rgb<S>`] a [`ColorSpace`], with the conversion
+/// matrices derived from the definition at compile-time, and [`ColorSpace::WHITE_POINT`] set to
+/// the definition's white point. This makes it possible to declare a new RGB color space in a few
+/// lines.
+///
+/// # Example
+///
+/// ```rust
+/// use color::{Chromaticity, ColorSpace, Rgb, RgbColorSpace, RgbSpace, Srgb, TransferFunction};
+///
+/// /// The definition of the DCI-P3 color space, as used in digital cinema projection.
+/// #[derive(Clone, Copy, Debug)]
+/// pub struct DciP3Space;
+///
+/// impl RgbColorSpace for DciP3Space {
+///     const SPACE: RgbSpace = RgbSpace::new(
+///         Chromaticity { x: 0.680, y: 0.320 },
+///         Chromaticity { x: 0.265, y: 0.690 },
+///         Chromaticity { x: 0.150, y: 0.060 },
+///         Chromaticity::DCI,
+///         TransferFunction::Gamma(2.6),
+///     );
+/// }
+///
+/// /// The DCI-P3 color space.
+/// pub type DciP3 = Rgb<DciP3Space>;
+///
+/// // White is adapted to D65 white in sRGB, ...
+/// let [r, g, b] = DciP3::convert::<Srgb>([1., 1., 1.]);
+/// assert!((r - 1.).abs() < 1e-4 && (g - 1.).abs() < 1e-4 && (b - 1.).abs() < 1e-4);
+///
+/// // ... unless the absolute color is requested: DCI white is slightly green.
+/// let [r, g, b] = DciP3::convert_absolute::<Srgb>([1., 1., 1.]);
+/// assert!(g > r && g > b);
+/// ```
+///
+/// To specialize methods such as [`ColorSpace::convert`] or [`ColorSpace::scale_chroma`],
+/// implement [`ColorSpace`] directly instead, using the `const` conversion matrices of
+/// [`RgbSpace`].
+pub trait RgbColorSpace: Clone + Copy + 'static {
+    /// The definition of the color space.
+    const SPACE: RgbSpace;
+
+    /// The tag corresponding to this color space, if a matching tag exists.
+    const TAG: Option<ColorSpaceTag> = None;
+}
+
+/// The RGB color space defined by the [`RgbColorSpace`] `S`.
+///
+/// Its components are `[r, g, b]` (red, green, and blue channels respectively), with `[0, 0, 0]`
+/// pure black and `[1, 1, 1]` the white point of the definition. The natural bounds of the
+/// channels are `[0, 1]`.
+#[derive(Clone, Copy, Debug)]
+pub struct Rgb<S>(PhantomData<S>);
+
+impl<S: RgbColorSpace> ColorSpace for Rgb<S> {
+    const IS_LINEAR: bool = S::SPACE.transfer_function.is_linear();
+
+    const LAYOUT: ColorSpaceLayout = ColorSpaceLayout::Rectangular;
+
+    const TAG: Option<ColorSpaceTag> = S::TAG;
+
+    const WHITE_POINT: Chromaticity = S::SPACE.white_point;
+
+    const WHITE_COMPONENTS: [f32; 3] = [1., 1., 1.];
+
+    fn to_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let transfer_function = S::SPACE.transfer_function;
+        matvecmul(
+            &const { S::SPACE.to_linear_srgb_matrix() },
+            src.map(|x| transfer_function.to_linear(x)),
+        )
+    }
+
+    fn from_linear_srgb(src: [f32; 3]) -> [f32; 3] {
+        let transfer_function = S::SPACE.transfer_function;
+        matvecmul(&const { S::SPACE.from_linear_srgb_matrix() }, src)
+            .map(|x| transfer_function.from_linear(x))
+    }
+
+    fn to_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        let transfer_function = S::SPACE.transfer_function;
+        matvecmul(
+            &const { S::SPACE.to_linear_srgb_absolute_matrix() },
+            src.map(|x| transfer_function.to_linear(x)),
+        )
+    }
+
+    fn from_linear_srgb_absolute(src: [f32; 3]) -> [f32; 3] {
+        let transfer_function = S::SPACE.transfer_function;
+        matvecmul(&const { S::SPACE.from_linear_srgb_absolute_matrix() }, src)
+            .map(|x| transfer_function.from_linear(x))
+    }
+
+    fn clip([r, g, b]: [f32; 3]) -> [f32; 3] {
+        [r.clamp(0., 1.), g.clamp(0., 1.), b.clamp(0., 1.)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Rgb, RgbColorSpace, RgbSpace};
+    use crate::{
+        Chromaticity, ColorSpace, DisplayP3, LinearProphotoRgb, LinearSrgb, Srgb, TransferFunction,
+        XyzD50,
+    };
+
+    #[derive(Clone, Copy, Debug)]
+    struct CustomDisplayP3Space;
+
+    type CustomDisplayP3 = Rgb<CustomDisplayP3Space>;
+
+    impl RgbColorSpace for CustomDisplayP3Space {
+        const SPACE: RgbSpace = RgbSpace::new(
+            Chromaticity { x: 0.680, y: 0.320 },
+            Chromaticity { x: 0.265, y: 0.690 },
+            Chromaticity { x: 0.150, y: 0.060 },
+            Chromaticity::D65,
+            TransferFunction::Srgb,
+        );
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct CustomLinearProphotoRgbSpace;
+
+    type CustomLinearProphotoRgb = Rgb<CustomLinearProphotoRgbSpace>;
+
+    impl RgbColorSpace for CustomLinearProphotoRgbSpace {
+        const SPACE: RgbSpace = RgbSpace::new(
+            Chromaticity {
+                x: 0.734_699,
+                y: 0.265_301,
+            },
+            Chromaticity {
+                x: 0.159_597,
+                y: 0.840_403,
+            },
+            Chromaticity {
+                x: 0.036_598,
+                y: 0.000_105,
+            },
+            Chromaticity::D50,
+            TransferFunction::Linear,
+        );
+    }
+
+    fn assert_close(a: [f32; 3], b: [f32; 3]) {
+        assert!(
+            (0..3).all(|i| (a[i] - b[i]).abs() < 1e-4),
+            "{a:?} and {b:?} differ"
+        );
+    }
+
+    #[test]
+    fn matches_builtin_display_p3() {
+        for components in [
+            [1., 1., 1.],
+            [1., 0., 0.],
+            [0.2, 0.8, 0.4],
+            [-0.1, 0.5, 1.2],
+        ] {
+            assert_close(
+                CustomDisplayP3::convert::<Srgb>(components),
+                DisplayP3::convert::<Srgb>(components),
+            );
+            assert_close(
+                Srgb::convert::<CustomDisplayP3>(components),
+                Srgb::convert::<DisplayP3>(components),
+            );
+        }
+    }
+
+    #[test]
+    fn matches_builtin_prophoto_rgb_with_white_point() {
+        assert_eq!(CustomLinearProphotoRgb::WHITE_POINT, Chromaticity::D50);
+        const { assert!(CustomLinearProphotoRgb::IS_LINEAR) };
+
+        for components in [[1., 1., 1.], [0., 0., 1.], [0.2, 0.8, 0.4]] {
+            assert_close(
+                CustomLinearProphotoRgb::convert::<LinearSrgb>(components),
+                LinearProphotoRgb::convert::<LinearSrgb>(components),
+            );
+            assert_close(
+                CustomLinearProphotoRgb::convert_absolute::<XyzD50>(components),
+                LinearProphotoRgb::convert_absolute::<XyzD50>(components),
+            );
+            assert_close(
+                Srgb::convert_absolute::<CustomLinearProphotoRgb>(components),
+                Srgb::convert_absolute::<LinearProphotoRgb>(components),
+            );
+        }
+    }
+}
@@ -92,19 +92,42 @@ impl core::fmt::Display for DynamicColor {
             ColorSpaceTag::Srgb => write_color_function(self, "srgb", f),
             ColorSpaceTag::LinearSrgb => write_color_function(self, "srgb-linear", f),
             ColorSpaceTag::DisplayP3 => write_color_function(self, "display-p3", f),
+            ColorSpaceTag::LinearDisplayP3 => write_color_function(self, "display-p3-linear", f),
             ColorSpaceTag::A98Rgb => write_color_function(self, "a98-rgb", f),
+            ColorSpaceTag::LinearA98Rgb => write_color_function(self, "a98-rgb-linear", f),
             ColorSpaceTag::ProphotoRgb => write_color_function(self, "prophoto-rgb", f),
+            ColorSpaceTag::LinearProphotoRgb => {
+                write_color_function(self, "prophoto-rgb-linear", f)
+            }
             ColorSpaceTag::Rec2020 => write_color_function(self, "rec2020", f),
+            ColorSpaceTag::LinearRec2020 => write_color_function(self, "rec2020-linear", f),
+            ColorSpaceTag::Rec2100Pq => write_color_function(self, "rec2100-pq", f),
+            ColorSpaceTag::Rec2100Hlg => write_color_function(self, "rec2100-hlg", f),
             ColorSpaceTag::Aces2065_1 => write_color_function(self, "--aces2065-1", f),
             ColorSpaceTag::AcesCg => write_color_function(self, "--acescg", f),
+            ColorSpaceTag::AcesCc => write_color_function(self, "--acescc", f),
+            ColorSpaceTag::AcesCct => write_color_function(self, "--acescct", f),
+            ColorSpaceTag::Rec709 => write_color_function(self, "--rec709", f),
             ColorSpaceTag::Hsl => write_legacy_function(self, "hsl", 1.0, f),
             ColorSpaceTag::Hwb => write_modern_function(self, "hwb", f),
+            ColorSpaceTag::Hsv => write_color_function(self, "--hsv", f),
+            ColorSpaceTag::Luv => write_color_function(self, "--luv", f),
+            ColorSpaceTag::LchUv => write_color_function(self, "--lchuv", f),
+            ColorSpaceTag::Hsluv => write_color_function(self, "--hsluv", f),
+            ColorSpaceTag::Hpluv => write_color_function(self, "--hpluv", f),
+            ColorSpaceTag::Jzazbz => write_color_function(self, "--jzazbz", f),
+            ColorSpaceTag::JzCzhz => write_color_function(self, "--jzczhz", f),
+            ColorSpaceTag::IctcpPq => write_color_function(self, "--ictcp-pq", f),
+            ColorSpaceTag::IctcpHlg => write_color_function(self, "--ictcp-hlg", f),
             ColorSpaceTag::XyzD50 => write_color_function(self, "xyz-d50", f),
             ColorSpaceTag::XyzD65 => write_color_function(self, "xyz-d65", f),
             ColorSpaceTag::Lab => write_modern_function(self, "lab", f),
             ColorSpaceTag::Lch => write_modern_function(self, "lch", f),
             ColorSpaceTag::Oklab => write_modern_function(self, "oklab", f),
             ColorSpaceTag::Oklch => write_modern_function(self, "oklch", f),
+            ColorSpaceTag::Okhsl => write_color_function(self, "--okhsl", f),
+            ColorSpaceTag::Okhsv => write_color_function(self, "--okhsv", f),
+            ColorSpaceTag::Custom(id) => write_color_function(self, id.space().name(), f),
         }
     }
 }
@@ -152,7 +175,7 @@ impl core::fmt::UpperHex for Rgba8 {
 mod tests {
     extern crate alloc;
 
-    use crate::{parse_color, AlphaColor, DynamicColor, Hsl, Oklab, Srgb, XyzD65};
+    use crate::{parse_color, AlphaColor, ColorSpaceTag, DynamicColor, Hsl, Oklab, Srgb, XyzD65};
     use alloc::format;
 
     #[test]
@@ -184,6 +207,30 @@ mod tests {
             ("color(srgb 1.0 1.0 1.0)", "color(srgb 1 1 1)"),
             ("oklab(0.4 0.2 -0.2)", "oklab(0.4 0.2 -0.2)"),
             ("lab(20% 0 60)", "lab(20 0 60)"),
+            ("color(--okhsl 30 0.5 0.6)", "color(--okhsl 30 0.5 0.6)"),
+            ("color(--okhsv 200 50% 0.25)", "color(--okhsv 200 0.5 0.25)"),
+            ("color(--acescg 0.5 0.25 1)", "color(--acescg 0.5 0.25 1)"),
+            ("color(--acescc 0.5 0.25 1)", "color(--acescc 0.5 0.25 1)"),
+            ("color(--acescct 0.5 0.25 1)", "color(--acescct 0.5 0.25 1)"),
+            ("color(--rec709 0.5 0.25 1)", "color(--rec709 0.5 0.25 1)"),
+            ("color(--hsv 120 50% 25)", "color(--hsv 120 50 25)"),
+            ("color(--luv 50 20 -30)", "color(--luv 50 20 -30)"),
+            ("color(--hsluv 120 50% 25)", "color(--hsluv 120 50 25)"),
+            ("color(--jzczhz 50% 0.1 30)", "color(--jzczhz 0.5 0.1 30)"),
+            (
+                "color(--ictcp-pq 0.5 -0.1 0.1)",
+                "color(--ictcp-pq 0.5 -0.1 0.1)",
+            ),
+            (
+                "color(rec2100-pq 0.5 0.2 0.1)",
+                "color(rec2100-pq 0.5 0.2 0.1)",
+            ),
+            ("color(rec2100-hlg 75% 0 0)", "color(rec2100-hlg 0.75 0 0)"),
+            ("color-mix(in srgb, red, blue)", "color(srgb 0.5 0 0.5)"),
+            (
+                "color-mix(in lab, lab(20 0 60) 25%, lab(60 40 20))",
+                "lab(50 30 30)",
+            ),
         ] {
             let result = format!("{}", parse_color(specified).unwrap());
             assert_eq!(
@@ -200,6 +247,8 @@ mod tests {
             ("hwb(740deg 20% 30% / 50%)", "rgba("),
             ("hsl(120deg 50% 25%)", "rgb("),
             ("hsla(0.4turn 50% 25% / 50%)", "rgba("),
+            ("color-mix(in hsl, red, lime)", "rgb("),
+            ("color-mix(in hwb, red 20%, lime 20%)", "rgba("),
         ] {
             let result = format!("{}", parse_color(specified).unwrap());
             assert!(
@@ -209,6 +258,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn serialized_color_space_names_parse() {
+        for cs in [
+            ColorSpaceTag::Srgb,
+            ColorSpaceTag::LinearSrgb,
+            ColorSpaceTag::Lab,
+            ColorSpaceTag::Lch,
+            ColorSpaceTag::Luv,
+            ColorSpaceTag::LchUv,
+            ColorSpaceTag::Hsl,
+            ColorSpaceTag::Hwb,
+            ColorSpaceTag::Hsv,
+            ColorSpaceTag::Hsluv,
+            ColorSpaceTag::Hpluv,
+            ColorSpaceTag::Oklab,
+            ColorSpaceTag::Oklch,
+            ColorSpaceTag::Jzazbz,
+            ColorSpaceTag::JzCzhz,
+            ColorSpaceTag::IctcpPq,
+            ColorSpaceTag::IctcpHlg,
+            ColorSpaceTag::Okhsl,
+            ColorSpaceTag::Okhsv,
+            ColorSpaceTag::DisplayP3,
+            ColorSpaceTag::LinearDisplayP3,
+            ColorSpaceTag::A98Rgb,
+            ColorSpaceTag::LinearA98Rgb,
+            ColorSpaceTag::ProphotoRgb,
+            ColorSpaceTag::LinearProphotoRgb,
+            ColorSpaceTag::Rec2020,
+            ColorSpaceTag::LinearRec2020,
+            ColorSpaceTag::Rec2100Pq,
+            ColorSpaceTag::Rec2100Hlg,
+            ColorSpaceTag::Aces2065_1,
+            ColorSpaceTag::AcesCg,
+            ColorSpaceTag::AcesCc,
+            ColorSpaceTag::AcesCct,
+            ColorSpaceTag::Rec709,
+            ColorSpaceTag::XyzD50,
+            ColorSpaceTag::XyzD65,
+        ] {
+            let color = DynamicColor {
+                cs,
+                flags: Default::default(),
+                components: [0.5, 0.5, 0.5, 1.],
+            };
+            let serialized = format!("{color}");
+            let name = match serialized.strip_prefix("color(") {
+                Some(args) => args.split(' ').next().unwrap(),
+                None => serialized.split('(').next().unwrap(),
+            };
+            assert_eq!(name.parse::<ColorSpaceTag>(), Ok(cs), "{serialized}");
+        }
+    }
+
     #[test]
     fn generated_to_serialized() {
         for (color, expected) in [
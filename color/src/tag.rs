@@ -4,15 +4,20 @@
 //! The color space tag enum.
 
 use crate::{
-    A98Rgb, Aces2065_1, AcesCg, Chromaticity, ColorSpace, ColorSpaceLayout, DisplayP3, Hsl, Hwb,
-    Lab, Lch, LinearSrgb, Missing, Oklab, Oklch, ProphotoRgb, Rec2020, Srgb, XyzD50, XyzD65,
+    A98Rgb, Aces2065_1, AcesCc, AcesCct, AcesCg, ChromaticAdaptation, Chromaticity, ColorSpace,
+    ColorSpaceLayout, CustomColorSpaceId, DisplayP3, DynamicColorSpace, Hpluv, Hsl, Hsluv, Hsv,
+    Hwb, IctcpHlg, IctcpPq, JzCzhz, Jzazbz, Lab, Lch, LchUv, LinearA98Rgb, LinearDisplayP3,
+    LinearProphotoRgb, LinearRec2020, LinearSrgb, Luv, Missing, Okhsl, Okhsv, Oklab, Oklch,
+    ProphotoRgb, Rec2020, Rec2100Hlg, Rec2100Pq, Rec709, Srgb, XyzD50, XyzD65,
 };
 
 /// The color space tag for [dynamic colors].
 ///
 /// This represents a fixed set of known color spaces. The set contains all
 /// color spaces in the CSS Color 4 spec and includes some other color spaces
-/// useful for computer graphics.
+/// useful for computer graphics. Additional RGB color spaces can be
+/// [registered](DynamicColorSpace::register) at runtime, and are represented by
+/// [`ColorSpaceTag::Custom`].
 ///
 /// The integer values of these variants can change in breaking releases.
 ///
@@ -32,40 +37,97 @@ pub enum ColorSpaceTag {
     Lab = 2,
     /// The [`Lch`] color space.
     Lch = 3,
+    /// The [`Luv`] color space.
+    Luv = 19,
+    /// The [`LchUv`] color space.
+    LchUv = 20,
     /// The [`Hsl`] color space.
     Hsl = 4,
     /// The [`Hwb`] color space.
     Hwb = 5,
+    /// The [`Hsv`] color space.
+    Hsv = 18,
+    /// The [`Hsluv`] color space.
+    Hsluv = 21,
+    /// The [`Hpluv`] color space.
+    Hpluv = 22,
     /// The [`Oklab`] color space.
     Oklab = 6,
     /// The [`Oklch`] color space.
     Oklch = 7,
+    /// The [`Jzazbz`] color space.
+    Jzazbz = 23,
+    /// The [`JzCzhz`] color space.
+    JzCzhz = 24,
+    /// The [`IctcpPq`] color space.
+    IctcpPq = 25,
+    /// The [`IctcpHlg`] color space.
+    IctcpHlg = 26,
+    /// The [`Okhsl`] color space.
+    Okhsl = 16,
+    /// The [`Okhsv`] color space.
+    Okhsv = 17,
     /// The [`DisplayP3`] color space.
     DisplayP3 = 8,
+    /// The [`LinearDisplayP3`] color space.
+    LinearDisplayP3 = 29,
     /// The [`A98Rgb`] color space.
     A98Rgb = 9,
+    /// The [`LinearA98Rgb`] color space.
+    LinearA98Rgb = 30,
     /// The [`ProphotoRgb`] color space.
     ProphotoRgb = 10,
+    /// The [`LinearProphotoRgb`] color space.
+    LinearProphotoRgb = 31,
+    /// The [`Rec709`] color space.
+    Rec709 = 36,
     /// The [`Rec2020`] color space.
     Rec2020 = 11,
+    /// The [`LinearRec2020`] color space.
+    LinearRec2020 = 32,
+    /// The [`Rec2100Pq`] color space.
+    Rec2100Pq = 27,
+    /// The [`Rec2100Hlg`] color space.
+    Rec2100Hlg = 28,
     /// The [`Aces2065_1`] color space.
     Aces2065_1 = 15,
     /// The [`AcesCg`] color space.
     AcesCg = 12,
+    /// The [`AcesCc`] color space.
+    AcesCc = 34,
+    /// The [`AcesCct`] color space.
+    AcesCct = 35,
     /// The [`XyzD50`] color space.
     XyzD50 = 13,
     /// The [`XyzD65`] color space.
     XyzD65 = 14,
-    // NOTICE: If a new value is added, be sure to modify `MAX_VALUE` in the bytemuck impl. Also
-    // note the variants' integer values are not necessarily in order, allowing newly added color
-    // space tags to be grouped with related color spaces.
+    /// A [`DynamicColorSpace`] registered at runtime.
+    Custom(CustomColorSpaceId) = 33,
+    // NOTICE: The variants' integer values are not necessarily in order, allowing newly added
+    // color space tags to be grouped with related color spaces.
 }
 
 impl ColorSpaceTag {
+    /// The registered color space of a [`ColorSpaceTag::Custom`] tag.
+    ///
+    /// Returns `None` for the built-in color spaces.
+    pub fn custom_space(self) -> Option<&'static DynamicColorSpace> {
+        match self {
+            Self::Custom(id) => Some(id.space()),
+            _ => None,
+        }
+    }
+
     pub(crate) fn layout(self) -> ColorSpaceLayout {
         match self {
-            Self::Lch | Self::Oklch => ColorSpaceLayout::HueThird,
-            Self::Hsl | Self::Hwb => ColorSpaceLayout::HueFirst,
+            Self::Lch | Self::LchUv | Self::Oklch | Self::JzCzhz => ColorSpaceLayout::HueThird,
+            Self::Hsl
+            | Self::Hwb
+            | Self::Hsv
+            | Self::Hsluv
+            | Self::Hpluv
+            | Self::Okhsl
+            | Self::Okhsv => ColorSpaceLayout::HueFirst,
             _ => ColorSpaceLayout::Rectangular,
         }
     }
@@ -84,32 +146,54 @@ impl ColorSpaceTag {
             (
                 Srgb | LinearSrgb
                     | DisplayP3
+                    | LinearDisplayP3
                     | A98Rgb
+                    | LinearA98Rgb
                     | ProphotoRgb
+                    | LinearProphotoRgb
+                    | Rec709
                     | Rec2020
+                    | LinearRec2020
+                    | Rec2100Pq
+                    | Rec2100Hlg
                     | Aces2065_1
                     | AcesCg
+                    | AcesCc
+                    | AcesCct
                     | XyzD50
-                    | XyzD65,
+                    | XyzD65
+                    | Custom(_),
                 Srgb | LinearSrgb
                     | DisplayP3
+                    | LinearDisplayP3
                     | A98Rgb
+                    | LinearA98Rgb
                     | ProphotoRgb
+                    | LinearProphotoRgb
+                    | Rec709
                     | Rec2020
+                    | LinearRec2020
+                    | Rec2100Pq
+                    | Rec2100Hlg
                     | Aces2065_1
                     | AcesCg
+                    | AcesCc
+                    | AcesCct
                     | XyzD50
                     | XyzD65
-            ) | (Lab | Oklab, Lab | Oklab)
-                | (Lch | Oklch, Lch | Oklch)
+                    | Custom(_)
+            ) | (Lab | Luv | Oklab | Jzazbz, Lab | Luv | Oklab | Jzazbz)
+                | (Lch | LchUv | Oklch | JzCzhz, Lch | LchUv | Oklch | JzCzhz)
         )
     }
 
     pub(crate) fn l_missing(self, missing: Missing) -> bool {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch => missing.contains(0),
-            Hsl => missing.contains(2),
+            Lab | Lch | Luv | LchUv | Oklab | Oklch | Jzazbz | JzCzhz | IctcpPq | IctcpHlg => {
+                missing.contains(0)
+            }
+            Hsl | Hsluv | Hpluv | Okhsl => missing.contains(2),
             _ => false,
         }
     }
@@ -117,11 +201,11 @@ impl ColorSpaceTag {
     pub(crate) fn set_l_missing(self, missing: &mut Missing, components: &mut [f32; 4]) {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch => {
+            Lab | Lch | Luv | LchUv | Oklab | Oklch | Jzazbz | JzCzhz | IctcpPq | IctcpHlg => {
                 missing.insert(0);
                 components[0] = 0.0;
             }
-            Hsl => {
+            Hsl | Hsluv | Hpluv | Okhsl => {
                 missing.insert(2);
                 components[2] = 0.0;
             }
@@ -132,7 +216,8 @@ impl ColorSpaceTag {
     pub(crate) fn c_missing(self, missing: Missing) -> bool {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch | Hsl => missing.contains(1),
+            Lab | Lch | Luv | LchUv | Oklab | Oklch | Jzazbz | JzCzhz | Hsl | Hsv | Hsluv
+            | Hpluv | Okhsl | Okhsv => missing.contains(1),
             _ => false,
         }
     }
@@ -140,7 +225,8 @@ impl ColorSpaceTag {
     pub(crate) fn set_c_missing(self, missing: &mut Missing, components: &mut [f32; 4]) {
         use ColorSpaceTag::*;
         match self {
-            Lab | Lch | Oklab | Oklch | Hsl => {
+            Lab | Lch | Luv | LchUv | Oklab | Oklch | Jzazbz | JzCzhz | Hsl | Hsv | Hsluv
+            | Hpluv | Okhsl | Okhsv => {
                 missing.insert(1);
                 components[1] = 0.0;
             }
@@ -173,15 +259,36 @@ impl ColorSpaceTag {
             Self::Oklab => Oklab::from_linear_srgb(rgb),
             Self::Oklch => Oklch::from_linear_srgb(rgb),
             Self::DisplayP3 => DisplayP3::from_linear_srgb(rgb),
+            Self::LinearDisplayP3 => LinearDisplayP3::from_linear_srgb(rgb),
             Self::A98Rgb => A98Rgb::from_linear_srgb(rgb),
+            Self::LinearA98Rgb => LinearA98Rgb::from_linear_srgb(rgb),
             Self::ProphotoRgb => ProphotoRgb::from_linear_srgb(rgb),
+            Self::LinearProphotoRgb => LinearProphotoRgb::from_linear_srgb(rgb),
+            Self::Rec709 => Rec709::from_linear_srgb(rgb),
             Self::Rec2020 => Rec2020::from_linear_srgb(rgb),
+            Self::LinearRec2020 => LinearRec2020::from_linear_srgb(rgb),
+            Self::Rec2100Pq => Rec2100Pq::from_linear_srgb(rgb),
+            Self::Rec2100Hlg => Rec2100Hlg::from_linear_srgb(rgb),
             Self::Aces2065_1 => Aces2065_1::from_linear_srgb(rgb),
             Self::AcesCg => AcesCg::from_linear_srgb(rgb),
+            Self::AcesCc => AcesCc::from_linear_srgb(rgb),
+            Self::AcesCct => AcesCct::from_linear_srgb(rgb),
             Self::XyzD50 => XyzD50::from_linear_srgb(rgb),
             Self::XyzD65 => XyzD65::from_linear_srgb(rgb),
             Self::Hsl => Hsl::from_linear_srgb(rgb),
             Self::Hwb => Hwb::from_linear_srgb(rgb),
+            Self::Hsv => Hsv::from_linear_srgb(rgb),
+            Self::Luv => Luv::from_linear_srgb(rgb),
+            Self::LchUv => LchUv::from_linear_srgb(rgb),
+            Self::Hsluv => Hsluv::from_linear_srgb(rgb),
+            Self::Hpluv => Hpluv::from_linear_srgb(rgb),
+            Self::Jzazbz => Jzazbz::from_linear_srgb(rgb),
+            Self::JzCzhz => JzCzhz::from_linear_srgb(rgb),
+            Self::IctcpPq => IctcpPq::from_linear_srgb(rgb),
+            Self::IctcpHlg => IctcpHlg::from_linear_srgb(rgb),
+            Self::Okhsl => Okhsl::from_linear_srgb(rgb),
+            Self::Okhsv => Okhsv::from_linear_srgb(rgb),
+            Self::Custom(id) => id.space().from_linear_srgb(rgb),
         }
     }
 
@@ -197,15 +304,36 @@ impl ColorSpaceTag {
             Self::Oklab => Oklab::to_linear_srgb(src),
             Self::Oklch => Oklch::to_linear_srgb(src),
             Self::DisplayP3 => DisplayP3::to_linear_srgb(src),
+            Self::LinearDisplayP3 => LinearDisplayP3::to_linear_srgb(src),
             Self::A98Rgb => A98Rgb::to_linear_srgb(src),
+            Self::LinearA98Rgb => LinearA98Rgb::to_linear_srgb(src),
             Self::ProphotoRgb => ProphotoRgb::to_linear_srgb(src),
+            Self::LinearProphotoRgb => LinearProphotoRgb::to_linear_srgb(src),
+            Self::Rec709 => Rec709::to_linear_srgb(src),
             Self::Rec2020 => Rec2020::to_linear_srgb(src),
+            Self::LinearRec2020 => LinearRec2020::to_linear_srgb(src),
+            Self::Rec2100Pq => Rec2100Pq::to_linear_srgb(src),
+            Self::Rec2100Hlg => Rec2100Hlg::to_linear_srgb(src),
             Self::Aces2065_1 => Aces2065_1::to_linear_srgb(src),
             Self::AcesCg => AcesCg::to_linear_srgb(src),
+            Self::AcesCc => AcesCc::to_linear_srgb(src),
+            Self::AcesCct => AcesCct::to_linear_srgb(src),
             Self::XyzD50 => XyzD50::to_linear_srgb(src),
             Self::XyzD65 => XyzD65::to_linear_srgb(src),
             Self::Hsl => Hsl::to_linear_srgb(src),
             Self::Hwb => Hwb::to_linear_srgb(src),
+            Self::Hsv => Hsv::to_linear_srgb(src),
+            Self::Luv => Luv::to_linear_srgb(src),
+            Self::LchUv => LchUv::to_linear_srgb(src),
+            Self::Hsluv => Hsluv::to_linear_srgb(src),
+            Self::Hpluv => Hpluv::to_linear_srgb(src),
+            Self::Jzazbz => Jzazbz::to_linear_srgb(src),
+            Self::JzCzhz => JzCzhz::to_linear_srgb(src),
+            Self::IctcpPq => IctcpPq::to_linear_srgb(src),
+            Self::IctcpHlg => IctcpHlg::to_linear_srgb(src),
+            Self::Okhsl => Okhsl::to_linear_srgb(src),
+            Self::Okhsv => Okhsv::to_linear_srgb(src),
+            Self::Custom(id) => id.space().to_linear_srgb(src),
         }
     }
 
@@ -215,14 +343,30 @@ impl ColorSpaceTag {
     pub fn convert(self, target: Self, src: [f32; 3]) -> [f32; 3] {
         match (self, target) {
             _ if self == target => src,
-            (Self::Oklab, Self::Oklch) | (Self::Lab, Self::Lch) => Oklab::convert::<Oklch>(src),
-            (Self::Oklch, Self::Oklab) | (Self::Lch, Self::Lab) => Oklch::convert::<Oklab>(src),
+            (Self::Oklab, Self::Oklch)
+            | (Self::Lab, Self::Lch)
+            | (Self::Luv, Self::LchUv)
+            | (Self::Jzazbz, Self::JzCzhz) => Oklab::convert::<Oklch>(src),
+            (Self::Oklch, Self::Oklab)
+            | (Self::Lch, Self::Lab)
+            | (Self::LchUv, Self::Luv)
+            | (Self::JzCzhz, Self::Jzazbz) => Oklch::convert::<Oklab>(src),
+            (Self::LchUv, Self::Hsluv) => LchUv::convert::<Hsluv>(src),
+            (Self::Hsluv, Self::LchUv) => Hsluv::convert::<LchUv>(src),
+            (Self::LchUv, Self::Hpluv) => LchUv::convert::<Hpluv>(src),
+            (Self::Hpluv, Self::LchUv) => Hpluv::convert::<LchUv>(src),
             (Self::Srgb, Self::Hsl) => Srgb::convert::<Hsl>(src),
             (Self::Hsl, Self::Srgb) => Hsl::convert::<Srgb>(src),
             (Self::Srgb, Self::Hwb) => Srgb::convert::<Hwb>(src),
             (Self::Hwb, Self::Srgb) => Hwb::convert::<Srgb>(src),
             (Self::Hsl, Self::Hwb) => Hsl::convert::<Hwb>(src),
             (Self::Hwb, Self::Hsl) => Hwb::convert::<Hsl>(src),
+            (Self::Srgb, Self::Hsv) => Srgb::convert::<Hsv>(src),
+            (Self::Hsv, Self::Srgb) => Hsv::convert::<Srgb>(src),
+            (Self::Hsl, Self::Hsv) => Hsl::convert::<Hsv>(src),
+            (Self::Hsv, Self::Hsl) => Hsv::convert::<Hsl>(src),
+            (Self::Hwb, Self::Hsv) => Hwb::convert::<Hsv>(src),
+            (Self::Hsv, Self::Hwb) => Hsv::convert::<Hwb>(src),
             _ => target.from_linear_srgb(self.to_linear_srgb(src)),
         }
     }
@@ -242,15 +386,36 @@ impl ColorSpaceTag {
             Self::Oklab => Oklab::from_linear_srgb_absolute(rgb),
             Self::Oklch => Oklch::from_linear_srgb_absolute(rgb),
             Self::DisplayP3 => DisplayP3::from_linear_srgb_absolute(rgb),
+            Self::LinearDisplayP3 => LinearDisplayP3::from_linear_srgb_absolute(rgb),
             Self::A98Rgb => A98Rgb::from_linear_srgb_absolute(rgb),
+            Self::LinearA98Rgb => LinearA98Rgb::from_linear_srgb_absolute(rgb),
             Self::ProphotoRgb => ProphotoRgb::from_linear_srgb_absolute(rgb),
+            Self::LinearProphotoRgb => LinearProphotoRgb::from_linear_srgb_absolute(rgb),
+            Self::Rec709 => Rec709::from_linear_srgb_absolute(rgb),
             Self::Rec2020 => Rec2020::from_linear_srgb_absolute(rgb),
+            Self::LinearRec2020 => LinearRec2020::from_linear_srgb_absolute(rgb),
+            Self::Rec2100Pq => Rec2100Pq::from_linear_srgb_absolute(rgb),
+            Self::Rec2100Hlg => Rec2100Hlg::from_linear_srgb_absolute(rgb),
             Self::Aces2065_1 => Aces2065_1::from_linear_srgb_absolute(rgb),
             Self::AcesCg => AcesCg::from_linear_srgb_absolute(rgb),
+            Self::AcesCc => AcesCc::from_linear_srgb_absolute(rgb),
+            Self::AcesCct => AcesCct::from_linear_srgb_absolute(rgb),
             Self::XyzD50 => XyzD50::from_linear_srgb_absolute(rgb),
             Self::XyzD65 => XyzD65::from_linear_srgb_absolute(rgb),
             Self::Hsl => Hsl::from_linear_srgb_absolute(rgb),
             Self::Hwb => Hwb::from_linear_srgb_absolute(rgb),
+            Self::Hsv => Hsv::from_linear_srgb_absolute(rgb),
+            Self::Luv => Luv::from_linear_srgb_absolute(rgb),
+            Self::LchUv => LchUv::from_linear_srgb_absolute(rgb),
+            Self::Hsluv => Hsluv::from_linear_srgb_absolute(rgb),
+            Self::Hpluv => Hpluv::from_linear_srgb_absolute(rgb),
+            Self::Jzazbz => Jzazbz::from_linear_srgb_absolute(rgb),
+            Self::JzCzhz => JzCzhz::from_linear_srgb_absolute(rgb),
+            Self::IctcpPq => IctcpPq::from_linear_srgb_absolute(rgb),
+            Self::IctcpHlg => IctcpHlg::from_linear_srgb_absolute(rgb),
+            Self::Okhsl => Okhsl::from_linear_srgb_absolute(rgb),
+            Self::Okhsv => Okhsv::from_linear_srgb_absolute(rgb),
+            Self::Custom(id) => id.space().from_linear_srgb_absolute(rgb),
         }
     }
 
@@ -269,15 +434,36 @@ impl ColorSpaceTag {
             Self::Oklab => Oklab::to_linear_srgb_absolute(src),
             Self::Oklch => Oklch::to_linear_srgb_absolute(src),
             Self::DisplayP3 => DisplayP3::to_linear_srgb_absolute(src),
+            Self::LinearDisplayP3 => LinearDisplayP3::to_linear_srgb_absolute(src),
             Self::A98Rgb => A98Rgb::to_linear_srgb_absolute(src),
+            Self::LinearA98Rgb => LinearA98Rgb::to_linear_srgb_absolute(src),
             Self::ProphotoRgb => ProphotoRgb::to_linear_srgb_absolute(src),
+            Self::LinearProphotoRgb => LinearProphotoRgb::to_linear_srgb_absolute(src),
+            Self::Rec709 => Rec709::to_linear_srgb_absolute(src),
             Self::Rec2020 => Rec2020::to_linear_srgb_absolute(src),
+            Self::LinearRec2020 => LinearRec2020::to_linear_srgb_absolute(src),
+            Self::Rec2100Pq => Rec2100Pq::to_linear_srgb_absolute(src),
+            Self::Rec2100Hlg => Rec2100Hlg::to_linear_srgb_absolute(src),
             Self::Aces2065_1 => Aces2065_1::to_linear_srgb_absolute(src),
             Self::AcesCg => AcesCg::to_linear_srgb_absolute(src),
+            Self::AcesCc => AcesCc::to_linear_srgb_absolute(src),
+            Self::AcesCct => AcesCct::to_linear_srgb_absolute(src),
             Self::XyzD50 => XyzD50::to_linear_srgb_absolute(src),
             Self::XyzD65 => XyzD65::to_linear_srgb_absolute(src),
             Self::Hsl => Hsl::to_linear_srgb_absolute(src),
             Self::Hwb => Hwb::to_linear_srgb_absolute(src),
+            Self::Hsv => Hsv::to_linear_srgb_absolute(src),
+            Self::Luv => Luv::to_linear_srgb_absolute(src),
+            Self::LchUv => LchUv::to_linear_srgb_absolute(src),
+            Self::Hsluv => Hsluv::to_linear_srgb_absolute(src),
+            Self::Hpluv => Hpluv::to_linear_srgb_absolute(src),
+            Self::Jzazbz => Jzazbz::to_linear_srgb_absolute(src),
+            Self::JzCzhz => JzCzhz::to_linear_srgb_absolute(src),
+            Self::IctcpPq => IctcpPq::to_linear_srgb_absolute(src),
+            Self::IctcpHlg => IctcpHlg::to_linear_srgb_absolute(src),
+            Self::Okhsl => Okhsl::to_linear_srgb_absolute(src),
+            Self::Okhsv => Okhsv::to_linear_srgb_absolute(src),
+            Self::Custom(id) => id.space().to_linear_srgb_absolute(src),
         }
     }
 
@@ -291,18 +477,30 @@ impl ColorSpaceTag {
     pub fn convert_absolute(self, target: Self, src: [f32; 3]) -> [f32; 3] {
         match (self, target) {
             _ if self == target => src,
-            (Self::Oklab, Self::Oklch) | (Self::Lab, Self::Lch) => {
-                Oklab::convert_absolute::<Oklch>(src)
-            }
-            (Self::Oklch, Self::Oklab) | (Self::Lch, Self::Lab) => {
-                Oklch::convert_absolute::<Oklab>(src)
-            }
+            (Self::Oklab, Self::Oklch)
+            | (Self::Lab, Self::Lch)
+            | (Self::Luv, Self::LchUv)
+            | (Self::Jzazbz, Self::JzCzhz) => Oklab::convert_absolute::<Oklch>(src),
+            (Self::Oklch, Self::Oklab)
+            | (Self::Lch, Self::Lab)
+            | (Self::LchUv, Self::Luv)
+            | (Self::JzCzhz, Self::Jzazbz) => Oklch::convert_absolute::<Oklab>(src),
+            (Self::LchUv, Self::Hsluv) => LchUv::convert_absolute::<Hsluv>(src),
+            (Self::Hsluv, Self::LchUv) => Hsluv::convert_absolute::<LchUv>(src),
+            (Self::LchUv, Self::Hpluv) => LchUv::convert_absolute::<Hpluv>(src),
+            (Self::Hpluv, Self::LchUv) => Hpluv::convert_absolute::<LchUv>(src),
             (Self::Srgb, Self::Hsl) => Srgb::convert_absolute::<Hsl>(src),
             (Self::Hsl, Self::Srgb) => Hsl::convert_absolute::<Srgb>(src),
             (Self::Srgb, Self::Hwb) => Srgb::convert_absolute::<Hwb>(src),
             (Self::Hwb, Self::Srgb) => Hwb::convert_absolute::<Srgb>(src),
             (Self::Hsl, Self::Hwb) => Hsl::convert_absolute::<Hwb>(src),
             (Self::Hwb, Self::Hsl) => Hwb::convert_absolute::<Hsl>(src),
+            (Self::Srgb, Self::Hsv) => Srgb::convert_absolute::<Hsv>(src),
+            (Self::Hsv, Self::Srgb) => Hsv::convert_absolute::<Srgb>(src),
+            (Self::Hsl, Self::Hsv) => Hsl::convert_absolute::<Hsv>(src),
+            (Self::Hsv, Self::Hsl) => Hsv::convert_absolute::<Hsl>(src),
+            (Self::Hwb, Self::Hsv) => Hwb::convert_absolute::<Hsv>(src),
+            (Self::Hsv, Self::Hwb) => Hsv::convert_absolute::<Hwb>(src),
             _ => target.from_linear_srgb_absolute(self.to_linear_srgb_absolute(src)),
         }
     }
@@ -313,30 +511,70 @@ impl ColorSpaceTag {
     ///
     /// The color is assumed to be under a reference white point of `from` and is chromatically
     /// adapted to the given white point `to`. The linear Bradford transform is used to perform the
-    /// chromatic adaptation.
+    /// chromatic adaptation. See [`ColorSpaceTag::chromatically_adapt_with`] to use a different
+    /// transform.
     pub fn chromatically_adapt(
         self,
         src: [f32; 3],
         from: Chromaticity,
         to: Chromaticity,
+    ) -> [f32; 3] {
+        self.chromatically_adapt_with(src, from, to, ChromaticAdaptation::Bradford)
+    }
+
+    /// Chromatically adapt the color between the given white point chromaticities, using the
+    /// given chromatic adaptation transform.
+    ///
+    /// This is the tagged counterpart of [`ColorSpace::chromatically_adapt_with`].
+    pub fn chromatically_adapt_with(
+        self,
+        src: [f32; 3],
+        from: Chromaticity,
+        to: Chromaticity,
+        method: ChromaticAdaptation,
     ) -> [f32; 3] {
         match self {
-            Self::Srgb => Srgb::chromatically_adapt(src, from, to),
-            Self::LinearSrgb => LinearSrgb::chromatically_adapt(src, from, to),
-            Self::Lab => Lab::chromatically_adapt(src, from, to),
-            Self::Lch => Lch::chromatically_adapt(src, from, to),
-            Self::Oklab => Oklab::chromatically_adapt(src, from, to),
-            Self::Oklch => Oklch::chromatically_adapt(src, from, to),
-            Self::DisplayP3 => DisplayP3::chromatically_adapt(src, from, to),
-            Self::A98Rgb => A98Rgb::chromatically_adapt(src, from, to),
-            Self::ProphotoRgb => ProphotoRgb::chromatically_adapt(src, from, to),
-            Self::Rec2020 => Rec2020::chromatically_adapt(src, from, to),
-            Self::Aces2065_1 => Aces2065_1::chromatically_adapt(src, from, to),
-            Self::AcesCg => AcesCg::chromatically_adapt(src, from, to),
-            Self::XyzD50 => XyzD50::chromatically_adapt(src, from, to),
-            Self::XyzD65 => XyzD65::chromatically_adapt(src, from, to),
-            Self::Hsl => Hsl::chromatically_adapt(src, from, to),
-            Self::Hwb => Hwb::chromatically_adapt(src, from, to),
+            Self::Srgb => Srgb::chromatically_adapt_with(src, from, to, method),
+            Self::LinearSrgb => LinearSrgb::chromatically_adapt_with(src, from, to, method),
+            Self::Lab => Lab::chromatically_adapt_with(src, from, to, method),
+            Self::Lch => Lch::chromatically_adapt_with(src, from, to, method),
+            Self::Oklab => Oklab::chromatically_adapt_with(src, from, to, method),
+            Self::Oklch => Oklch::chromatically_adapt_with(src, from, to, method),
+            Self::DisplayP3 => DisplayP3::chromatically_adapt_with(src, from, to, method),
+            Self::LinearDisplayP3 => {
+                LinearDisplayP3::chromatically_adapt_with(src, from, to, method)
+            }
+            Self::A98Rgb => A98Rgb::chromatically_adapt_with(src, from, to, method),
+            Self::LinearA98Rgb => LinearA98Rgb::chromatically_adapt_with(src, from, to, method),
+            Self::ProphotoRgb => ProphotoRgb::chromatically_adapt_with(src, from, to, method),
+            Self::LinearProphotoRgb => {
+                LinearProphotoRgb::chromatically_adapt_with(src, from, to, method)
+            }
+            Self::Rec709 => Rec709::chromatically_adapt_with(src, from, to, method),
+            Self::Rec2020 => Rec2020::chromatically_adapt_with(src, from, to, method),
+            Self::LinearRec2020 => LinearRec2020::chromatically_adapt_with(src, from, to, method),
+            Self::Rec2100Pq => Rec2100Pq::chromatically_adapt_with(src, from, to, method),
+            Self::Rec2100Hlg => Rec2100Hlg::chromatically_adapt_with(src, from, to, method),
+            Self::Aces2065_1 => Aces2065_1::chromatically_adapt_with(src, from, to, method),
+            Self::AcesCg => AcesCg::chromatically_adapt_with(src, from, to, method),
+            Self::AcesCc => AcesCc::chromatically_adapt_with(src, from, to, method),
+            Self::AcesCct => AcesCct::chromatically_adapt_with(src, from, to, method),
+            Self::XyzD50 => XyzD50::chromatically_adapt_with(src, from, to, method),
+            Self::XyzD65 => XyzD65::chromatically_adapt_with(src, from, to, method),
+            Self::Hsl => Hsl::chromatically_adapt_with(src, from, to, method),
+            Self::Hwb => Hwb::chromatically_adapt_with(src, from, to, method),
+            Self::Hsv => Hsv::chromatically_adapt_with(src, from, to, method),
+            Self::Luv => Luv::chromatically_adapt_with(src, from, to, method),
+            Self::LchUv => LchUv::chromatically_adapt_with(src, from, to, method),
+            Self::Hsluv => Hsluv::chromatically_adapt_with(src, from, to, method),
+            Self::Hpluv => Hpluv::chromatically_adapt_with(src, from, to, method),
+            Self::Jzazbz => Jzazbz::chromatically_adapt_with(src, from, to, method),
+            Self::JzCzhz => JzCzhz::chromatically_adapt_with(src, from, to, method),
+            Self::IctcpPq => IctcpPq::chromatically_adapt_with(src, from, to, method),
+            Self::IctcpHlg => IctcpHlg::chromatically_adapt_with(src, from, to, method),
+            Self::Okhsl => Okhsl::chromatically_adapt_with(src, from, to, method),
+            Self::Okhsv => Okhsv::chromatically_adapt_with(src, from, to, method),
+            Self::Custom(id) => id.space().chromatically_adapt_with(src, from, to, method),
         }
     }
 
@@ -346,8 +584,19 @@ impl ColorSpaceTag {
     pub fn scale_chroma(self, src: [f32; 3], scale: f32) -> [f32; 3] {
         match self {
             Self::LinearSrgb => LinearSrgb::scale_chroma(src, scale),
-            Self::Oklab | Self::Lab => Oklab::scale_chroma(src, scale),
-            Self::Oklch | Self::Lch | Self::Hsl => Oklch::scale_chroma(src, scale),
+            Self::Oklab | Self::Lab | Self::Luv | Self::Jzazbz | Self::IctcpPq | Self::IctcpHlg => {
+                Oklab::scale_chroma(src, scale)
+            }
+            Self::Oklch
+            | Self::Lch
+            | Self::LchUv
+            | Self::JzCzhz
+            | Self::Hsl
+            | Self::Hsv
+            | Self::Hsluv
+            | Self::Hpluv
+            | Self::Okhsl
+            | Self::Okhsv => Oklch::scale_chroma(src, scale),
             _ => {
                 let rgb = self.to_linear_srgb(src);
                 let scaled = LinearSrgb::scale_chroma(rgb, scale);
@@ -368,15 +617,81 @@ impl ColorSpaceTag {
             Self::Oklab => Oklab::clip(src),
             Self::Oklch => Oklch::clip(src),
             Self::DisplayP3 => DisplayP3::clip(src),
+            Self::LinearDisplayP3 => LinearDisplayP3::clip(src),
             Self::A98Rgb => A98Rgb::clip(src),
+            Self::LinearA98Rgb => LinearA98Rgb::clip(src),
             Self::ProphotoRgb => ProphotoRgb::clip(src),
+            Self::LinearProphotoRgb => LinearProphotoRgb::clip(src),
+            Self::Rec709 => Rec709::clip(src),
             Self::Rec2020 => Rec2020::clip(src),
+            Self::LinearRec2020 => LinearRec2020::clip(src),
+            Self::Rec2100Pq => Rec2100Pq::clip(src),
+            Self::Rec2100Hlg => Rec2100Hlg::clip(src),
             Self::Aces2065_1 => Aces2065_1::clip(src),
             Self::AcesCg => AcesCg::clip(src),
+            Self::AcesCc => AcesCc::clip(src),
+            Self::AcesCct => AcesCct::clip(src),
             Self::XyzD50 => XyzD50::clip(src),
             Self::XyzD65 => XyzD65::clip(src),
             Self::Hsl => Hsl::clip(src),
             Self::Hwb => Hwb::clip(src),
+            Self::Hsv => Hsv::clip(src),
+            Self::Luv => Luv::clip(src),
+            Self::LchUv => LchUv::clip(src),
+            Self::Hsluv => Hsluv::clip(src),
+            Self::Hpluv => Hpluv::clip(src),
+            Self::Jzazbz => Jzazbz::clip(src),
+            Self::JzCzhz => JzCzhz::clip(src),
+            Self::IctcpPq => IctcpPq::clip(src),
+            Self::IctcpHlg => IctcpHlg::clip(src),
+            Self::Okhsl => Okhsl::clip(src),
+            Self::Okhsv => Okhsv::clip(src),
+            Self::Custom(id) => id.space().clip(src),
+        }
+    }
+
+    /// Whether the color is within the gamut of the color space.
+    ///
+    /// See [`ColorSpace::is_in_gamut`] for more details.
+    pub fn is_in_gamut(self, src: [f32; 3], epsilon: f32) -> bool {
+        match self {
+            Self::Srgb => Srgb::is_in_gamut(src, epsilon),
+            Self::LinearSrgb => LinearSrgb::is_in_gamut(src, epsilon),
+            Self::Lab => Lab::is_in_gamut(src, epsilon),
+            Self::Lch => Lch::is_in_gamut(src, epsilon),
+            Self::Oklab => Oklab::is_in_gamut(src, epsilon),
+            Self::Oklch => Oklch::is_in_gamut(src, epsilon),
+            Self::DisplayP3 => DisplayP3::is_in_gamut(src, epsilon),
+            Self::LinearDisplayP3 => LinearDisplayP3::is_in_gamut(src, epsilon),
+            Self::A98Rgb => A98Rgb::is_in_gamut(src, epsilon),
+            Self::LinearA98Rgb => LinearA98Rgb::is_in_gamut(src, epsilon),
+            Self::ProphotoRgb => ProphotoRgb::is_in_gamut(src, epsilon),
+            Self::LinearProphotoRgb => LinearProphotoRgb::is_in_gamut(src, epsilon),
+            Self::Rec709 => Rec709::is_in_gamut(src, epsilon),
+            Self::Rec2020 => Rec2020::is_in_gamut(src, epsilon),
+            Self::LinearRec2020 => LinearRec2020::is_in_gamut(src, epsilon),
+            Self::Rec2100Pq => Rec2100Pq::is_in_gamut(src, epsilon),
+            Self::Rec2100Hlg => Rec2100Hlg::is_in_gamut(src, epsilon),
+            Self::Aces2065_1 => Aces2065_1::is_in_gamut(src, epsilon),
+            Self::AcesCg => AcesCg::is_in_gamut(src, epsilon),
+            Self::AcesCc => AcesCc::is_in_gamut(src, epsilon),
+            Self::AcesCct => AcesCct::is_in_gamut(src, epsilon),
+            Self::XyzD50 => XyzD50::is_in_gamut(src, epsilon),
+            Self::XyzD65 => XyzD65::is_in_gamut(src, epsilon),
+            Self::Hsl => Hsl::is_in_gamut(src, epsilon),
+            Self::Hwb => Hwb::is_in_gamut(src, epsilon),
+            Self::Hsv => Hsv::is_in_gamut(src, epsilon),
+            Self::Luv => Luv::is_in_gamut(src, epsilon),
+            Self::LchUv => LchUv::is_in_gamut(src, epsilon),
+            Self::Hsluv => Hsluv::is_in_gamut(src, epsilon),
+            Self::Hpluv => Hpluv::is_in_gamut(src, epsilon),
+            Self::Jzazbz => Jzazbz::is_in_gamut(src, epsilon),
+            Self::JzCzhz => JzCzhz::is_in_gamut(src, epsilon),
+            Self::IctcpPq => IctcpPq::is_in_gamut(src, epsilon),
+            Self::IctcpHlg => IctcpHlg::is_in_gamut(src, epsilon),
+            Self::Okhsl => Okhsl::is_in_gamut(src, epsilon),
+            Self::Okhsv => Okhsv::is_in_gamut(src, epsilon),
+            Self::Custom(id) => id.space().is_in_gamut(src, epsilon),
         }
     }
 }
@@ -0,0 +1,222 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Correlated color temperature estimation.
+
+use crate::Chromaticity;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The method used to estimate a [`ColorTemperature`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ColorTemperatureMethod {
+    /// The method of [Ohno (2013)][ohno], combining a triangular and a parabolic solution around
+    /// the closest point on the Planckian locus.
+    ///
+    /// This is accurate to within a few kelvin for chromaticities near the Planckian locus.
+    ///
+    /// [ohno]: https://doi.org/10.1080/15502724.2014.839020
+    #[default]
+    Ohno2013,
+    /// The cubic approximation of [McCamy (1992)][mccamy].
+    ///
+    /// This is fast, but only accurate near the Planckian locus from about 2000 K to 12500 K. The
+    /// Duv is measured to the point on the Planckian locus at the estimated temperature, clamped to
+    /// the range of 1000 K to 15000 K of the Planckian locus approximation.
+    ///
+    /// [mccamy]: https://doi.org/10.1002/col.5080170211
+    McCamy,
+}
+
+/// A correlated color temperature, with the distance of a chromaticity from the Planckian locus.
+///
+/// The correlated color temperature (CCT) is the temperature of the black-body radiator whose
+/// chromaticity is closest to a given chromaticity in the CIE 1960 UCS `uv` diagram. The distance
+/// to it is Duv, which is positive above the Planckian locus (towards green) and negative below it
+/// (towards magenta).
+///
+/// The Planckian locus is calculated with the rational approximation of [Krystek (1985)][krystek],
+/// limiting estimates to temperatures from 1000 K to 15000 K.
+///
+/// The color temperature of a color can be estimated with for example
+/// [`AlphaColor::color_temperature`], which determines the chromaticity through [`XyzD65`].
+///
+/// ```rust
+/// use color::{Chromaticity, ColorTemperature, ColorTemperatureMethod};
+///
+/// let d65 = ColorTemperature::from_chromaticity(Chromaticity::D65, ColorTemperatureMethod::Ohno2013);
+/// assert!((d65.cct - 6504.).abs() < 5.);
+/// assert!((d65.duv - 0.0032).abs() < 1e-4);
+/// ```
+///
+/// [krystek]: https://doi.org/10.1002/col.5080100109
+/// [`AlphaColor::color_temperature`]: crate::AlphaColor::color_temperature
+/// [`XyzD65`]: crate::XyzD65
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorTemperature {
+    /// The correlated color temperature, in kelvin.
+    pub cct: f32,
+    /// The signed distance from the Planckian locus in the CIE 1960 UCS `uv` diagram.
+    pub duv: f32,
+}
+
+impl ColorTemperature {
+    /// Estimate the correlated color temperature of `xy` chromaticities.
+    ///
+    /// The chromaticities of black are undefined, giving a NaN temperature.
+    #[must_use]
+    pub fn from_chromaticity(xy: Chromaticity, method: ColorTemperatureMethod) -> Self {
+        let [u, v_prime] = xy.to_uv();
+        // The CIE 1960 UCS `uv` coordinates.
+        let uv = [u, v_prime * (2. / 3.)];
+        match method {
+            ColorTemperatureMethod::Ohno2013 => ohno2013(uv),
+            ColorTemperatureMethod::McCamy => {
+                let n = (xy.x - 0.3320) / (0.1858 - xy.y);
+                let cct = ((449. * n + 3525.) * n + 6823.3) * n + 5520.33;
+                let [u_t, v_t] = planckian_uv(cct.clamp(1000., 15000.));
+                let duv = (uv[0] - u_t).hypot(uv[1] - v_t).copysign(uv[1] - v_t);
+                Self { cct, duv }
+            }
+        }
+    }
+}
+
+/// The CIE 1960 UCS `uv` chromaticities of a black-body radiator at the given temperature.
+///
+/// This uses the approximation of Krystek (1985), valid from 1000 K to 15000 K.
+fn planckian_uv(t: f32) -> [f32; 2] {
+    let u = (0.860_117_8 + 1.541_182_5e-4 * t + 1.286_412e-7 * t * t)
+        / (1. + 8.424_202e-4 * t + 7.081_452e-7 * t * t);
+    let v = (0.317_398_73 + 4.228_062_5e-5 * t + 4.204_817e-8 * t * t)
+        / (1. - 2.897_418e-5 * t + 1.614_560_5e-7 * t * t);
+    [u, v]
+}
+
+fn ohno2013([u, v]: [f32; 2]) -> ColorTemperature {
+    const MIN_MIRED: f32 = 1e6 / 15000.;
+    const MAX_MIRED: f32 = 1e6 / 1000.;
+    const POINTS: usize = 15;
+
+    let distance = |t: f32| {
+        let [u_t, v_t] = planckian_uv(t);
+        (u - u_t).hypot(v - v_t)
+    };
+
+    // Cascade through tables of the Planckian locus, evenly spaced in reciprocal temperature, each
+    // spanning the neighbors of the closest point of the previous table. This stops at a spacing
+    // of about 1%, which is the spacing of the table of Ohno's method.
+    let (mut lo, mut hi) = (MIN_MIRED, MAX_MIRED);
+    let mut points = [(0., 0.); 3];
+    loop {
+        let step = (hi - lo) / (POINTS - 1) as f32;
+        let mut closest = (0, f32::INFINITY);
+        for i in 0..POINTS {
+            let d = distance(1e6 / (lo + step * i as f32));
+            if d < closest.1 {
+                closest = (i, d);
+            }
+        }
+        let m = closest.0.clamp(1, POINTS - 2);
+        for (point, i) in points.iter_mut().zip(m - 1..=m + 1) {
+            let t = 1e6 / (lo + step * i as f32);
+            *point = (t, distance(t));
+        }
+        // Tables are not narrowed further once spacing is below 1%, and stop at the range
+        // boundaries. NaN chromaticities also end up here, as no point is closer than infinity.
+        if step < 0.01 * (lo + step * m as f32) || !closest.1.is_finite() {
+            break;
+        }
+        (lo, hi) = (lo + step * (m - 1) as f32, lo + step * (m + 1) as f32);
+    }
+    let [(t0, d0), (t1, d1), (t2, d2)] = points;
+
+    // The triangular solution.
+    let [u0, v0] = planckian_uv(t0);
+    let [u2, v2] = planckian_uv(t2);
+    let l = (u2 - u0).hypot(v2 - v0);
+    let x = (d0 * d0 - d2 * d2 + l * l) / (2. * l);
+    let cct = t0 + (t2 - t0) * (x / l);
+    let v_x = v0 + (v2 - v0) * (x / l);
+    let duv = (d0 * d0 - x * x).max(0.).sqrt().copysign(v - v_x);
+    if duv.abs() < 0.002 {
+        return ColorTemperature { cct, duv };
+    }
+
+    // The parabolic solution, with the temperatures centered on the closest point for precision.
+    let (s0, s2) = (t0 - t1, t2 - t1);
+    let denom = s0 * s2 * (s2 - s0);
+    let a = (s0 * (d2 - d1) + s2 * (d1 - d0)) / denom;
+    let b = -(s0 * s0 * (d2 - d1) + s2 * s2 * (d1 - d0)) / denom;
+    let c = d1;
+    let s = -b / (2. * a);
+    let duv = (a * s * s + b * s + c).copysign(v - planckian_uv(t1 + s)[1]);
+    // Ohno's correction of the bias of the parabolic solution.
+    let cct = (t1 + s) * 0.99991;
+    ColorTemperature { cct, duv }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{planckian_uv, ColorTemperature, ColorTemperatureMethod};
+    use crate::Chromaticity;
+
+    #[test]
+    fn illuminants() {
+        // The temperatures and Duv of the illuminants, as calculated from their spectra.
+        for (white, cct, duv) in [
+            (Chromaticity::A, 2856., 0.),
+            (Chromaticity::D50, 5003., 0.0033),
+            (Chromaticity::D65, 6504., 0.0032),
+        ] {
+            let ohno = ColorTemperature::from_chromaticity(white, ColorTemperatureMethod::Ohno2013);
+            assert!((ohno.cct - cct).abs() < 10., "{white:?}: {ohno:?}");
+            assert!((ohno.duv - duv).abs() < 2e-4, "{white:?}: {ohno:?}");
+
+            let mccamy = ColorTemperature::from_chromaticity(white, ColorTemperatureMethod::McCamy);
+            assert!((mccamy.cct - cct).abs() < 30., "{white:?}: {mccamy:?}");
+            assert!((mccamy.duv - duv).abs() < 5e-4, "{white:?}: {mccamy:?}");
+        }
+    }
+
+    #[test]
+    fn mccamy_out_of_range() {
+        // McCamy's approximation estimates about 29000 K here, beyond the range of the Planckian
+        // locus approximation, so the Duv is measured to its 15000 K end.
+        let xy = Chromaticity { x: 0.24, y: 0.24 };
+        let mccamy = ColorTemperature::from_chromaticity(xy, ColorTemperatureMethod::McCamy);
+        assert!(mccamy.cct > 15000., "{mccamy:?}");
+        let [u, v_prime] = xy.to_uv();
+        let [u_t, v_t] = planckian_uv(15000.);
+        let distance = (u - u_t).hypot(v_prime * (2. / 3.) - v_t);
+        assert!((mccamy.duv.abs() - distance).abs() < 1e-6, "{mccamy:?}");
+    }
+
+    #[test]
+    fn off_locus() {
+        // Chromaticities displaced perpendicularly from the Planckian locus, far enough to use
+        // the parabolic solution.
+        for t in [1500., 2700., 4000., 6500., 10000.] {
+            let [u0, v0] = planckian_uv(t - 1.);
+            let [u1, v1] = planckian_uv(t + 1.);
+            let [u, v] = planckian_uv(t);
+            let (du, dv) = (u1 - u0, v1 - v0);
+            let len = du.hypot(dv);
+            for duv in [-0.02, -0.005, 0.005, 0.02] {
+                // The temperature increases with decreasing `u`, so this normal points towards
+                // increasing `v`.
+                let uv = [u + dv / len * duv, v - du / len * duv];
+                let xy = Chromaticity::from_uv([uv[0], uv[1] * 1.5]);
+                let estimate =
+                    ColorTemperature::from_chromaticity(xy, ColorTemperatureMethod::Ohno2013);
+                assert!(
+                    (estimate.cct - t).abs() < t * 3e-3,
+                    "{t} {duv}: {estimate:?}"
+                );
+                assert!((estimate.duv - duv).abs() < 1e-4, "{t} {duv}: {estimate:?}");
+            }
+        }
+    }
+}
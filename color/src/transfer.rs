@@ -0,0 +1,397 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::colorspace::{
+    hlg_from_linear, hlg_to_linear, lin_to_srgb, pq_from_linear, pq_to_linear, srgb_to_lin, PQ_M2,
+};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// A transfer function, mapping between encoded and linear-light component values.
+///
+/// Transfer functions can be applied independently of any color space, for example to decode
+/// texture data, and are used to define RGB color spaces through
+/// [`RgbColorSpace`](crate::RgbColorSpace).
+///
+/// The transfer functions passing through the origin are odd-symmetric: negative component values
+/// are mapped by mirroring the function around the origin, following CSS Color 4's treatment of
+/// extended-range values. The logarithmic ACES encodings define their own handling of values
+/// around and below zero.
+///
+/// # Example
+///
+/// ```rust
+/// use color::TransferFunction;
+///
+/// let tf = TransferFunction::Srgb;
+/// let linear = tf.to_linear(0.5);
+/// assert!((linear - 0.214_041_14).abs() < 1e-6);
+/// assert!((tf.from_linear(linear) - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TransferFunction {
+    /// The identity transfer function, for linear-light color spaces.
+    Linear,
+
+    /// A pure power function with the given exponent.
+    ///
+    /// The exponent is applied when decoding to linear light, so for example `Gamma(2.2)`
+    /// linearizes values by raising them to the power 2.2. This is the transfer function of
+    /// [Adobe RGB](crate::A98Rgb) with an exponent of `563 / 256`.
+    Gamma(f32),
+
+    /// An ICC parametric curve.
+    ///
+    /// See [`ParametricCurve`] for its definition.
+    Parametric(ParametricCurve),
+
+    /// The piecewise sRGB transfer function, as defined in IEC 61966-2-1.
+    ///
+    /// This is also the transfer function of [Display P3](crate::DisplayP3). It is equivalent to
+    /// [`ParametricCurve::SRGB`].
+    Srgb,
+
+    /// The transfer function of [Rec. 2020](crate::Rec2020), as defined in ITU-R BT.2020.
+    ///
+    /// This is the opto-electronic transfer function of ITU-R BT.709, with its constants given to
+    /// higher precision. It is nearly equal to [`ParametricCurve::REC709`].
+    Rec2020,
+
+    /// The perceptual quantizer of SMPTE ST 2084, as used in [Rec. 2100 PQ](crate::Rec2100Pq).
+    ///
+    /// The linear-light values are luminance relative to 10,000 cd/m², so `1.0` encodes the
+    /// maximum luminance of 10,000 cd/m².
+    Pq,
+
+    /// The hybrid log-gamma opto-electronic transfer function of ITU-R BT.2100, as used in
+    /// [Rec. 2100 HLG](crate::Rec2100Hlg).
+    ///
+    /// The linear-light values are relative scene light, with `1.0` encoded as the peak signal
+    /// `1.0`.
+    Hlg,
+
+    /// The logarithmic encoding of ACEScc, as defined in Academy S-2014-003.
+    ///
+    /// Linear-light values below zero are encoded as the value of zero. Decoded values are
+    /// limited to the largest half-precision float, 65504.
+    AcesCc,
+
+    /// The logarithmic encoding of ACEScct, as defined in Academy S-2016-001.
+    ///
+    /// This is similar to [`TransferFunction::AcesCc`], but with a linear toe near black, which
+    /// also extends below zero. Decoded values are limited to the largest half-precision float,
+    /// 65504.
+    AcesCct,
+}
+
+impl TransferFunction {
+    /// Decode an encoded component value to linear light.
+    #[must_use]
+    pub fn to_linear(self, x: f32) -> f32 {
+        match self {
+            Self::Linear => x,
+            Self::Gamma(gamma) => x.abs().powf(gamma).copysign(x),
+            Self::Parametric(curve) => curve.decode(x.abs()).copysign(x),
+            Self::Srgb => srgb_to_lin(x),
+            Self::Rec2020 => rec2020_to_linear(x),
+            Self::Pq => pq_to_linear(x, PQ_M2),
+            Self::Hlg => hlg_to_linear(x),
+            Self::AcesCc => acescc_to_linear(x),
+            Self::AcesCct => acescct_to_linear(x),
+        }
+    }
+
+    /// Encode a linear-light component value.
+    ///
+    /// This is the inverse of [`TransferFunction::to_linear`].
+    #[must_use]
+    pub fn from_linear(self, x: f32) -> f32 {
+        match self {
+            Self::Linear => x,
+            Self::Gamma(gamma) => x.abs().powf(1. / gamma).copysign(x),
+            Self::Parametric(curve) => curve.encode(x.abs()).copysign(x),
+            Self::Srgb => lin_to_srgb(x),
+            Self::Rec2020 => rec2020_from_linear(x),
+            Self::Pq => pq_from_linear(x, PQ_M2),
+            Self::Hlg => hlg_from_linear(x),
+            Self::AcesCc => acescc_from_linear(x),
+            Self::AcesCct => acescct_from_linear(x),
+        }
+    }
+
+    /// Whether this is the identity transfer function.
+    #[must_use]
+    pub const fn is_linear(self) -> bool {
+        matches!(self, Self::Linear)
+    }
+}
+
+/// An ICC parametric curve, decoding an encoded value `X` to a linear-light value `Y`.
+///
+/// The curve is given by the parameters `g`, `a`, `b`, `c`, `d`, `e` and `f` as
+///
+/// ```text
+/// Y = (a * X + b)^g + e    if X >= d,
+/// Y = c * X + f            if X < d.
+/// ```
+///
+/// This is the most general function type 4 of the `parametricCurveType` of ICC.1:2022 § 10.18.
+/// The other function types are special cases of it, and can be constructed with
+/// [`ParametricCurve::type0`] through [`ParametricCurve::type3`].
+///
+/// The curve is evaluated on the magnitude of the value, with the sign of the value reapplied
+/// afterwards, so negative values are mirrored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParametricCurve {
+    /// The exponent of the power segment.
+    pub g: f32,
+    /// The scale of the encoded value in the power segment.
+    pub a: f32,
+    /// The offset of the encoded value in the power segment.
+    pub b: f32,
+    /// The slope of the linear segment.
+    pub c: f32,
+    /// The encoded value where the power segment starts.
+    pub d: f32,
+    /// The offset of the power segment.
+    pub e: f32,
+    /// The offset of the linear segment.
+    pub f: f32,
+}
+
+impl ParametricCurve {
+    /// The sRGB transfer function.
+    ///
+    /// This is equivalent to [`TransferFunction::Srgb`].
+    pub const SRGB: Self = Self::type3(2.4, 1. / 1.055, 0.055 / 1.055, 1. / 12.92, 0.04045);
+
+    /// The transfer function of ITU-R BT.709, inverting its opto-electronic transfer function.
+    pub const REC709: Self = Self::type3(1. / 0.45, 1. / 1.099, 0.099 / 1.099, 1. / 4.5, 0.081);
+
+    /// The transfer function of [ProPhoto RGB](crate::ProphotoRgb).
+    pub const PROPHOTO_RGB: Self = Self::type3(1.8, 1., 0., 1. / 16., 16. / 512.);
+
+    /// Function type 0: `Y = X^g`.
+    pub const fn type0(g: f32) -> Self {
+        Self::type4(g, 1., 0., 0., 0., 0., 0.)
+    }
+
+    /// Function type 1: `Y = (a * X + b)^g` for `X >= -b / a`, and `Y = 0` otherwise.
+    pub const fn type1(g: f32, a: f32, b: f32) -> Self {
+        Self::type4(g, a, b, 0., -b / a, 0., 0.)
+    }
+
+    /// Function type 2: `Y = (a * X + b)^g + c` for `X >= -b / a`, and `Y = c` otherwise.
+    pub const fn type2(g: f32, a: f32, b: f32, c: f32) -> Self {
+        Self::type4(g, a, b, 0., -b / a, c, c)
+    }
+
+    /// Function type 3: `Y = (a * X + b)^g` for `X >= d`, and `Y = c * X` otherwise.
+    pub const fn type3(g: f32, a: f32, b: f32, c: f32, d: f32) -> Self {
+        Self::type4(g, a, b, c, d, 0., 0.)
+    }
+
+    /// Function type 4: `Y = (a * X + b)^g + e` for `X >= d`, and `Y = c * X + f` otherwise.
+    #[expect(clippy::many_single_char_names, reason = "named as in the ICC spec")]
+    pub const fn type4(g: f32, a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Self {
+            g,
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+        }
+    }
+
+    /// Decode a non-negative encoded value.
+    fn decode(self, x: f32) -> f32 {
+        if x >= self.d {
+            (self.a * x + self.b).max(0.).powf(self.g) + self.e
+        } else {
+            self.c * x + self.f
+        }
+    }
+
+    /// Encode a non-negative linear-light value.
+    fn encode(self, y: f32) -> f32 {
+        // The linear segment is evaluated at the start of the power segment to find the segment
+        // `y` is in. This assumes the curve is continuous, as are the curves in practical use.
+        if self.c == 0. {
+            // The linear segment is constant, so all values it produces map to its end.
+            if y <= self.f {
+                return self.d;
+            }
+        } else if y < self.c * self.d + self.f {
+            return (y - self.f) / self.c;
+        }
+        ((y - self.e).max(0.).powf(1. / self.g) - self.b) / self.a
+    }
+}
+
+/// The parameters of the Rec. 2020 transfer function, truncated to f32 precision.
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_97;
+
+fn rec2020_to_linear(x: f32) -> f32 {
+    if x.abs() < REC2020_BETA * 4.5 {
+        x * (1. / 4.5)
+    } else {
+        ((x.abs() + (REC2020_ALPHA - 1.)) / REC2020_ALPHA)
+            .powf(1. / 0.45)
+            .copysign(x)
+    }
+}
+
+fn rec2020_from_linear(x: f32) -> f32 {
+    if x.abs() < REC2020_BETA {
+        x * 4.5
+    } else {
+        (REC2020_ALPHA * x.abs().powf(0.45) - (REC2020_ALPHA - 1.)).copysign(x)
+    }
+}
+
+/// The largest value representable as a half-precision float, which limits the ACES log encodings.
+const ACES_HALF_MAX: f32 = 65504.;
+
+/// The log encoding shared by ACEScc and ACEScct above their toes.
+fn aces_log_from_linear(x: f32) -> f32 {
+    (x.log2() + 9.72) / 17.52
+}
+
+/// The inverse of [`aces_log_from_linear`], limited to the half-precision range.
+fn aces_log_to_linear(x: f32) -> f32 {
+    // (log2(65504) + 9.72) / 17.52
+    const LOG_HALF_MAX: f32 = 1.467_996_3;
+    if x < LOG_HALF_MAX {
+        (x * 17.52 - 9.72).exp2()
+    } else {
+        ACES_HALF_MAX
+    }
+}
+
+fn acescc_from_linear(x: f32) -> f32 {
+    if x <= 0. {
+        // (log2(2^-16) + 9.72) / 17.52
+        -0.358_447_5
+    } else if x < 1. / 32768. {
+        aces_log_from_linear(1. / 65536. + x * 0.5)
+    } else {
+        aces_log_from_linear(x)
+    }
+}
+
+fn acescc_to_linear(x: f32) -> f32 {
+    // (9.72 - 15) / 17.52
+    const LOG_TOE: f32 = -0.301_369_87;
+    if x <= LOG_TOE {
+        ((x * 17.52 - 9.72).exp2() - 1. / 65536.) * 2.
+    } else {
+        aces_log_to_linear(x)
+    }
+}
+
+/// The parameters of the linear toe of ACEScct.
+const ACESCCT_X_BREAK: f32 = 0.007_812_5;
+const ACESCCT_Y_BREAK: f32 = 0.155_251_14;
+const ACESCCT_A: f32 = 10.540_238;
+const ACESCCT_B: f32 = 0.072_905_53;
+
+fn acescct_from_linear(x: f32) -> f32 {
+    if x <= ACESCCT_X_BREAK {
+        ACESCCT_A * x + ACESCCT_B
+    } else {
+        aces_log_from_linear(x)
+    }
+}
+
+fn acescct_to_linear(x: f32) -> f32 {
+    if x <= ACESCCT_Y_BREAK {
+        (x - ACESCCT_B) / ACESCCT_A
+    } else {
+        aces_log_to_linear(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParametricCurve, TransferFunction};
+
+    const ALL: [TransferFunction; 12] = [
+        TransferFunction::Linear,
+        TransferFunction::Gamma(2.2),
+        TransferFunction::Parametric(ParametricCurve::SRGB),
+        TransferFunction::Parametric(ParametricCurve::REC709),
+        TransferFunction::Parametric(ParametricCurve::PROPHOTO_RGB),
+        TransferFunction::Parametric(ParametricCurve::type2(2.4, 0.9, 0.1, 0.05)),
+        TransferFunction::Srgb,
+        TransferFunction::Rec2020,
+        TransferFunction::Pq,
+        TransferFunction::Hlg,
+        TransferFunction::AcesCc,
+        TransferFunction::AcesCct,
+    ];
+
+    #[test]
+    fn roundtrip() {
+        for tf in ALL {
+            for x in [-1.2, -0.5, -0.001, 0., 0.002, 0.04, 0.5, 0.9, 1.] {
+                if matches!(tf, TransferFunction::AcesCc) && x <= -0.358_447_5 {
+                    // The encoding of values at and below zero is not invertible.
+                    continue;
+                }
+                let y = tf.from_linear(tf.to_linear(x));
+                assert!((x - y).abs() < 1e-4, "{tf:?} roundtrip of {x} gave {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn gamma_is_mirrored() {
+        let tf = TransferFunction::Gamma(2.6);
+        assert_eq!(tf.to_linear(-0.5), -tf.to_linear(0.5));
+        assert_eq!(tf.from_linear(-0.5), -tf.from_linear(0.5));
+    }
+
+    #[test]
+    fn parametric_matches_named() {
+        for x in [-0.5, 0.001, 0.01, 0.04, 0.05, 0.3, 0.8, 1.] {
+            let srgb = TransferFunction::Srgb.to_linear(x);
+            let parametric = TransferFunction::Parametric(ParametricCurve::SRGB).to_linear(x);
+            assert!(
+                (srgb - parametric).abs() < 1e-6,
+                "{x}: {srgb} != {parametric}"
+            );
+
+            let rec2020 = TransferFunction::Rec2020.to_linear(x);
+            let rec709 = TransferFunction::Parametric(ParametricCurve::REC709).to_linear(x);
+            assert!(
+                (rec2020 - rec709).abs() < 1e-3,
+                "{x}: {rec2020} != {rec709}"
+            );
+        }
+    }
+
+    #[test]
+    fn reference_values() {
+        // 100 cd/m² in PQ.
+        let pq = TransferFunction::Pq.from_linear(0.01);
+        assert!((pq - 0.508_078_4).abs() < 1e-5, "{pq}");
+
+        // Linear 0.18 mid gray in the ACES log encodings.
+        let cc = TransferFunction::AcesCc.from_linear(0.18);
+        assert!((cc - 0.413_588_4).abs() < 1e-5, "{cc}");
+        let cct = TransferFunction::AcesCct.from_linear(0.18);
+        assert!((cct - 0.413_588_4).abs() < 1e-5, "{cct}");
+        assert!((TransferFunction::AcesCct.from_linear(0.) - 0.072_905_53).abs() < 1e-7);
+
+        // Type 1 curves are zero below `-b / a`, which is inverted to `-b / a`.
+        let curve = ParametricCurve::type1(2., 2., -0.5);
+        let tf = TransferFunction::Parametric(curve);
+        assert_eq!(tf.to_linear(0.2), 0.);
+        assert_eq!(tf.from_linear(0.), 0.25);
+    }
+}
@@ -305,6 +305,153 @@ pub(crate) const COLORS: [[u8; 4]; 142] = [
     [154, 205, 50, 255],
 ];
 
+/// The [`Oklab`](crate::Oklab) components of the named X11 colors, in the same order as
+/// [`NAMES`].
+pub(crate) const OKLAB: [[f32; 3]; 142] = [
+    [0.6927437, 0.051944155, 0.017446108],
+    [0.5578046, -0.13460505, 0.101826444],
+    [0.9773007, -0.00353115, 0.037089836],
+    [0.80779624, 3.003376e-08, 1.9314918e-08],
+    [0.42091367, 0.1647043, -0.10147178],
+    [0.8332929, 0.036385544, -0.024576483],
+    [0.9466916, 0.007930047, 0.030054277],
+    [0.86680585, -0.19017531, 0.0810971],
+    [0.52080655, 1.936352e-08, 1.2452813e-08],
+    [0.59948385, -0.08137674, 0.110690005],
+    [0.9088334, 0.0074599143, 0.06104451],
+    [0.7868021, -0.11386517, -0.023286551],
+    [0.7508648, -0.065076895, 0.046025872],
+    [0.8045399, 0.022220602, 0.0746197],
+    [0.70167387, 0.27456626, -0.16915606],
+    [0.9778096, -0.012258978, 0.05691115],
+    [0.76747406, -0.024571218, 0.09491046],
+    [0.99200743, -0.011840986, 0.038462892],
+    [0.6044729, 0.0518099, -0.18684],
+    [0.62557954, 0.15330534, -0.132207],
+    [0.4402718, 0.08817676, -0.13386434],
+    [0., 0., 0.],
+    [0.8749299, -0.20581256, 0.11397102],
+    [0.3766921, 0.13488878, 0.075491525],
+    [0.691201, -0.11281918, -0.017791484],
+    [0.9679827, -0.07136904, 0.19856974],
+    [0.5998708, 2.230312e-08, 1.4343289e-08],
+    [0.78618664, 0.016927663, 0.061535202],
+    [0.8944904, 3.3257038e-08, 2.138783e-08],
+    [0.8902625, -0.19050692, 0.18392421],
+    [0.8867711, -0.016925143, 0.18139815],
+    [0.7021318, 0.15488695, -0.09415189],
+    [0.6279554, 0.22486304, 0.1258463],
+    [0.98893696, 0.005097004, 0.0015823954],
+    [0.91304886, -0.15012003, 0.17879698],
+    [0.74187446, -0.18220712, 0.1381284],
+    [0.90539926, -0.14944388, -0.039398175],
+    [0.73500234, 0.13369869, 0.07126991],
+    [0.52648216, 0.08196151, 0.08083751],
+    [0.5435672, 0.04587725, -0.16496575],
+    [0.91499454, -0.12798627, 0.02489662],
+    [0.98895067, -0.01504102, -0.0045705163],
+    [0.47078353, 0.07080873, 0.08696012],
+    [0.8206188, -0.051824182, -0.079055876],
+    [0.674622, -0.021288995, -0.13974452],
+    [0.75054425, 0.09416566, 0.15236416],
+    [0.65200555, -0.054933235, -0.18200986],
+    [0.28811887, 0.0069275885, -0.14345874],
+    [0.62691426, 0.07093669, -0.14121489],
+    [1., 3.7179873e-08, 2.391063e-08],
+    [0.4143428, 0.03449148, -0.11997692],
+    [0.7719288, -0.1259335, -0.037646174],
+    [0.9069087, -0.060753144, -0.017526563],
+    [0.96023846, 0.0065292367, 0.01585795],
+    [0.59250414, -0.011410067, -0.028742738],
+    [0.8473878, 0.08471584, 0.013548868],
+    [0.9778582, -0.030687917, -0.009171618],
+    [0.67819256, 0.05728327, 0.10856276],
+    [0.9635736, -0.009584797, 0.03135183],
+    [0.61544085, 0.1334389, 0.05453257],
+    [0.9164022, 0.01588152, 0.071257755],
+    [0.51975185, -0.14030229, 0.10767589],
+    [0.8562331, -0.03767937, -0.031231308],
+    [0.77668625, -0.1077623, 0.021289887],
+    [0.9035428, -0.13154516, 0.09527283],
+    [0.81481725, -0.057156235, -0.058680054],
+    [0.51490974, 0.16689837, -0.20023905],
+    [0.654935, 0.26096502, -0.013928857],
+    [0.57652205, -0.095159896, -0.025087183],
+    [0.91125286, 0.026585301, 0.053788193],
+    [0.27114984, -0.01947, -0.18687664],
+    [0.4955208, -0.052899312, 0.07231539],
+    [0.93090236, 0.0073649646, -0.025915224],
+    [0.9750065, -0.015435908, 0.049487017],
+    [0.9296221, 0.010123998, 0.06677445],
+    [0.75535, -0.09521557, -0.12030098],
+    [0.4967711, 0.16036719, 0.081059314],
+    [0.98111874, 0.0025899964, -0.008898677],
+    [0.9848419, -0.020746147, 0.014322241],
+    [0.8223342, -0.1302285, -0.011597318],
+    [0.54111457, 0.15060078, -0.1701666],
+    [0.78328353, 0.089908056, -0.05940839],
+    [0.8668631, 3.222986e-08, 2.0727242e-08],
+    [0.6343984, 0.09907392, 0.11919316],
+    [0.81362313, -0.011055356, -0.04135574],
+    [0.9328562, 0.016026154, 0.048885453],
+    [0.38345328, -0.027533986, -0.26427624],
+    [0.97514284, -0.0055005113, -0.011404182],
+    [0.72464097, 0.1285654, 0.04942582],
+    [0.61902016, -0.011989209, -0.03020635],
+    [0.43601784, -0.11769906, 0.09032889],
+    [0.991172, -0.011939167, 0.0032409742],
+    [0.73511285, 0.12822507, 0.10853774],
+    [0.44679788, 0.17483284, -0.107711814],
+    [0.9400117, 0.027195763, 0.0128444545],
+    [0.97015077, 3.6070084e-08, 2.3196916e-08],
+    [0.79375505, 0.0921471, 0.08421497],
+    [0.55336696, 0.21807167, -0.03968207],
+    [0.6962193, 0.1652304, 0.10454088],
+    [0.33898208, 0.09416151, -0.15255125],
+    [0.48061255, 0.14402947, 0.068890296],
+    [0.88175315, -0.18977888, 0.18217744],
+    [0.65768147, -0.06172229, -0.020410119],
+    [0.7618986, 0.15646528, -0.10079779],
+    [0.70167387, 0.27456626, -0.16915606],
+    [0.28782427, -0.020667315, -0.19836867],
+    [0.7926884, 0.056611218, 0.16138454],
+    [0.68404204, -0.13052304, 0.060866628],
+    [0.86643964, -0.23388751, 0.17949846],
+    [0.45201373, -0.03245699, -0.31152815],
+    [0.3998569, 0.1431838, 0.08013389],
+    [0.53376496, 0.13031806, -0.21370523],
+    [0.95807993, 0.006622402, 0.037681166],
+    [0.94843936, 0.009296519, 0.043962285],
+    [0.5685264, -0.10754651, 0.050266035],
+    [0.5880009, -0.04081705, -0.09056585],
+    [0.97601813, 0.007638667, 0.012033091],
+    [0.96833485, 0.017342936, -0.0014863587],
+    [0.98623276, 0.0013425532, 0.01415645],
+    [0.8680033, -0.12618394, 0.09138163],
+    [0.75073624, 0.08360268, 0.068656795],
+    [0., 0., 0.],
+    [0.5711893, 0.20843764, 0.07622524],
+    [0.7282974, 0.195155, -0.02744563],
+    [0.40296292, -0.03630596, -0.010244616],
+    [0.67792284, 0.1382069, 0.0016424874],
+    [0.9723415, 0.002528705, 0.021410495],
+    [0.73480856, 2.732009e-08, 1.7569736e-08],
+    [0.5806646, -0.042812202, 0.11911619],
+    [0.9210476, -0.018201165, 0.07770316],
+    [0.8750832, -0.04522601, -0.021795861],
+    [0.8677384, 0.07298039, 0.0090714395],
+    [0.6601995, 0.18694867, 0.13286985],
+    [0.9959763, -0.005653327, 0.018783504],
+    [0.5431226, -0.089647025, -0.023633815],
+    [0.90539926, -0.14944388, -0.039398175],
+    [0.65206957, 0.01937694, 0.13077162],
+    [0.5598482, -0.011820596, -0.18786201],
+    [0.78399736, 0.06401245, 0.10958704],
+    [0.91348904, -0.024851864, 0.10912825],
+    [0.7515723, 0.015389085, 0.14612558],
+    [0.78485227, -0.10964242, 0.1474421],
+];
+
 /// Hash the 32 bit key into a value less than `n`, adding salt.
 ///
 /// This is basically the weakest hash we can get away with that
@@ -0,0 +1,327 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Y'CbCr encoding of non-linear RGB colors.
+
+use crate::{ColorSpace, OpaqueColor, TransferFunction};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The matrix coefficients deriving Y'CbCr from non-linear R'G'B' components.
+///
+/// Each matrix belongs with the R'G'B' components of a color space: [`YcbcrMatrix::Bt709`] with
+/// [`Rec709`](crate::Rec709) (or, commonly, [`Srgb`](crate::Srgb)), and [`YcbcrMatrix::Bt2020`]
+/// and [`YcbcrMatrix::Bt2020ConstantLuminance`] with [`Rec2020`](crate::Rec2020).
+/// [`YcbcrMatrix::Bt601`] is used with standard-definition video, which is nowadays usually
+/// displayed as Rec. 709 or sRGB.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum YcbcrMatrix {
+    /// The matrix of ITU-R BT.601, with the luma coefficients `Kr = 0.299` and `Kb = 0.114`.
+    Bt601,
+    /// The matrix of ITU-R BT.709, with the luma coefficients `Kr = 0.2126` and `Kb = 0.0722`.
+    Bt709,
+    /// The non-constant luminance matrix of ITU-R BT.2020, with the luma coefficients
+    /// `Kr = 0.2627` and `Kb = 0.0593`.
+    Bt2020,
+    /// The constant luminance encoding of ITU-R BT.2020.
+    ///
+    /// Unlike the other matrices, this derives luma from linear-light components, so the R'G'B'
+    /// components must be encoded with the Rec. 2020 transfer function.
+    Bt2020ConstantLuminance,
+}
+
+impl YcbcrMatrix {
+    /// The luma coefficients `Kr` and `Kb`.
+    const fn coefficients(self) -> (f32, f32) {
+        match self {
+            Self::Bt601 => (0.299, 0.114),
+            Self::Bt709 => (0.2126, 0.0722),
+            Self::Bt2020 | Self::Bt2020ConstantLuminance => (0.2627, 0.0593),
+        }
+    }
+
+    /// Convert non-linear `[r, g, b]` components to Y'CbCr.
+    ///
+    /// The resulting components are `[y, cb, cr]`, with `y` in `[0, 1]` and `cb` and `cr` in
+    /// `[-0.5, 0.5]` for components in `[0, 1]`.
+    #[must_use]
+    pub fn from_rgb(self, [r, g, b]: [f32; 3]) -> [f32; 3] {
+        let (kr, kb) = self.coefficients();
+        if self == Self::Bt2020ConstantLuminance {
+            let tf = TransferFunction::Rec2020;
+            let yc = kr * tf.to_linear(r) + (1. - kr - kb) * tf.to_linear(g) + kb * tf.to_linear(b);
+            let y = tf.from_linear(yc);
+            // The divisors of BT.2020 table 4, depending on the sign of the difference.
+            let cb = if b - y <= 0. {
+                (b - y) / 1.9404
+            } else {
+                (b - y) / 1.5816
+            };
+            let cr = if r - y <= 0. {
+                (r - y) / 1.7184
+            } else {
+                (r - y) / 0.9936
+            };
+            return [y, cb, cr];
+        }
+        let y = kr * r + (1. - kr - kb) * g + kb * b;
+        [y, (b - y) / (2. * (1. - kb)), (r - y) / (2. * (1. - kr))]
+    }
+
+    /// Convert `[y, cb, cr]` components to non-linear R'G'B'.
+    ///
+    /// This is the inverse of [`YcbcrMatrix::from_rgb`].
+    #[must_use]
+    pub fn to_rgb(self, [y, cb, cr]: [f32; 3]) -> [f32; 3] {
+        let (kr, kb) = self.coefficients();
+        if self == Self::Bt2020ConstantLuminance {
+            let tf = TransferFunction::Rec2020;
+            let b = y + cb * if cb <= 0. { 1.9404 } else { 1.5816 };
+            let r = y + cr * if cr <= 0. { 1.7184 } else { 0.9936 };
+            let g_lin =
+                (tf.to_linear(y) - kr * tf.to_linear(r) - kb * tf.to_linear(b)) / (1. - kr - kb);
+            return [r, tf.from_linear(g_lin), b];
+        }
+        let r = y + 2. * (1. - kr) * cr;
+        let b = y + 2. * (1. - kb) * cb;
+        let g = (y - kr * r - kb * b) / (1. - kr - kb);
+        [r, g, b]
+    }
+}
+
+/// The quantization range of Y'CbCr components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum YcbcrRange {
+    /// The full range of the code values is used, as in JPEG.
+    ///
+    /// Y' spans `[0, 2^n - 1]`, and Cb and Cr are centered at `2^(n - 1)`.
+    Full,
+    /// The limited, or studio, range of ITU-R BT.601, BT.709 and BT.2020, as used by most video.
+    ///
+    /// At 8 bits, Y' spans `[16, 235]` and Cb and Cr span `[16, 240]`, scaled by `2^(n - 8)` at
+    /// higher bit depths. Code values outside of these ranges are headroom and footroom.
+    Limited,
+}
+
+/// The format of quantized Y'CbCr components.
+///
+/// This combines the [matrix](YcbcrMatrix), [range](YcbcrRange) and bit depth of Y'CbCr
+/// components, converting them from and to non-linear RGB colors.
+///
+/// # Example
+///
+/// ```rust
+/// use color::{OpaqueColor, Rec709, Srgb, YcbcrFormat, YcbcrMatrix, YcbcrRange};
+///
+/// let format = YcbcrFormat::new(YcbcrMatrix::Bt709, YcbcrRange::Limited, 8);
+///
+/// // Pure red in limited-range 8-bit BT.709.
+/// let red = format.to_rgb::<Rec709>([63, 102, 240]);
+/// assert!(red.difference(OpaqueColor::new([1., 0., 0.])) < 0.01);
+///
+/// assert_eq!(format.from_rgb(red), [63, 102, 240]);
+///
+/// // Convert the video color for display.
+/// let red_srgb = red.convert::<Srgb>();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct YcbcrFormat {
+    matrix: YcbcrMatrix,
+    range: YcbcrRange,
+    bit_depth: u8,
+}
+
+impl YcbcrFormat {
+    /// Create a new Y'CbCr format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_depth` is not between 8 and 16 inclusive.
+    #[must_use]
+    pub const fn new(matrix: YcbcrMatrix, range: YcbcrRange, bit_depth: u8) -> Self {
+        assert!(
+            8 <= bit_depth && bit_depth <= 16,
+            "the bit depth must be between 8 and 16"
+        );
+        Self {
+            matrix,
+            range,
+            bit_depth,
+        }
+    }
+
+    /// The matrix coefficients.
+    #[must_use]
+    pub const fn matrix(self) -> YcbcrMatrix {
+        self.matrix
+    }
+
+    /// The quantization range.
+    #[must_use]
+    pub const fn range(self) -> YcbcrRange {
+        self.range
+    }
+
+    /// The number of bits per component, between 8 and 16 inclusive.
+    ///
+    /// Common values are 8, 10 and 12.
+    #[must_use]
+    pub const fn bit_depth(self) -> u8 {
+        self.bit_depth
+    }
+
+    /// The scale and offset of luma and chroma code values, as `[y_scale, y_offset, c_scale,
+    /// c_offset]`.
+    fn quantization(self) -> [f32; 4] {
+        let n = i32::from(self.bit_depth);
+        let half = (1_u32 << (n - 1)) as f32;
+        match self.range {
+            YcbcrRange::Full => {
+                let max = (1_u32 << n) as f32 - 1.;
+                [max, 0., max, half]
+            }
+            YcbcrRange::Limited => {
+                let scale = (1_u32 << (n - 8)) as f32;
+                [219. * scale, 16. * scale, 224. * scale, half]
+            }
+        }
+    }
+
+    /// Quantize `[y, cb, cr]` components as returned by [`YcbcrMatrix::from_rgb`] to code values.
+    ///
+    /// The code values are rounded and clamped to the range representable with the bit depth.
+    #[must_use]
+    pub fn quantize(self, [y, cb, cr]: [f32; 3]) -> [u16; 3] {
+        let [y_scale, y_offset, c_scale, c_offset] = self.quantization();
+        let max = ((1_u32 << self.bit_depth) - 1) as f32;
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "the value is rounded and clamped to the range of the bit depth"
+        )]
+        let quantize = |x: f32| x.round().clamp(0., max) as u16;
+        [
+            quantize(y * y_scale + y_offset),
+            quantize(cb * c_scale + c_offset),
+            quantize(cr * c_scale + c_offset),
+        ]
+    }
+
+    /// Convert code values to `[y, cb, cr]` components as taken by [`YcbcrMatrix::to_rgb`].
+    ///
+    /// This is the inverse of [`YcbcrFormat::quantize`]. Code values in the headroom and footroom
+    /// of limited range are preserved as components outside of the nominal range.
+    #[must_use]
+    pub fn dequantize(self, [y, cb, cr]: [u16; 3]) -> [f32; 3] {
+        let [y_scale, y_offset, c_scale, c_offset] = self.quantization();
+        [
+            (f32::from(y) - y_offset) / y_scale,
+            (f32::from(cb) - c_offset) / c_scale,
+            (f32::from(cr) - c_offset) / c_scale,
+        ]
+    }
+
+    /// Encode a color as Y'CbCr code values.
+    ///
+    /// The components of the color are used as the R'G'B' components of the matrix, so the color
+    /// should be in a non-linear RGB color space matching the matrix, such as
+    /// [`Rec709`](crate::Rec709) or [`Rec2020`](crate::Rec2020). Colors can be converted to it
+    /// first with [`OpaqueColor::convert`].
+    #[must_use]
+    pub fn from_rgb<CS: ColorSpace>(self, color: OpaqueColor<CS>) -> [u16; 3] {
+        self.quantize(self.matrix.from_rgb(color.components))
+    }
+
+    /// Decode Y'CbCr code values to a color.
+    ///
+    /// The R'G'B' components of the matrix are used as the components of the color, so `CS`
+    /// should be a non-linear RGB color space matching the matrix, such as
+    /// [`Rec709`](crate::Rec709) or [`Rec2020`](crate::Rec2020).
+    #[must_use]
+    pub fn to_rgb<CS: ColorSpace>(self, ycbcr: [u16; 3]) -> OpaqueColor<CS> {
+        OpaqueColor::new(self.matrix.to_rgb(self.dequantize(ycbcr)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{YcbcrFormat, YcbcrMatrix, YcbcrRange};
+    use crate::{OpaqueColor, Rec2020, Rec709};
+
+    const MATRICES: [YcbcrMatrix; 4] = [
+        YcbcrMatrix::Bt601,
+        YcbcrMatrix::Bt709,
+        YcbcrMatrix::Bt2020,
+        YcbcrMatrix::Bt2020ConstantLuminance,
+    ];
+
+    #[test]
+    fn roundtrip() {
+        for matrix in MATRICES {
+            for rgb in [
+                [0., 0., 0.],
+                [1., 1., 1.],
+                [1., 0., 0.],
+                [0., 1., 0.],
+                [0., 0., 1.],
+                [0.2, 0.7, 0.4],
+                [0.9, 0.1, 0.6],
+            ] {
+                let ycbcr = matrix.from_rgb(rgb);
+                assert!((0. ..=1.).contains(&ycbcr[0]), "{matrix:?} {ycbcr:?}");
+                // The divisors of the constant luminance encoding are rounded in the
+                // specification, slightly overshooting the chroma range.
+                assert!(
+                    ycbcr[1].abs() <= 0.501 && ycbcr[2].abs() <= 0.501,
+                    "{ycbcr:?}"
+                );
+                let roundtripped = matrix.to_rgb(ycbcr);
+                for (a, b) in rgb.into_iter().zip(roundtripped) {
+                    assert!((a - b).abs() < 1e-5, "{matrix:?} {rgb:?} {roundtripped:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reference_values() {
+        let bt601 = YcbcrFormat::new(YcbcrMatrix::Bt601, YcbcrRange::Limited, 8);
+        let bt709 = YcbcrFormat::new(YcbcrMatrix::Bt709, YcbcrRange::Limited, 8);
+        let red = OpaqueColor::<Rec709>::new([1., 0., 0.]);
+        assert_eq!(bt601.from_rgb(red), [81, 90, 240]);
+        assert_eq!(bt709.from_rgb(red), [63, 102, 240]);
+
+        let white = OpaqueColor::<Rec2020>::new([1., 1., 1.]);
+        let black = OpaqueColor::<Rec2020>::new([0., 0., 0.]);
+        for matrix in MATRICES {
+            let limited = YcbcrFormat::new(matrix, YcbcrRange::Limited, 10);
+            assert_eq!(limited.from_rgb(white), [940, 512, 512]);
+            assert_eq!(limited.from_rgb(black), [64, 512, 512]);
+            let full = YcbcrFormat::new(matrix, YcbcrRange::Full, 12);
+            assert_eq!(full.from_rgb(white), [4095, 2048, 2048]);
+            assert_eq!(full.from_rgb(black), [0, 2048, 2048]);
+        }
+    }
+
+    #[test]
+    fn quantization_roundtrip() {
+        for range in [YcbcrRange::Full, YcbcrRange::Limited] {
+            for bit_depth in [8, 10, 12, 16] {
+                let format = YcbcrFormat::new(YcbcrMatrix::Bt2020, range, bit_depth);
+                let max = u16::MAX >> (16 - bit_depth);
+                for code in [[0, 0, 0], [max, max, max], [100, max / 2, max / 3]] {
+                    assert_eq!(format.quantize(format.dequantize(code)), code);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the bit depth must be between 8 and 16")]
+    fn invalid_bit_depth() {
+        let _ = YcbcrFormat::new(YcbcrMatrix::Bt709, YcbcrRange::Full, 7);
+    }
+}
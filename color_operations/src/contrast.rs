@@ -0,0 +1,192 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Contrast metrics for accessibility.
+
+use color::{DynamicColor, OpaqueColor, Srgb};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// Composite the foreground over the background in sRGB, ignoring the alpha of the background.
+///
+/// This is how browsers blend translucent colors, so contrast is measured between the colors
+/// that are displayed.
+fn composite(
+    foreground: impl Into<DynamicColor>,
+    background: impl Into<DynamicColor>,
+) -> (OpaqueColor<Srgb>, OpaqueColor<Srgb>) {
+    let (foreground, alpha) = foreground.into().to_alpha_color::<Srgb>().split();
+    let background = background.into().to_alpha_color::<Srgb>().discard_alpha();
+    (foreground * alpha + background * (1. - alpha), background)
+}
+
+/// The WCAG 2.1 contrast ratio between a foreground and a background color.
+///
+/// This is `(L1 + 0.05) / (L2 + 0.05)`, where `L1` and `L2` are the [relative luminances] of the
+/// lighter and darker color respectively, ranging from 1 to 21. WCAG 2.1 level AA requires a
+/// contrast ratio of at least 4.5 for normal text.
+///
+/// The colors are converted to [`Srgb`], and a translucent foreground is composited over the
+/// background. The alpha of the background is ignored; a translucent background should first be
+/// composited over its own backdrop.
+///
+/// ```rust
+/// use color::palette::css;
+/// use color_operations::contrast_ratio;
+///
+/// assert!((contrast_ratio(css::BLACK, css::WHITE) - 21.).abs() < 1e-4);
+/// assert!((contrast_ratio(css::BLACK.with_alpha(0.), css::WHITE) - 1.).abs() < 1e-4);
+/// ```
+///
+/// [relative luminances]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+#[must_use]
+pub fn contrast_ratio(
+    foreground: impl Into<DynamicColor>,
+    background: impl Into<DynamicColor>,
+) -> f32 {
+    let (foreground, background) = composite(foreground, background);
+    let foreground = foreground.relative_luminance();
+    let background = background.relative_luminance();
+    (foreground.max(background) + 0.05) / (foreground.min(background) + 0.05)
+}
+
+/// The APCA lightness contrast `Lc` of text on a background color.
+///
+/// This implements SAPC-APCA 0.0.98G, as proposed for WCAG 3. Unlike the [WCAG 2.1 contrast
+/// ratio](contrast_ratio), this depends on the polarity: `Lc` is positive for dark text on a light
+/// background, and negative for light text on a dark background. It ranges from about -108 to
+/// 106, and contrasts with a magnitude below 7.5 are reported as 0.
+///
+/// The colors are converted to [`Srgb`] and clipped to its gamut, and translucent text is
+/// composited over the background. The alpha of the background is ignored; a translucent
+/// background should first be composited over its own backdrop.
+///
+/// ```rust
+/// use color::{AlphaColor, Srgb};
+/// use color_operations::apca_contrast;
+///
+/// let gray = AlphaColor::<Srgb>::from_rgb8(136, 136, 136);
+/// let white = AlphaColor::<Srgb>::from_rgb8(255, 255, 255);
+/// assert!((apca_contrast(gray, white) - 63.056).abs() < 1e-2);
+/// assert!((apca_contrast(white, gray) + 68.541).abs() < 1e-2);
+/// ```
+#[must_use]
+pub fn apca_contrast(text: impl Into<DynamicColor>, background: impl Into<DynamicColor>) -> f32 {
+    const NORM_BG: f32 = 0.56;
+    const NORM_TEXT: f32 = 0.57;
+    const REV_TEXT: f32 = 0.62;
+    const REV_BG: f32 = 0.65;
+    const SCALE: f32 = 1.14;
+    const OFFSET: f32 = 0.027;
+    const LOW_CLIP: f32 = 0.1;
+    const DELTA_Y_MIN: f32 = 0.0005;
+
+    let (text, background) = composite(text, background);
+    let text = apca_luminance(text);
+    let background = apca_luminance(background);
+    if (background - text).abs() < DELTA_Y_MIN {
+        return 0.;
+    }
+
+    let lc = if background > text {
+        // Dark text on a light background.
+        let sapc = (background.powf(NORM_BG) - text.powf(NORM_TEXT)) * SCALE;
+        if sapc < LOW_CLIP {
+            0.
+        } else {
+            sapc - OFFSET
+        }
+    } else {
+        // Light text on a dark background.
+        let sapc = (background.powf(REV_BG) - text.powf(REV_TEXT)) * SCALE;
+        if sapc > -LOW_CLIP {
+            0.
+        } else {
+            sapc + OFFSET
+        }
+    };
+    lc * 100.
+}
+
+/// The estimated screen luminance of APCA, with a soft clamp of near-black colors.
+///
+/// This uses a simple 2.4 gamma, rather than the piecewise sRGB transfer function.
+fn apca_luminance(color: OpaqueColor<Srgb>) -> f32 {
+    const BLACK_THRESHOLD: f32 = 0.022;
+    const BLACK_CLAMP: f32 = 1.414;
+
+    let [r, g, b] = color.components.map(|c| c.clamp(0., 1.).powf(2.4));
+    let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+    if y > BLACK_THRESHOLD {
+        y
+    } else {
+        y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apca_contrast, contrast_ratio};
+    use color::{palette::css, AlphaColor, DynamicColor, Oklch, Srgb};
+
+    #[test]
+    fn wcag() {
+        let gray = AlphaColor::<Srgb>::from_rgb8(118, 118, 118);
+        // The lightest gray passing WCAG AA on white.
+        assert!((contrast_ratio(gray, css::WHITE) - 4.54).abs() < 1e-2);
+        assert_eq!(
+            contrast_ratio(gray, css::WHITE),
+            contrast_ratio(css::WHITE, gray)
+        );
+        assert_eq!(contrast_ratio(css::RED, css::RED), 1.);
+
+        // Colors in other spaces are converted.
+        let oklch = gray.convert::<Oklch>();
+        assert!((contrast_ratio(oklch, css::WHITE) - 4.54).abs() < 1e-2);
+        let dynamic = DynamicColor::from_alpha_color(oklch);
+        assert!((contrast_ratio(dynamic, css::WHITE) - 4.54).abs() < 1e-2);
+    }
+
+    #[test]
+    fn translucent_foreground() {
+        // Half-transparent black over white is displayed as the sRGB midpoint.
+        let composited = AlphaColor::<Srgb>::new([0.5, 0.5, 0.5, 1.]);
+        let translucent = css::BLACK.with_alpha(0.5);
+        assert_eq!(
+            contrast_ratio(translucent, css::WHITE),
+            contrast_ratio(composited, css::WHITE)
+        );
+        assert_eq!(
+            apca_contrast(translucent, css::WHITE),
+            apca_contrast(composited, css::WHITE)
+        );
+
+        // The alpha of the background is ignored.
+        assert_eq!(
+            contrast_ratio(translucent, css::WHITE.with_alpha(0.2)),
+            contrast_ratio(composited, css::WHITE)
+        );
+    }
+
+    #[test]
+    fn apca_reference() {
+        // The reference values of the APCA-W3 0.0.98G implementation.
+        for (text, background, lc) in [
+            ([136, 136, 136], [255, 255, 255], 63.056_47),
+            ([255, 255, 255], [136, 136, 136], -68.541_46),
+            ([0, 0, 0], [170, 170, 170], 58.146_26),
+            ([170, 170, 170], [0, 0, 0], -56.241_13),
+        ] {
+            let text = AlphaColor::<Srgb>::from_rgb8(text[0], text[1], text[2]);
+            let background =
+                AlphaColor::<Srgb>::from_rgb8(background[0], background[1], background[2]);
+            let estimate = apca_contrast(text, background);
+            assert!((estimate - lc).abs() < 1e-2, "{estimate} {lc}");
+        }
+
+        // Low contrasts are clipped to 0.
+        let light = AlphaColor::<Srgb>::from_rgb8(250, 250, 250);
+        assert_eq!(apca_contrast(light, css::WHITE), 0.);
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Simulation and correction of color vision deficiencies.
+
+use color::{AlphaColor, ColorSpace, ColorSpaceTag, DynamicColor, LinearSrgb, PremulColor};
+
+/// A color vision deficiency, caused by anomalous or missing cones of one type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ColorVisionDeficiency {
+    /// Protanomaly, or protanopia at full severity, affecting the long-wavelength (L) cones.
+    Protan,
+    /// Deuteranomaly, or deuteranopia at full severity, affecting the medium-wavelength (M)
+    /// cones.
+    Deutan,
+    /// Tritanomaly, or tritanopia at full severity, affecting the short-wavelength (S) cones.
+    Tritan,
+}
+
+/// The model used to simulate a [`ColorVisionDeficiency`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CvdModel {
+    /// The model of [Machado et al. (2009)][machado], which simulates anomalous trichromacy by
+    /// shifting the spectral sensitivity of the affected cones.
+    ///
+    /// The tabulated matrices are interpolated between steps of 0.1 severity.
+    ///
+    /// [machado]: https://doi.org/10.1109/TVCG.2009.113
+    #[default]
+    Machado2009,
+    /// The model of [Viénot et al. (1999)][vienot], which projects colors onto a single plane in
+    /// the LMS cone space through black, white and the sRGB blue primary.
+    ///
+    /// This is accurate for protanopia and deuteranopia. For tritanopia, which the model was not
+    /// designed for, the plane goes through the sRGB red primary instead.
+    ///
+    /// [vienot]: https://doi.org/10.1002/(SICI)1520-6378(199908)24:4%3C243::AID-COL5%3E3.0.CO;2-3
+    Vienot1999,
+    /// The model of [Brettel et al. (1997)][brettel], which projects colors onto two half-planes
+    /// in the LMS cone space, anchored at black, white and the monochromatic colors of 475 nm and
+    /// 575 nm, or 485 nm and 660 nm for tritanopia.
+    ///
+    /// [brettel]: https://doi.org/10.1364/JOSAA.14.002647
+    Brettel1997,
+}
+
+/// A color that can be transformed in [`LinearSrgb`].
+///
+/// This is implemented for [`AlphaColor`], [`PremulColor`] and [`DynamicColor`], so that they can
+/// be used with [`CvdSimulation`].
+pub trait MapLinearSrgb: Sized {
+    /// Map the color through linear sRGB, preserving alpha.
+    ///
+    /// The function receives the components in linear sRGB, and the result is converted back to the
+    /// color space of the color. It must be positively homogeneous, i.e. commute with scaling by a
+    /// non-negative factor, as premultiplied components may be passed to it.
+    #[must_use]
+    fn map_linear_srgb(self, f: impl Fn([f32; 3]) -> [f32; 3]) -> Self;
+}
+
+impl<CS: ColorSpace> MapLinearSrgb for AlphaColor<CS> {
+    fn map_linear_srgb(self, f: impl Fn([f32; 3]) -> [f32; 3]) -> Self {
+        self.map_in::<LinearSrgb>(|r, g, b, a| {
+            let [r, g, b] = f([r, g, b]);
+            [r, g, b, a]
+        })
+    }
+}
+
+impl<CS: ColorSpace> MapLinearSrgb for PremulColor<CS> {
+    fn map_linear_srgb(self, f: impl Fn([f32; 3]) -> [f32; 3]) -> Self {
+        let [r, g, b, a] = self.convert::<LinearSrgb>().components;
+        let [r, g, b] = f([r, g, b]);
+        PremulColor::<LinearSrgb>::new([r, g, b, a]).convert()
+    }
+}
+
+impl MapLinearSrgb for DynamicColor {
+    fn map_linear_srgb(self, f: impl Fn([f32; 3]) -> [f32; 3]) -> Self {
+        self.map_in(ColorSpaceTag::LinearSrgb, |r, g, b, a| {
+            let [r, g, b] = f([r, g, b]);
+            [r, g, b, a]
+        })
+    }
+}
+
+/// A simulation of a color vision deficiency, at a given severity.
+///
+/// The simulation is applied in [`LinearSrgb`], to which colors are converted. The simulated
+/// colors of the Viénot and Brettel models are blended with the original color by the severity.
+///
+/// ```rust
+/// use color::palette::css;
+/// use color_operations::{delta_e_2000, ColorVisionDeficiency, CvdModel, CvdSimulation};
+///
+/// let deuteranopia =
+///     CvdSimulation::new(ColorVisionDeficiency::Deutan, 1., CvdModel::Machado2009);
+/// let red = deuteranopia.simulate(css::RED);
+/// let green = deuteranopia.simulate(css::GREEN);
+/// // Red and green are harder to distinguish.
+/// assert!(delta_e_2000(red, green) < delta_e_2000(css::RED, css::GREEN));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CvdSimulation {
+    deficiency: ColorVisionDeficiency,
+    /// The matrices in linear sRGB for colors on the non-negative and negative side of
+    /// `separation` respectively.
+    matrices: [[[f32; 3]; 3]; 2],
+    /// The normal of the plane separating the half-planes of the Brettel model.
+    separation: [f32; 3],
+}
+
+impl CvdSimulation {
+    /// Create a simulation of the color vision deficiency with the given model.
+    ///
+    /// The `severity` ranges from 0, for normal color vision, to 1, for dichromacy, and is clamped
+    /// to that range.
+    #[must_use]
+    pub fn new(deficiency: ColorVisionDeficiency, severity: f32, model: CvdModel) -> Self {
+        let severity = severity.clamp(0., 1.);
+        let ix = deficiency as usize;
+        let (matrices, separation) = match model {
+            CvdModel::Machado2009 => {
+                let scaled = severity * 10.;
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "the severity is clamped to [0, 1]"
+                )]
+                let step = (scaled as usize).min(9);
+                let lower = if step == 0 {
+                    IDENTITY
+                } else {
+                    MACHADO[ix][step - 1]
+                };
+                let matrix = lerp_matrix(lower, MACHADO[ix][step], scaled - step as f32);
+                ([matrix; 2], [0.; 3])
+            }
+            CvdModel::Vienot1999 => {
+                let matrix = lerp_matrix(IDENTITY, VIENOT[ix], severity);
+                ([matrix; 2], [0.; 3])
+            }
+            CvdModel::Brettel1997 => {
+                let (matrices, separation) = BRETTEL[ix];
+                (
+                    matrices.map(|matrix| lerp_matrix(IDENTITY, matrix, severity)),
+                    separation,
+                )
+            }
+        };
+        Self {
+            deficiency,
+            matrices,
+            separation,
+        }
+    }
+
+    /// Simulate the appearance of the color.
+    #[must_use]
+    pub fn simulate<C: MapLinearSrgb>(&self, color: C) -> C {
+        color.map_linear_srgb(|rgb| self.simulate_linear_srgb(rgb))
+    }
+
+    /// Correct the color for the color vision deficiency, also known as daltonization.
+    ///
+    /// The components lost in the simulated color are redistributed to the channels that remain
+    /// distinguishable, following Fidaner et al. (2005). The corrected color may be out of gamut,
+    /// and can be mapped back with for example [`AlphaColor::map_to_gamut`].
+    #[must_use]
+    pub fn daltonize<C: MapLinearSrgb>(&self, color: C) -> C {
+        color.map_linear_srgb(|rgb| self.daltonize_linear_srgb(rgb))
+    }
+
+    /// Simulate the appearance of a color given in [`LinearSrgb`].
+    #[must_use]
+    pub fn simulate_linear_srgb(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let [r, g, b] = rgb;
+        let [nr, ng, nb] = self.separation;
+        let side = if nr * r + ng * g + nb * b >= 0. { 0 } else { 1 };
+        matvecmul(&self.matrices[side], rgb)
+    }
+
+    /// Correct a color given in [`LinearSrgb`] for the color vision deficiency.
+    ///
+    /// See [`CvdSimulation::daltonize`].
+    #[must_use]
+    pub fn daltonize_linear_srgb(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let [sr, sg, sb] = self.simulate_linear_srgb(rgb);
+        let error = [rgb[0] - sr, rgb[1] - sg, rgb[2] - sb];
+        let shift = match self.deficiency {
+            ColorVisionDeficiency::Protan | ColorVisionDeficiency::Deutan => {
+                [[0., 0., 0.], [0.7, 1., 0.], [0.7, 0., 1.]]
+            }
+            ColorVisionDeficiency::Tritan => [[1., 0., 0.7], [0., 1., 0.7], [0., 0., 0.]],
+        };
+        let [er, eg, eb] = matvecmul(&shift, error);
+        [rgb[0] + er, rgb[1] + eg, rgb[2] + eb]
+    }
+}
+
+fn matvecmul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn lerp_matrix(a: [[f32; 3]; 3], b: [[f32; 3]; 3], t: f32) -> [[f32; 3]; 3] {
+    let mut result = a;
+    for (result_row, b_row) in result.iter_mut().zip(b) {
+        for (x, y) in result_row.iter_mut().zip(b_row) {
+            *x += t * (y - *x);
+        }
+    }
+    result
+}
+
+const IDENTITY: [[f32; 3]; 3] = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+
+/// The matrices of Machado et al. (2009) in linear sRGB, for severities 0.1 to 1.0 of protanomaly,
+/// deuteranomaly and tritanomaly.
+#[rustfmt::skip]
+const MACHADO: [[[[f32; 3]; 3]; 10]; 3] = [
+    [
+        [[0.856_167, 0.182_038, -0.038_205], [0.029_342, 0.955_115, 0.015_544], [-0.002_88, -0.001_563, 1.004_443]],
+        [[0.734_766, 0.334_872, -0.069_637], [0.051_84, 0.919_198, 0.028_963], [-0.004_928, -0.004_209, 1.009_137]],
+        [[0.630_323, 0.465_641, -0.095_964], [0.069_181, 0.890_046, 0.040_773], [-0.006_308, -0.007_724, 1.014_032]],
+        [[0.539_009, 0.579_343, -0.118_352], [0.082_546, 0.866_121, 0.051_332], [-0.007_136, -0.011_959, 1.019_095]],
+        [[0.458_064, 0.679_578, -0.137_642], [0.092_785, 0.846_313, 0.060_902], [-0.007_494, -0.016_807, 1.024_301]],
+        [[0.385_45, 0.769_005, -0.154_455], [0.100_526, 0.829_802, 0.069_673], [-0.007_442, -0.022_19, 1.029_632]],
+        [[0.319_627, 0.849_633, -0.169_261], [0.106_241, 0.815_969, 0.077_79], [-0.007_025, -0.028_051, 1.035_076]],
+        [[0.259_411, 0.923_008, -0.182_42], [0.110_296, 0.804_34, 0.085_364], [-0.006_276, -0.034_346, 1.040_622]],
+        [[0.203_876, 0.990_338, -0.194_214], [0.112_975, 0.794_542, 0.092_483], [-0.005_222, -0.041_043, 1.046_265]],
+        [[0.152_286, 1.052_583, -0.204_868], [0.114_503, 0.786_281, 0.099_216], [-0.003_882, -0.048_116, 1.051_998]],
+    ],
+    [
+        [[0.866_435, 0.177_704, -0.044_139], [0.049_567, 0.939_063, 0.011_37], [-0.003_453, 0.007_233, 0.996_22]],
+        [[0.760_729, 0.319_078, -0.079_807], [0.090_568, 0.889_315, 0.020_117], [-0.006_027, 0.013_325, 0.992_702]],
+        [[0.675_425, 0.433_85, -0.109_275], [0.125_303, 0.847_755, 0.026_942], [-0.007_95, 0.018_572, 0.989_378]],
+        [[0.605_511, 0.528_56, -0.134_071], [0.155_318, 0.812_366, 0.032_316], [-0.009_376, 0.023_176, 0.986_2]],
+        [[0.547_494, 0.607_765, -0.155_259], [0.181_692, 0.781_742, 0.036_566], [-0.010_41, 0.027_275, 0.983_136]],
+        [[0.498_864, 0.674_741, -0.173_604], [0.205_199, 0.754_872, 0.039_929], [-0.011_131, 0.030_969, 0.980_162]],
+        [[0.457_771, 0.731_899, -0.189_67], [0.226_409, 0.731_012, 0.042_579], [-0.011_595, 0.034_333, 0.977_261]],
+        [[0.422_823, 0.781_057, -0.203_881], [0.245_752, 0.709_602, 0.044_646], [-0.011_843, 0.037_423, 0.974_421]],
+        [[0.392_952, 0.823_61, -0.216_562], [0.263_559, 0.690_21, 0.046_232], [-0.011_91, 0.040_281, 0.971_63]],
+        [[0.367_322, 0.860_646, -0.227_968], [0.280_085, 0.672_501, 0.047_413], [-0.011_82, 0.042_94, 0.968_881]],
+    ],
+    [
+        [[0.926_67, 0.092_514, -0.019_184], [0.021_191, 0.964_503, 0.014_306], [0.008_437, 0.054_813, 0.936_75]],
+        [[0.895_72, 0.133_33, -0.029_05], [0.029_997, 0.945_4, 0.024_603], [0.013_027, 0.104_707, 0.882_266]],
+        [[0.905_871, 0.127_791, -0.033_662], [0.026_856, 0.941_251, 0.031_893], [0.013_41, 0.148_296, 0.838_294]],
+        [[0.948_035, 0.089_49, -0.037_526], [0.014_364, 0.946_792, 0.038_844], [0.010_853, 0.193_991, 0.795_156]],
+        [[1.017_277, 0.027_029, -0.044_306], [-0.006_113, 0.958_479, 0.047_634], [0.006_379, 0.248_708, 0.744_913]],
+        [[1.104_996, -0.046_633, -0.058_363], [-0.032_137, 0.971_635, 0.060_503], [0.001_336, 0.317_922, 0.680_742]],
+        [[1.193_214, -0.109_812, -0.083_402], [-0.058_496, 0.979_41, 0.079_086], [-0.002_346, 0.403_492, 0.598_854]],
+        [[1.257_728, -0.139_648, -0.118_081], [-0.078_003, 0.975_409, 0.102_594], [-0.003_316, 0.501_214, 0.502_102]],
+        [[1.278_864, -0.125_333, -0.153_531], [-0.084_748, 0.957_674, 0.127_074], [-0.000_989, 0.601_151, 0.399_838]],
+        [[1.255_528, -0.076_749, -0.178_779], [-0.078_411, 0.930_809, 0.147_602], [0.004_733, 0.691_367, 0.303_9]],
+    ],
+];
+
+// The matrices of the Viénot and Brettel models are derived in the LMS space of the Smith and
+// Pokorny (1975) cone fundamentals, with the CIE 1931 color matching functions at the anchor
+// wavelengths.
+
+/// The projection matrices of Viénot et al. (1999) in linear sRGB, for protanopia, deuteranopia
+/// and tritanopia.
+const VIENOT: [[[f32; 3]; 3]; 3] = [
+    [
+        [0.108_873, 0.891_127, 0.],
+        [0.108_873, 0.891_127, 0.],
+        [0.004_47, -0.004_47, 1.],
+    ],
+    [
+        [0.290_268, 0.709_732, 0.],
+        [0.290_268, 0.709_732, 0.],
+        [-0.021_965, 0.021_965, 1.],
+    ],
+    [
+        [1., 0.152_419, -0.152_419],
+        [0., 0.867_148, 0.132_852],
+        [0., 0.867_148, 0.132_852],
+    ],
+];
+
+/// The projection matrices of Brettel et al. (1997) in linear sRGB, for the half-planes through
+/// 475 nm and 575 nm, or 485 nm and 660 nm, with the normal of the plane separating them.
+type BrettelProjection = ([[[f32; 3]; 3]; 2], [f32; 3]);
+
+/// The projections of Brettel et al. (1997), for protanopia, deuteranopia and tritanopia.
+const BRETTEL: [BrettelProjection; 3] = [
+    (
+        [
+            [
+                [0.141_142, 1.167_948, -0.309_09],
+                [0.104_93, 0.857_307, 0.037_763],
+                [0.004_308, -0.005_859, 1.001_551],
+            ],
+            [
+                [0.145_096, 1.201_864, -0.346_96],
+                [0.104_447, 0.853_164, 0.042_389],
+                [0.004_289, -0.006_029, 1.001_741],
+            ],
+        ],
+        [-0.104_402, -0.895_598, 1.],
+    ),
+    (
+        [
+            [
+                [0.361_963, 0.867_656, -0.229_62],
+                [0.260_946, 0.645_143, 0.093_911],
+                [-0.019_746, 0.026_853, 0.992_894],
+            ],
+            [
+                [0.370_09, 0.885_557, -0.255_647],
+                [0.257_623, 0.637_822, 0.104_555],
+                [-0.019_495, 0.027_407, 0.992_088],
+            ],
+        ],
+        [-0.312_234, -0.687_766, 1.],
+    ),
+    (
+        [
+            [
+                [0.933_377, 0.200_036, -0.133_413],
+                [0.058_07, 0.825_644, 0.116_286],
+                [-0.379_035, 1.138_055, 0.240_979],
+            ],
+            [
+                [1.013_548, 0.142_735, -0.156_283],
+                [-0.011_809, 0.875_588, 0.136_221],
+                [0.077_079, 0.812_057, 0.110_863],
+            ],
+        ],
+        [-1., 0.714_73, 0.285_27],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorVisionDeficiency, CvdModel, CvdSimulation};
+    use color::{palette::css, AlphaColor, DynamicColor, LinearSrgb, PremulColor, Srgb};
+
+    const DEFICIENCIES: [ColorVisionDeficiency; 3] = [
+        ColorVisionDeficiency::Protan,
+        ColorVisionDeficiency::Deutan,
+        ColorVisionDeficiency::Tritan,
+    ];
+    const MODELS: [CvdModel; 3] = [
+        CvdModel::Machado2009,
+        CvdModel::Vienot1999,
+        CvdModel::Brettel1997,
+    ];
+
+    fn almost_equal(a: [f32; 3], b: [f32; 3], epsilon: f32) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < epsilon)
+    }
+
+    #[test]
+    fn neutrals_are_preserved() {
+        for deficiency in DEFICIENCIES {
+            for model in MODELS {
+                for severity in [0., 0.25, 0.5, 1.] {
+                    let cvd = CvdSimulation::new(deficiency, severity, model);
+                    for gray in [0., 0.2, 1.] {
+                        let simulated = cvd.simulate_linear_srgb([gray; 3]);
+                        assert!(
+                            almost_equal(simulated, [gray; 3], 1e-5),
+                            "{deficiency:?} {model:?} {severity}: {simulated:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn severity() {
+        let color = [0.8, 0.3, 0.1];
+        for deficiency in DEFICIENCIES {
+            for model in MODELS {
+                let none = CvdSimulation::new(deficiency, 0., model);
+                assert!(almost_equal(none.simulate_linear_srgb(color), color, 1e-6));
+
+                // Severities beyond the valid range are clamped.
+                let full = CvdSimulation::new(deficiency, 1., model);
+                assert_eq!(full, CvdSimulation::new(deficiency, 2., model));
+            }
+        }
+
+        // The Machado model interpolates between the tabulated severities.
+        let cvd = |severity| {
+            CvdSimulation::new(
+                ColorVisionDeficiency::Protan,
+                severity,
+                CvdModel::Machado2009,
+            )
+            .simulate_linear_srgb(color)
+        };
+        let [a, b, c] = [cvd(0.3), cvd(0.35), cvd(0.4)];
+        assert!(almost_equal(
+            b,
+            [0, 1, 2].map(|i| 0.5 * (a[i] + c[i])),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn dichromats_confuse_colors() {
+        // The Viénot projection of protanopia and deuteranopia is onto the plane `R = G`.
+        for deficiency in [ColorVisionDeficiency::Protan, ColorVisionDeficiency::Deutan] {
+            let cvd = CvdSimulation::new(deficiency, 1., CvdModel::Vienot1999);
+            let [r, g, _] = cvd.simulate_linear_srgb([0.9, 0.1, 0.4]);
+            assert!((r - g).abs() < 1e-6, "{deficiency:?}");
+        }
+
+        // Colors differing only in the missing cone response are indistinguishable.
+        for model in MODELS {
+            let cvd = CvdSimulation::new(ColorVisionDeficiency::Deutan, 1., model);
+            let red = cvd
+                .simulate(css::RED)
+                .convert::<LinearSrgb>()
+                .discard_alpha();
+            let lime = cvd
+                .simulate(css::LIME)
+                .convert::<LinearSrgb>()
+                .discard_alpha();
+            let blue = cvd
+                .simulate(css::BLUE)
+                .convert::<LinearSrgb>()
+                .discard_alpha();
+            assert!(red.difference(lime) < 0.7, "{model:?}");
+            assert!(red.difference(blue) > 0.7, "{model:?}");
+        }
+    }
+
+    #[test]
+    fn daltonize() {
+        let cvd = CvdSimulation::new(ColorVisionDeficiency::Protan, 1., CvdModel::Machado2009);
+        let gray = [0.4; 3];
+        assert!(almost_equal(cvd.daltonize_linear_srgb(gray), gray, 1e-5));
+
+        // Daltonized red and green are easier to tell apart.
+        let red = AlphaColor::<LinearSrgb>::new([1., 0., 0., 1.]);
+        let green = AlphaColor::<LinearSrgb>::new([0., 0.5, 0., 1.]);
+        let distance = |a: AlphaColor<LinearSrgb>, b: AlphaColor<LinearSrgb>| {
+            cvd.simulate(a)
+                .discard_alpha()
+                .difference(cvd.simulate(b).discard_alpha())
+        };
+        assert!(distance(cvd.daltonize(red), cvd.daltonize(green)) > distance(red, green));
+    }
+
+    #[test]
+    fn color_types() {
+        let cvd = CvdSimulation::new(ColorVisionDeficiency::Tritan, 0.6, CvdModel::Brettel1997);
+        let color = AlphaColor::<Srgb>::new([0.2, 0.6, 0.9, 0.5]);
+        let simulated = cvd.simulate(color);
+        assert_eq!(simulated.components[3], 0.5);
+
+        let premul = cvd.simulate(color.premultiply()).un_premultiply();
+        assert!(premul.discard_alpha().difference(simulated.discard_alpha()) < 1e-5);
+        let premul: PremulColor<LinearSrgb> = cvd.simulate(color.convert().premultiply());
+        assert!(
+            premul
+                .discard_alpha()
+                .convert()
+                .difference(simulated.discard_alpha())
+                < 1e-5
+        );
+
+        let dynamic = cvd.simulate(DynamicColor::from_alpha_color(color));
+        assert!(
+            dynamic
+                .to_alpha_color::<Srgb>()
+                .discard_alpha()
+                .difference(simulated.discard_alpha())
+                < 1e-5
+        );
+    }
+}
@@ -0,0 +1,347 @@
+// Copyright 2024 the Color Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Color difference metrics in CIELAB.
+
+use color::{DynamicColor, Lab};
+
+#[cfg(all(not(feature = "std"), not(test)))]
+use crate::floatfuncs::FloatFuncs;
+
+/// The parametric factors of the [CIE94 color difference](delta_e_94).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeltaE94Application {
+    /// The factors for graphic arts, with `kL = 1`, `K1 = 0.045` and `K2 = 0.015`.
+    #[default]
+    GraphicArts,
+    /// The factors for textiles, with `kL = 2`, `K1 = 0.048` and `K2 = 0.014`.
+    Textiles,
+}
+
+/// Convert a color to the components of [`Lab`], ignoring alpha.
+fn to_lab(color: impl Into<DynamicColor>) -> [f32; 3] {
+    let [l, a, b, _] = color.into().to_alpha_color::<Lab>().components;
+    [l, a, b]
+}
+
+/// The hue angle of `a` and `b` in degrees, in the range `[0, 360)`.
+fn hue(a: f32, b: f32) -> f32 {
+    let h = b.atan2(a).to_degrees();
+    if h < 0. {
+        h + 360.
+    } else {
+        h
+    }
+}
+
+/// The CIE76 color difference between two colors.
+///
+/// This is the Euclidean distance in [`Lab`], to which the colors are converted. It is symmetric,
+/// but overestimates differences between saturated colors compared to the later metrics.
+///
+/// ```rust
+/// use color::{AlphaColor, Lab};
+/// use color_operations::delta_e_76;
+///
+/// let a = AlphaColor::<Lab>::new([50., 10., 10., 1.]);
+/// let b = AlphaColor::<Lab>::new([53., 6., 10., 1.]);
+/// assert!((delta_e_76(a, b) - 5.).abs() < 1e-5);
+/// ```
+#[must_use]
+pub fn delta_e_76(reference: impl Into<DynamicColor>, sample: impl Into<DynamicColor>) -> f32 {
+    let [l1, a1, b1] = to_lab(reference);
+    let [l2, a2, b2] = to_lab(sample);
+    let (dl, da, db) = (l1 - l2, a1 - a2, b1 - b2);
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// The CIE94 color difference between two colors.
+///
+/// The colors are converted to [`Lab`]. The chroma and hue differences are weighted by the chroma
+/// of the `reference` color, so this is not symmetric.
+#[must_use]
+pub fn delta_e_94(
+    reference: impl Into<DynamicColor>,
+    sample: impl Into<DynamicColor>,
+    application: DeltaE94Application,
+) -> f32 {
+    let (k_l, k_1, k_2) = match application {
+        DeltaE94Application::GraphicArts => (1., 0.045, 0.015),
+        DeltaE94Application::Textiles => (2., 0.048, 0.014),
+    };
+    let [l1, a1, b1] = to_lab(reference);
+    let [l2, a2, b2] = to_lab(sample);
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let dl = (l1 - l2) / k_l;
+    let dc = c1 - c2;
+    let (da, db) = (a1 - a2, b1 - b2);
+    // The squared hue difference, which can become slightly negative through rounding.
+    let dh_squared = (da * da + db * db - dc * dc).max(0.);
+    let s_c = 1. + k_1 * c1;
+    let s_h = 1. + k_2 * c1;
+    (dl * dl + (dc / s_c) * (dc / s_c) + dh_squared / (s_h * s_h)).sqrt()
+}
+
+/// The CIEDE2000 color difference between two colors.
+///
+/// The colors are converted to [`Lab`]. This uses the parametric factors `kL = kC = kH = 1` of
+/// the reference conditions, see [`delta_e_2000_with`] to use different factors.
+///
+/// ```rust
+/// use color::{AlphaColor, Lab};
+/// use color_operations::delta_e_2000;
+///
+/// let a = AlphaColor::<Lab>::new([50., 2.6772, -79.7751, 1.]);
+/// let b = AlphaColor::<Lab>::new([50., 0., -82.7485, 1.]);
+/// assert!((delta_e_2000(a, b) - 2.0425).abs() < 1e-4);
+/// ```
+#[must_use]
+pub fn delta_e_2000(reference: impl Into<DynamicColor>, sample: impl Into<DynamicColor>) -> f32 {
+    delta_e_2000_with(reference, sample, 1., 1., 1.)
+}
+
+/// The CIEDE2000 color difference between two colors, with the given parametric factors.
+///
+/// The colors are converted to [`Lab`]. The lightness, chroma and hue differences are divided by
+/// `k_l`, `k_c` and `k_h` respectively. For example, textile applications commonly use `k_l = 2`.
+///
+/// This follows the implementation notes of [Sharma et al. (2005)][sharma], and is symmetric.
+///
+/// [sharma]: https://doi.org/10.1002/col.20070
+#[must_use]
+pub fn delta_e_2000_with(
+    reference: impl Into<DynamicColor>,
+    sample: impl Into<DynamicColor>,
+    k_l: f32,
+    k_c: f32,
+    k_h: f32,
+) -> f32 {
+    /// 25 to the power of 7.
+    const POW25_7: f32 = 6_103_515_625.;
+
+    let [l1, a1, b1] = to_lab(reference);
+    let [l2, a2, b2] = to_lab(sample);
+
+    // Scale the `a` axis to make neutral colors more uniform.
+    let c_mean = 0.5 * (a1.hypot(b1) + a2.hypot(b2));
+    let c_mean_7 = c_mean * c_mean * c_mean * c_mean * c_mean * c_mean * c_mean;
+    let g = 0.5 * (1. - (c_mean_7 / (c_mean_7 + POW25_7)).sqrt());
+    let a1 = (1. + g) * a1;
+    let a2 = (1. + g) * a2;
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let h1 = hue(a1, b1);
+    let h2 = hue(a2, b2);
+    let achromatic = c1 * c2 == 0.;
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if achromatic {
+        0.
+    } else if h2 - h1 > 180. {
+        h2 - h1 - 360.
+    } else if h2 - h1 < -180. {
+        h2 - h1 + 360.
+    } else {
+        h2 - h1
+    };
+    let dh = 2. * (c1 * c2).sqrt() * (0.5 * dh).to_radians().sin();
+
+    let l_mean = 0.5 * (l1 + l2);
+    let c_mean = 0.5 * (c1 + c2);
+    let h_mean = if achromatic {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180. {
+        0.5 * (h1 + h2)
+    } else if h1 + h2 < 360. {
+        0.5 * (h1 + h2 + 360.)
+    } else {
+        0.5 * (h1 + h2 - 360.)
+    };
+
+    let t = 1. - 0.17 * (h_mean - 30.).to_radians().cos()
+        + 0.24 * (2. * h_mean).to_radians().cos()
+        + 0.32 * (3. * h_mean + 6.).to_radians().cos()
+        - 0.20 * (4. * h_mean - 63.).to_radians().cos();
+    let d_theta = 30. * (-((h_mean - 275.) / 25.) * ((h_mean - 275.) / 25.)).exp();
+    let c_mean_7 = c_mean * c_mean * c_mean * c_mean * c_mean * c_mean * c_mean;
+    let r_c = 2. * (c_mean_7 / (c_mean_7 + POW25_7)).sqrt();
+    let l_offset_squared = (l_mean - 50.) * (l_mean - 50.);
+    let s_l = 1. + 0.015 * l_offset_squared / (20. + l_offset_squared).sqrt();
+    let s_c = 1. + 0.045 * c_mean;
+    let s_h = 1. + 0.015 * c_mean * t;
+    // The rotation term, correcting for the tilt of ellipses in the blue region.
+    let r_t = -(2. * d_theta).to_radians().sin() * r_c;
+
+    let dl = dl / (k_l * s_l);
+    let dc = dc / (k_c * s_c);
+    let dh = dh / (k_h * s_h);
+    (dl * dl + dc * dc + dh * dh + r_t * dc * dh).sqrt()
+}
+
+/// The CMC l:c color difference between two colors.
+///
+/// The colors are converted to [`Lab`]. The lightness and chroma differences are divided by
+/// `lightness` and `chroma` respectively, commonly 2:1 for acceptability and 1:1 for
+/// perceptibility. The differences are weighted by the chroma and hue of the `reference` color, so
+/// this is not symmetric.
+#[must_use]
+pub fn delta_e_cmc(
+    reference: impl Into<DynamicColor>,
+    sample: impl Into<DynamicColor>,
+    lightness: f32,
+    chroma: f32,
+) -> f32 {
+    let [l1, a1, b1] = to_lab(reference);
+    let [l2, a2, b2] = to_lab(sample);
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let h1 = hue(a1, b1);
+
+    let dl = l1 - l2;
+    let dc = c1 - c2;
+    let (da, db) = (a1 - a2, b1 - b2);
+    // The squared hue difference, which can become slightly negative through rounding.
+    let dh_squared = (da * da + db * db - dc * dc).max(0.);
+
+    let s_l = if l1 < 16. {
+        0.511
+    } else {
+        0.040_975 * l1 / (1. + 0.017_65 * l1)
+    };
+    let s_c = 0.0638 * c1 / (1. + 0.0131 * c1) + 0.638;
+    let c1_4 = c1 * c1 * c1 * c1;
+    let f = (c1_4 / (c1_4 + 1900.)).sqrt();
+    let t = if (164. ..=345.).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.).to_radians().cos()).abs()
+    };
+    let s_h = s_c * (f * t + 1. - f);
+
+    let dl = dl / (lightness * s_l);
+    let dc = dc / (chroma * s_c);
+    (dl * dl + dc * dc + dh_squared / (s_h * s_h)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        delta_e_2000, delta_e_2000_with, delta_e_76, delta_e_94, delta_e_cmc, DeltaE94Application,
+    };
+    use color::{AlphaColor, DynamicColor, Lab, Srgb};
+
+    fn lab(l: f32, a: f32, b: f32) -> AlphaColor<Lab> {
+        AlphaColor::new([l, a, b, 1.])
+    }
+
+    #[test]
+    fn ciede2000_sharma() {
+        // The test data of Sharma et al. (2005), "The CIEDE2000 color-difference formula:
+        // Implementation notes, supplementary test data, and mathematical observations".
+        #[rustfmt::skip]
+        let data: [([f32; 3], [f32; 3], f32); 34] = [
+            ([50.0000, 2.6772, -79.7751], [50.0000, 0.0000, -82.7485], 2.0425),
+            ([50.0000, 3.1571, -77.2803], [50.0000, 0.0000, -82.7485], 2.8615),
+            ([50.0000, 2.8361, -74.0200], [50.0000, 0.0000, -82.7485], 3.4412),
+            ([50.0000, -1.3802, -84.2814], [50.0000, 0.0000, -82.7485], 1.0000),
+            ([50.0000, -1.1848, -84.8006], [50.0000, 0.0000, -82.7485], 1.0000),
+            ([50.0000, -0.9009, -85.5211], [50.0000, 0.0000, -82.7485], 1.0000),
+            ([50.0000, 0.0000, 0.0000], [50.0000, -1.0000, 2.0000], 2.3669),
+            ([50.0000, -1.0000, 2.0000], [50.0000, 0.0000, 0.0000], 2.3669),
+            ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0009], 7.1792),
+            ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0010], 7.1792),
+            ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0011], 7.2195),
+            ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0012], 7.2195),
+            ([50.0000, -0.0010, 2.4900], [50.0000, 0.0009, -2.4900], 4.8045),
+            ([50.0000, -0.0010, 2.4900], [50.0000, 0.0010, -2.4900], 4.8045),
+            ([50.0000, -0.0010, 2.4900], [50.0000, 0.0011, -2.4900], 4.7461),
+            ([50.0000, 2.5000, 0.0000], [50.0000, 0.0000, -2.5000], 4.3065),
+            ([50.0000, 2.5000, 0.0000], [73.0000, 25.0000, -18.0000], 27.1492),
+            ([50.0000, 2.5000, 0.0000], [61.0000, -5.0000, 29.0000], 22.8977),
+            ([50.0000, 2.5000, 0.0000], [56.0000, -27.0000, -3.0000], 31.9030),
+            ([50.0000, 2.5000, 0.0000], [58.0000, 24.0000, 15.0000], 19.4535),
+            ([50.0000, 2.5000, 0.0000], [50.0000, 3.1736, 0.5854], 1.0000),
+            ([50.0000, 2.5000, 0.0000], [50.0000, 3.2972, 0.0000], 1.0000),
+            ([50.0000, 2.5000, 0.0000], [50.0000, 1.8634, 0.5757], 1.0000),
+            ([50.0000, 2.5000, 0.0000], [50.0000, 3.2592, 0.3350], 1.0000),
+            ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+            ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+            ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+            ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+            ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+            ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+            ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+            ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+            ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+            ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+        ];
+        for (i, ([l1, a1, b1], [l2, a2, b2], expected)) in data.into_iter().enumerate() {
+            let (c1, c2) = (lab(l1, a1, b1), lab(l2, a2, b2));
+            let forward = delta_e_2000(c1, c2);
+            let backward = delta_e_2000(c2, c1);
+            assert!(
+                (forward - expected).abs() < 1e-4,
+                "pair {}: {forward}",
+                i + 1
+            );
+            assert!(
+                (backward - expected).abs() < 1e-4,
+                "pair {}: {backward}",
+                i + 1
+            );
+        }
+    }
+
+    #[test]
+    fn parametric_factors() {
+        // For a pure lightness difference around `L = 50`, `S_L` is 1 for CIEDE2000 and CIE94.
+        let (a, b) = (lab(49., 20., -10.), lab(51., 20., -10.));
+        assert!((delta_e_2000_with(a, b, 2., 1., 1.) - 1.).abs() < 1e-5);
+        assert!((delta_e_94(a, b, DeltaE94Application::GraphicArts) - 2.).abs() < 1e-5);
+        assert!((delta_e_94(a, b, DeltaE94Application::Textiles) - 1.).abs() < 1e-5);
+
+        // For CMC, `S_L` is 0.511 below a lightness of 16.
+        let (a, b) = (lab(10., 20., -10.), lab(11., 20., -10.));
+        assert!((delta_e_cmc(a, b, 2., 1.) - 1. / (2. * 0.511)).abs() < 1e-5);
+        assert!((delta_e_cmc(a, b, 1., 1.) - 1. / 0.511).abs() < 1e-5);
+    }
+
+    #[test]
+    fn chroma_weighting() {
+        // A pure chroma difference of 1 from a reference with a chroma of 20.
+        let (a, b) = (lab(50., 20., 0.), lab(50., 21., 0.));
+        let expected = 1. / (1. + 0.045 * 20.);
+        let delta_e = delta_e_94(a, b, DeltaE94Application::GraphicArts);
+        assert!((delta_e - expected).abs() < 1e-5);
+
+        let expected = 1. / (0.0638 * 20. / (1. + 0.0131 * 20.) + 0.638);
+        assert!((delta_e_cmc(a, b, 2., 1.) - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn converts_to_lab() {
+        let a = AlphaColor::<Srgb>::new([0.8, 0.3, 0.2, 1.]);
+        let b = AlphaColor::<Srgb>::new([0.7, 0.35, 0.2, 1.]);
+        let lab_a = a.convert::<Lab>();
+        let lab_b = b.convert::<Lab>();
+        let dynamic_a = DynamicColor::from_alpha_color(a);
+        let dynamic_b = DynamicColor::from_alpha_color(b);
+
+        assert_eq!(
+            delta_e_76(lab_a, lab_b),
+            lab_a.split().0.difference(lab_b.split().0)
+        );
+        assert!((delta_e_76(a, b) - delta_e_76(lab_a, lab_b)).abs() < 1e-4);
+        assert!((delta_e_2000(dynamic_a, dynamic_b) - delta_e_2000(lab_a, lab_b)).abs() < 1e-4);
+        assert!(
+            (delta_e_94(a, b, DeltaE94Application::Textiles)
+                - delta_e_94(lab_a, lab_b, DeltaE94Application::Textiles))
+            .abs()
+                < 1e-4
+        );
+        assert!((delta_e_cmc(a, b, 1., 1.) - delta_e_cmc(lab_a, lab_b, 1., 1.)).abs() < 1e-4);
+    }
+}
@@ -13,5 +13,22 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 
-// Temporary to suppress warning.
-use color as _;
+mod contrast;
+mod cvd;
+mod difference;
+
+#[cfg(all(not(feature = "std"), not(test)))]
+mod floatfuncs;
+
+pub use contrast::{apca_contrast, contrast_ratio};
+pub use cvd::{ColorVisionDeficiency, CvdModel, CvdSimulation, MapLinearSrgb};
+pub use difference::{
+    delta_e_2000, delta_e_2000_with, delta_e_76, delta_e_94, delta_e_cmc, DeltaE94Application,
+};
+
+// Keep clippy from complaining about unused libm in nostd test case.
+#[cfg(feature = "libm")]
+#[expect(unused, reason = "keep clippy happy")]
+fn ensure_libm_dependency_used() -> f32 {
+    libm::sqrtf(4_f32)
+}